@@ -1,7 +1,8 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, IntoVal,
+    Symbol, Val, Vec,
 };
 
 use shared::types::{Asset, AssetMetadata, AssetConversionRate};
@@ -14,6 +15,24 @@ const ADMIN: Symbol = symbol_short!("ADMIN");
 const PAUSED: Symbol = symbol_short!("PAUSED");
 const ASSET_COUNT: Symbol = symbol_short!("ASSET_CNT");
 const SUPPORTED_ASSETS: Symbol = symbol_short!("ASSETS");
+const TRUSTED: Symbol = symbol_short!("TRUSTED");
+const UTILIZATION: Symbol = symbol_short!("UTIL");
+const PREMIUM_ASSETS: Symbol = symbol_short!("PREM_AST");
+const CLAIM_ASSETS: Symbol = symbol_short!("CLM_AST");
+const ACTIVE_COUNT: Symbol = symbol_short!("ACT_CNT");
+const RATE_TIERS: Symbol = symbol_short!("RATE_TIER");
+const ASSET_INDEX: Symbol = symbol_short!("AST_IDX");
+const CONFIG: Symbol = symbol_short!("CONFIG");
+const PRICE_FEED: Symbol = symbol_short!("PRICE_FD");
+
+/// Maximum number of assets returned by a single paginated request
+const MAX_PAGINATION_LIMIT: u32 = 50;
+
+/// Maximum number of assets registered by a single `register_assets` call
+const MAX_BATCH_REGISTRATIONS: u32 = 20;
+
+/// Maximum number of conversion rates moved by a single `migrate_conversion_rates` call
+const MAX_RATE_MIGRATIONS: u32 = 20;
 
 // ============================================================================
 // Error Handling
@@ -33,6 +52,8 @@ pub enum AssetRegistryError {
     InvalidAssetConfig = 9,
     ConversionRateNotFound = 10,
     InvalidConversionRate = 11,
+    NotTrustedContract = 12,
+    OracleRequired = 13,
 }
 
 // ============================================================================
@@ -71,6 +92,8 @@ pub struct AssetRegistration {
     pub accept_for_premium: bool,
     /// Accept for claim payouts
     pub accept_for_claims: bool,
+    /// Oracle data id of the asset's price feed
+    pub price_feed_id: Option<u64>,
 }
 
 /// Summary of asset registry state
@@ -87,6 +110,73 @@ pub struct AssetRegistrySummary {
     pub claim_assets: u32,
 }
 
+/// Premium and claim volume that has flowed through an asset
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetUtilization {
+    /// Cumulative premium volume recorded for the asset
+    pub premium_volume: i128,
+    /// Cumulative claim payout volume recorded for the asset
+    pub claim_volume: i128,
+    /// Number of premium payments recorded
+    pub premium_count: u32,
+    /// Number of claim payouts recorded
+    pub claim_count: u32,
+    /// Timestamp of the last recorded volume
+    pub last_updated: u64,
+}
+
+/// One band of a tiered conversion rate schedule
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateTier {
+    /// Smallest amount (inclusive) this band applies to
+    pub min_amount: i128,
+    /// Conversion rate for amounts in this band, in basis points
+    pub rate_bps: u32,
+}
+
+/// Storage identity of an asset. Contract tokens are keyed by their address
+/// so distinct token contracts never share an entry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AssetKey {
+    /// Native XLM or a classic Stellar asset, keyed by asset code
+    Code(Symbol),
+    /// Contract token, keyed by its contract address
+    Contract(Address),
+}
+
+/// First entry of a `register_assets` batch that could not be registered
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchFailure {
+    /// Position of the entry in the batch
+    pub index: u32,
+    /// `AssetRegistryError` code the entry failed with
+    pub error_code: u32,
+}
+
+/// Outcome of a `register_assets` batch
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BatchRegistrationResult {
+    /// Every entry was registered; holds the number of assets
+    Registered(u32),
+    /// Nothing was registered because this entry failed
+    Rejected(BatchFailure),
+}
+
+/// Result of a paginated supported-assets query
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaginatedAssetsResult {
+    /// Assets in the current page, in registration order
+    pub assets: Vec<Asset>,
+    /// Total number of registered assets (for pagination calculations)
+    pub total_count: u32,
+}
+
 // ============================================================================
 // Asset Registry Contract
 // ============================================================================
@@ -111,7 +201,17 @@ fn is_paused(env: &Env) -> bool {
     env.storage().persistent().get(&PAUSED).unwrap_or(false)
 }
 
-fn get_asset_key(asset: &Asset) -> Symbol {
+fn get_asset_key(asset: &Asset) -> AssetKey {
+    match asset {
+        Asset::Native => AssetKey::Code(symbol_short!("XLM")),
+        Asset::Stellar((code, _)) => AssetKey::Code(code.clone()),
+        Asset::Contract(address) => AssetKey::Contract(address.clone()),
+    }
+}
+
+/// Key an asset was stored under before `AssetKey`. Every contract token
+/// shared one entry, so at most one of them could be registered.
+fn legacy_asset_key(asset: &Asset) -> Symbol {
     match asset {
         Asset::Native => symbol_short!("XLM"),
         Asset::Stellar((code, _)) => code.clone(),
@@ -119,6 +219,122 @@ fn get_asset_key(asset: &Asset) -> Symbol {
     }
 }
 
+/// Rate of the highest band whose `min_amount` is at or below `amount`
+fn select_tier_rate(tiers: &Vec<RateTier>, amount: i128) -> Option<u32> {
+    let mut rate = None;
+    for tier in tiers.iter() {
+        if amount < tier.min_amount {
+            break;
+        }
+        rate = Some(tier.rate_bps);
+    }
+    rate
+}
+
+fn require_trusted_contract(env: &Env, caller: &Address) -> Result<(), AssetRegistryError> {
+    let trusted: bool = env
+        .storage()
+        .persistent()
+        .get(&(TRUSTED, caller.clone()))
+        .unwrap_or(false);
+    if !trusted {
+        return Err(AssetRegistryError::NotTrustedContract);
+    }
+    Ok(())
+}
+
+fn get_utilization(env: &Env, asset_key: &AssetKey) -> AssetUtilization {
+    env.storage()
+        .persistent()
+        .get(&(UTILIZATION, asset_key.clone()))
+        .unwrap_or(AssetUtilization {
+            premium_volume: 0,
+            claim_volume: 0,
+            premium_count: 0,
+            claim_count: 0,
+            last_updated: 0,
+        })
+}
+
+/// Adds or removes `asset` from the cached list stored under `key` when its
+/// membership changes.
+fn sync_cached_list(env: &Env, key: &Symbol, asset: &Asset, was_listed: bool, listed: bool) {
+    if was_listed == listed {
+        return;
+    }
+
+    let mut list: Vec<Asset> = env
+        .storage()
+        .persistent()
+        .get(key)
+        .unwrap_or_else(|| Vec::new(env));
+    if listed {
+        list.push_back(asset.clone());
+    } else if let Some(index) = list.first_index_of(asset) {
+        list.remove(index);
+    }
+    env.storage().persistent().set(key, &list);
+}
+
+/// Keeps the cached premium/claim asset lists and active count consistent with
+/// a metadata change. `previous` is `None` for a newly registered asset.
+fn update_asset_caches(env: &Env, previous: Option<&AssetMetadata>, current: &AssetMetadata) {
+    let was_active = previous.map(|m| m.is_active).unwrap_or(false);
+    let was_premium = previous.map(|m| m.is_active && m.accept_for_premium).unwrap_or(false);
+    let was_claims = previous.map(|m| m.is_active && m.accept_for_claims).unwrap_or(false);
+
+    sync_cached_list(
+        env,
+        &PREMIUM_ASSETS,
+        &current.asset,
+        was_premium,
+        current.is_active && current.accept_for_premium,
+    );
+    sync_cached_list(
+        env,
+        &CLAIM_ASSETS,
+        &current.asset,
+        was_claims,
+        current.is_active && current.accept_for_claims,
+    );
+
+    if was_active != current.is_active {
+        let active_count: u32 = env.storage().persistent().get(&ACTIVE_COUNT).unwrap_or(0u32);
+        let active_count = if current.is_active {
+            active_count + 1
+        } else {
+            active_count.saturating_sub(1)
+        };
+        env.storage().persistent().set(&ACTIVE_COUNT, &active_count);
+    }
+}
+
+/// When the registry requires an oracle, the registration must name a price
+/// feed that the configured oracle contract can serve
+fn require_price_feed(
+    env: &Env,
+    registration: &AssetRegistration,
+) -> Result<(), AssetRegistryError> {
+    let config: Option<RegistryConfig> = env.storage().persistent().get(&CONFIG);
+    let config = match config {
+        Some(config) if config.require_oracle => config,
+        _ => return Ok(()),
+    };
+
+    let oracle = config.oracle_contract.ok_or(AssetRegistryError::OracleRequired)?;
+    let feed_id = registration.price_feed_id.ok_or(AssetRegistryError::OracleRequired)?;
+
+    let feed = env.try_invoke_contract::<Val, soroban_sdk::Error>(
+        &oracle,
+        &Symbol::new(env, "get_oracle_data"),
+        (feed_id,).into_val(env),
+    );
+    match feed {
+        Ok(Ok(_)) => Ok(()),
+        _ => Err(AssetRegistryError::OracleRequired),
+    }
+}
+
 fn validate_asset_registration(
     registration: &AssetRegistration,
 ) -> Result<(), AssetRegistryError> {
@@ -134,21 +350,130 @@ fn validate_asset_registration(
     Ok(())
 }
 
+/// Validate a registration and check the asset is not already registered
+fn check_registration(
+    env: &Env,
+    registration: &AssetRegistration,
+) -> Result<(), AssetRegistryError> {
+    validate_asset_registration(registration)?;
+
+    let asset_key = get_asset_key(&registration.asset);
+    if env.storage().persistent().has(&(symbol_short!("ASSET"), asset_key)) {
+        return Err(AssetRegistryError::AssetAlreadyExists);
+    }
+
+    require_price_feed(env, registration)
+}
+
+/// Check every registration in a batch without writing anything. Fails with
+/// the index and error of the first entry that cannot be registered.
+fn check_batch(
+    env: &Env,
+    registrations: &Vec<AssetRegistration>,
+) -> Result<(), (u32, AssetRegistryError)> {
+    if registrations.is_empty() || registrations.len() > MAX_BATCH_REGISTRATIONS {
+        return Err((0, AssetRegistryError::InvalidInput));
+    }
+
+    let mut seen: Vec<AssetKey> = Vec::new(env);
+    for (index, registration) in registrations.iter().enumerate() {
+        let index = index as u32;
+        check_registration(env, &registration).map_err(|err| (index, err))?;
+
+        let asset_key = get_asset_key(&registration.asset);
+        if seen.contains(&asset_key) {
+            return Err((index, AssetRegistryError::AssetAlreadyExists));
+        }
+        seen.push_back(asset_key);
+    }
+    Ok(())
+}
+
+/// Store a checked registration: metadata, price feed, caches, index and
+/// supported list
+fn store_registration(env: &Env, registration: AssetRegistration) {
+    let asset_key = get_asset_key(&registration.asset);
+
+    if let Some(feed_id) = registration.price_feed_id {
+        env.storage().persistent().set(&(PRICE_FEED, asset_key.clone()), &feed_id);
+    }
+
+    let metadata = AssetMetadata {
+        asset: registration.asset.clone(),
+        symbol: registration.symbol,
+        name: registration.name,
+        decimals: registration.decimals,
+        is_active: true,
+        accept_for_premium: registration.accept_for_premium,
+        accept_for_claims: registration.accept_for_claims,
+        min_amount: registration.min_amount,
+        max_amount: registration.max_amount,
+        registered_at: env.ledger().timestamp(),
+    };
+
+    // Store asset metadata
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("ASSET"), asset_key.clone()), &metadata);
+    update_asset_caches(env, None, &metadata);
+
+    env.events().publish(
+        (Symbol::new(env, "asset_registered"), asset_key),
+        (
+            metadata.symbol.clone(),
+            metadata.decimals,
+            metadata.accept_for_premium,
+            metadata.accept_for_claims,
+        ),
+    );
+
+    // Update asset count and index the asset at its registration position
+    let current_count: u32 = env.storage().persistent().get(&ASSET_COUNT).unwrap_or(0u32);
+    env.storage()
+        .persistent()
+        .set(&(ASSET_INDEX, current_count), &registration.asset);
+    env.storage().persistent().set(&ASSET_COUNT, &(current_count + 1));
+
+    // Add to supported list
+    let mut supported_list: Vec<Asset> = env
+        .storage()
+        .persistent()
+        .get(&SUPPORTED_ASSETS)
+        .unwrap_or_else(|| Vec::new(env));
+    supported_list.push_back(registration.asset);
+    env.storage().persistent().set(&SUPPORTED_ASSETS, &supported_list);
+}
+
 // ============================================================================
 // Contract Implementation
 // ============================================================================
 
 #[contractimpl]
 impl AssetRegistryContract {
-    /// Initialize the asset registry contract
+    /// Initialize the asset registry contract with no oracle requirement
     pub fn initialize(env: Env, admin: Address) -> Result<(), AssetRegistryError> {
+        Self::initialize_with_config(
+            env,
+            RegistryConfig { admin, oracle_contract: None, require_oracle: false },
+        )
+    }
+
+    /// Initialize the asset registry contract with a full configuration.
+    /// With `require_oracle` set, every registered asset needs a price feed
+    /// served by `oracle_contract`.
+    pub fn initialize_with_config(
+        env: Env,
+        config: RegistryConfig,
+    ) -> Result<(), AssetRegistryError> {
         if env.storage().persistent().has(&ADMIN) {
             return Err(AssetRegistryError::AlreadyInitialized);
         }
 
+        let admin = config.admin.clone();
         admin.require_auth();
 
         env.storage().persistent().set(&ADMIN, &admin);
+        env.storage().persistent().set(&CONFIG, &config);
         env.storage().persistent().set(&PAUSED, &false);
         env.storage().persistent().set(&ASSET_COUNT, &0u32);
 
@@ -160,7 +485,7 @@ impl AssetRegistryContract {
         let xlm_metadata = AssetMetadata {
             asset: Asset::Native,
             symbol: symbol_short!("XLM"),
-            name: Symbol::new(&env, "Stellar Lumens"),
+            name: Symbol::new(&env, "Stellar_Lumens"),
             decimals: 7,
             is_active: true,
             accept_for_premium: true,
@@ -173,8 +498,10 @@ impl AssetRegistryContract {
         env.storage()
             .persistent()
             .set(&(symbol_short!("ASSET"), get_asset_key(&Asset::Native)), &xlm_metadata);
+        update_asset_caches(&env, None, &xlm_metadata);
 
-        // Update asset count
+        // Update asset count and index
+        env.storage().persistent().set(&(ASSET_INDEX, 0u32), &Asset::Native);
         env.storage().persistent().set(&ASSET_COUNT, &1u32);
 
         // Add to supported list
@@ -210,51 +537,54 @@ impl AssetRegistryContract {
         let admin = require_admin(&env)?;
         admin.require_auth();
 
-        validate_asset_registration(&registration)?;
+        check_registration(&env, &registration)?;
+        store_registration(&env, registration);
 
-        let asset_key = get_asset_key(&registration.asset);
+        Ok(())
+    }
 
-        // Check if asset already exists
-        if env.storage().persistent().has(&(symbol_short!("ASSET"), asset_key.clone())) {
-            return Err(AssetRegistryError::AssetAlreadyExists);
+    /// Register several assets in one call. Every entry is checked before any
+    /// is stored, so the batch is all-or-nothing: the first invalid or
+    /// duplicate entry (including a repeat within the batch) is returned as
+    /// `Rejected` with its index, and a `batch_rejected` event, and nothing is
+    /// registered. An empty or oversized batch fails with `InvalidInput`.
+    pub fn register_assets(
+        env: Env,
+        registrations: Vec<AssetRegistration>,
+    ) -> Result<BatchRegistrationResult, AssetRegistryError> {
+        if is_paused(&env) {
+            return Err(AssetRegistryError::Paused);
         }
 
-        let metadata = AssetMetadata {
-            asset: registration.asset.clone(),
-            symbol: registration.symbol,
-            name: registration.name,
-            decimals: registration.decimals,
-            is_active: true,
-            accept_for_premium: registration.accept_for_premium,
-            accept_for_claims: registration.accept_for_claims,
-            min_amount: registration.min_amount,
-            max_amount: registration.max_amount,
-            registered_at: env.ledger().timestamp(),
-        };
+        let admin = require_admin(&env)?;
+        admin.require_auth();
 
-        // Store asset metadata
-        env.storage()
-            .persistent()
-            .set(&(symbol_short!("ASSET"), asset_key), &metadata);
+        if registrations.is_empty() || registrations.len() > MAX_BATCH_REGISTRATIONS {
+            return Err(AssetRegistryError::InvalidInput);
+        }
 
-        // Update asset count
-        let current_count: u32 = env
-            .storage()
-            .persistent()
-            .get(&ASSET_COUNT)
-            .unwrap_or(0u32);
-        env.storage().persistent().set(&ASSET_COUNT, &(current_count + 1));
+        if let Err((index, err)) = check_batch(&env, &registrations) {
+            env.events()
+                .publish((Symbol::new(&env, "batch_rejected"), index), err as u32);
+            return Ok(BatchRegistrationResult::Rejected(BatchFailure {
+                index,
+                error_code: err as u32,
+            }));
+        }
+        for registration in registrations.iter() {
+            store_registration(&env, registration);
+        }
 
-        // Add to supported list
-        let mut supported_list: Vec<Asset> = env
-            .storage()
-            .persistent()
-            .get(&SUPPORTED_ASSETS)
-            .unwrap_or_else(|| Vec::new(&env));
-        supported_list.push_back(registration.asset);
-        env.storage().persistent().set(&SUPPORTED_ASSETS, &supported_list);
+        Ok(BatchRegistrationResult::Registered(registrations.len()))
+    }
 
-        Ok(())
+    /// Dry run of `register_assets`: the index and error of the first entry
+    /// that would fail, or `None` if the whole batch would register
+    pub fn validate_asset_batch(
+        env: Env,
+        registrations: Vec<AssetRegistration>,
+    ) -> Option<(u32, AssetRegistryError)> {
+        check_batch(&env, &registrations).err()
     }
 
     /// Update asset status (active/inactive)
@@ -273,11 +603,15 @@ impl AssetRegistryContract {
             .get(&(symbol_short!("ASSET"), asset_key.clone()))
             .ok_or(AssetRegistryError::AssetNotFound)?;
 
+        let previous = metadata.clone();
         metadata.is_active = is_active;
 
         env.storage()
             .persistent()
-            .set(&(symbol_short!("ASSET"), asset_key), &metadata);
+            .set(&(symbol_short!("ASSET"), asset_key.clone()), &metadata);
+        update_asset_caches(&env, Some(&previous), &metadata);
+
+        env.events().publish((Symbol::new(&env, "asset_status_updated"), asset_key), is_active);
 
         Ok(())
     }
@@ -301,6 +635,7 @@ impl AssetRegistryContract {
             .get(&(symbol_short!("ASSET"), asset_key.clone()))
             .ok_or(AssetRegistryError::AssetNotFound)?;
 
+        let previous = metadata.clone();
         if let Some(premium) = accept_for_premium {
             metadata.accept_for_premium = premium;
         }
@@ -316,11 +651,126 @@ impl AssetRegistryContract {
 
         env.storage()
             .persistent()
-            .set(&(symbol_short!("ASSET"), asset_key), &metadata);
+            .set(&(symbol_short!("ASSET"), asset_key.clone()), &metadata);
+        update_asset_caches(&env, Some(&previous), &metadata);
+
+        env.events().publish(
+            (Symbol::new(&env, "asset_config_updated"), asset_key),
+            (
+                metadata.accept_for_premium,
+                metadata.accept_for_claims,
+                metadata.min_amount,
+                metadata.max_amount,
+            ),
+        );
 
         Ok(())
     }
 
+    /// Upgrade storage written before assets were keyed by `AssetKey` (admin
+    /// only): moves asset records off their legacy `Symbol` keys, then rebuilds
+    /// the premium/claim caches, the active count and the registration index
+    /// from the supported list. Conversion rates are moved separately by
+    /// `migrate_conversion_rates`. Safe to run again.
+    pub fn migrate_storage(env: Env) -> Result<(), AssetRegistryError> {
+        let admin = require_admin(&env)?;
+        admin.require_auth();
+
+        let supported = Self::get_supported_assets(env.clone());
+        let mut premium_assets: Vec<Asset> = Vec::new(&env);
+        let mut claim_assets: Vec<Asset> = Vec::new(&env);
+        let mut active_count = 0u32;
+
+        for (index, asset) in supported.iter().enumerate() {
+            let asset_key = (symbol_short!("ASSET"), get_asset_key(&asset));
+            let legacy_key = (symbol_short!("ASSET"), legacy_asset_key(&asset));
+            let legacy: Option<AssetMetadata> = env.storage().persistent().get(&legacy_key);
+            if let Some(metadata) = legacy {
+                if metadata.asset == asset {
+                    env.storage().persistent().set(&asset_key, &metadata);
+                    env.storage().persistent().remove(&legacy_key);
+                }
+            }
+
+            if let Some(metadata) = env.storage().persistent().get::<_, AssetMetadata>(&asset_key) {
+                if metadata.is_active {
+                    active_count += 1;
+                    if metadata.accept_for_premium {
+                        premium_assets.push_back(asset.clone());
+                    }
+                    if metadata.accept_for_claims {
+                        claim_assets.push_back(asset.clone());
+                    }
+                }
+            }
+
+            env.storage().persistent().set(&(ASSET_INDEX, index as u32), &asset);
+        }
+
+        env.storage().persistent().set(&PREMIUM_ASSETS, &premium_assets);
+        env.storage().persistent().set(&CLAIM_ASSETS, &claim_assets);
+        env.storage().persistent().set(&ACTIVE_COUNT, &active_count);
+        env.storage().persistent().set(&ASSET_COUNT, &supported.len());
+
+        env.events().publish((Symbol::new(&env, "storage_migrated"),), supported.len());
+
+        Ok(())
+    }
+
+    /// Move the conversion rates for the given `(from, to)` pairs off their
+    /// legacy `Symbol` keys (admin only). Takes an explicit list, at most
+    /// `MAX_RATE_MIGRATIONS` per call, so each call stays within the
+    /// transaction footprint. Returns how many rates were moved; safe to run again.
+    pub fn migrate_conversion_rates(
+        env: Env,
+        pairs: Vec<(Asset, Asset)>,
+    ) -> Result<u32, AssetRegistryError> {
+        let admin = require_admin(&env)?;
+        admin.require_auth();
+
+        if pairs.is_empty() || pairs.len() > MAX_RATE_MIGRATIONS {
+            return Err(AssetRegistryError::InvalidInput);
+        }
+
+        let mut migrated = 0u32;
+        for (from_asset, to_asset) in pairs.iter() {
+            let legacy_key = (
+                symbol_short!("RATE"),
+                legacy_asset_key(&from_asset),
+                legacy_asset_key(&to_asset),
+            );
+            let legacy: Option<AssetConversionRate> = env.storage().persistent().get(&legacy_key);
+            if let Some(rate) = legacy {
+                if rate.from_asset == from_asset && rate.to_asset == to_asset {
+                    env.storage().persistent().set(
+                        &(
+                            symbol_short!("RATE"),
+                            get_asset_key(&from_asset),
+                            get_asset_key(&to_asset),
+                        ),
+                        &rate,
+                    );
+                    env.storage().persistent().remove(&legacy_key);
+                    migrated += 1;
+                }
+            }
+        }
+
+        env.events().publish((Symbol::new(&env, "rates_migrated"),), migrated);
+
+        Ok(migrated)
+    }
+
+    /// Get the registry configuration
+    pub fn get_config(env: Env) -> Result<RegistryConfig, AssetRegistryError> {
+        env.storage().persistent().get(&CONFIG).ok_or(AssetRegistryError::NotInitialized)
+    }
+
+    /// Get the oracle price feed id recorded for an asset, if any
+    pub fn get_price_feed(env: Env, asset: Asset) -> Option<u64> {
+        env.storage().persistent().get(&(PRICE_FEED, get_asset_key(&asset)))
+    }
+
     /// Get asset metadata
     pub fn get_asset_metadata(env: Env, asset: Asset) -> Result<AssetMetadata, AssetRegistryError> {
         let asset_key = get_asset_key(&asset);
@@ -380,72 +830,61 @@ impl AssetRegistryContract {
             .unwrap_or_else(|| Vec::new(&env))
     }
 
-    /// Get list of assets that accept premiums
-    pub fn get_premium_assets(env: Env) -> Vec<Asset> {
-        let all_assets: Vec<Asset> = env
-            .storage()
-            .persistent()
-            .get(&SUPPORTED_ASSETS)
-            .unwrap_or_else(|| Vec::new(&env));
+    /// Get a page of supported assets in registration order, reading only the
+    /// indexed entries for that page rather than the full list.
+    ///
+    /// `limit` is capped at 50; zero means the maximum. A `start_index` past
+    /// the end returns an empty page.
+    pub fn get_supported_assets_paginated(
+        env: Env,
+        start_index: u32,
+        limit: u32,
+    ) -> PaginatedAssetsResult {
+        let effective_limit = if limit == 0 || limit > MAX_PAGINATION_LIMIT {
+            MAX_PAGINATION_LIMIT
+        } else {
+            limit
+        };
 
-        let mut premium_assets: Vec<Asset> = Vec::new(&env);
-        for i in 0..all_assets.len() {
-            let asset = all_assets.get(i).unwrap();
-            if Self::accepts_premium(env.clone(), asset.clone()) {
-                premium_assets.push_back(asset);
+        let total_count: u32 = env.storage().persistent().get(&ASSET_COUNT).unwrap_or(0u32);
+        let mut assets: Vec<Asset> = Vec::new(&env);
+        if start_index >= total_count {
+            return PaginatedAssetsResult { assets, total_count };
+        }
+
+        let end_index = core::cmp::min(start_index.saturating_add(effective_limit), total_count);
+        for i in start_index..end_index {
+            if let Some(asset) = env.storage().persistent().get(&(ASSET_INDEX, i)) {
+                assets.push_back(asset);
             }
         }
-        premium_assets
+
+        PaginatedAssetsResult { assets, total_count }
+    }
+
+    /// Get list of assets that accept premiums
+    pub fn get_premium_assets(env: Env) -> Vec<Asset> {
+        env.storage()
+            .persistent()
+            .get(&PREMIUM_ASSETS)
+            .unwrap_or_else(|| Vec::new(&env))
     }
 
     /// Get list of assets that accept claims
     pub fn get_claim_assets(env: Env) -> Vec<Asset> {
-        let all_assets: Vec<Asset> = env
-            .storage()
+        env.storage()
             .persistent()
-            .get(&SUPPORTED_ASSETS)
-            .unwrap_or_else(|| Vec::new(&env));
-
-        let mut claim_assets: Vec<Asset> = Vec::new(&env);
-        for i in 0..all_assets.len() {
-            let asset = all_assets.get(i).unwrap();
-            if Self::accepts_claims(env.clone(), asset.clone()) {
-                claim_assets.push_back(asset);
-            }
-        }
-        claim_assets
+            .get(&CLAIM_ASSETS)
+            .unwrap_or_else(|| Vec::new(&env))
     }
 
     /// Get asset registry summary
     pub fn get_registry_summary(env: Env) -> AssetRegistrySummary {
-        let all_assets: Vec<Asset> = env
-            .storage()
-            .persistent()
-            .get(&SUPPORTED_ASSETS)
-            .unwrap_or_else(|| Vec::new(&env));
-
-        let mut active_count = 0u32;
-        let mut premium_count = 0u32;
-        let mut claim_count = 0u32;
-
-        for i in 0..all_assets.len() {
-            let asset = all_assets.get(i).unwrap();
-            if Self::is_asset_active(env.clone(), asset.clone()) {
-                active_count += 1;
-            }
-            if Self::accepts_premium(env.clone(), asset.clone()) {
-                premium_count += 1;
-            }
-            if Self::accepts_claims(env.clone(), asset.clone()) {
-                claim_count += 1;
-            }
-        }
-
         AssetRegistrySummary {
-            total_assets: all_assets.len(),
-            active_assets: active_count,
-            premium_assets: premium_count,
-            claim_assets: claim_count,
+            total_assets: env.storage().persistent().get(&ASSET_COUNT).unwrap_or(0u32),
+            active_assets: env.storage().persistent().get(&ACTIVE_COUNT).unwrap_or(0u32),
+            premium_assets: Self::get_premium_assets(env.clone()).len(),
+            claim_assets: Self::get_claim_assets(env).len(),
         }
     }
 
@@ -487,6 +926,15 @@ impl AssetRegistryContract {
             &conversion_rate,
         );
 
+        env.events().publish(
+            (
+                Symbol::new(&env, "conversion_rate_set"),
+                get_asset_key(&from_asset),
+                get_asset_key(&to_asset),
+            ),
+            rate_bps,
+        );
+
         Ok(())
     }
 
@@ -506,7 +954,8 @@ impl AssetRegistryContract {
             .ok_or(AssetRegistryError::ConversionRateNotFound)
     }
 
-    /// Convert amount from one asset to another
+    /// Convert amount from one asset to another, deriving the rate from the
+    /// opposite direction when only that one is set
     pub fn convert_amount(
         env: Env,
         from_asset: Asset,
@@ -522,15 +971,212 @@ impl AssetRegistryContract {
             return Ok(amount);
         }
 
-        let rate = Self::get_conversion_rate(env, from_asset, to_asset)?;
+        match Self::get_conversion_rate(env.clone(), from_asset.clone(), to_asset.clone()) {
+            // Convert: (amount * rate_bps) / 10000
+            Ok(rate) => amount
+                .checked_mul(rate.rate_bps as i128)
+                .and_then(|v| v.checked_div(10000))
+                .ok_or(AssetRegistryError::InvalidConversionRate),
+            // Fall back to the inverse of the opposite direction:
+            // (amount * 10000) / rate_bps
+            Err(AssetRegistryError::ConversionRateNotFound) => {
+                let inverse = Self::get_conversion_rate(env, to_asset, from_asset)?;
+                if inverse.rate_bps == 0 {
+                    return Err(AssetRegistryError::InvalidConversionRate);
+                }
+
+                let converted = amount
+                    .checked_mul(10000)
+                    .and_then(|v| v.checked_div(inverse.rate_bps as i128))
+                    .ok_or(AssetRegistryError::InvalidConversionRate)?;
+
+                // A positive amount must not silently round down to nothing
+                if converted == 0 {
+                    return Err(AssetRegistryError::InvalidConversionRate);
+                }
+                Ok(converted)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Set a tiered rate schedule for a pair (admin only).
+    /// Bands must be sorted by strictly increasing `min_amount`; an empty
+    /// schedule removes it so quotes fall back to the flat rate.
+    pub fn set_conversion_tiers(
+        env: Env,
+        from_asset: Asset,
+        to_asset: Asset,
+        tiers: Vec<RateTier>,
+    ) -> Result<(), AssetRegistryError> {
+        let admin = require_admin(&env)?;
+        admin.require_auth();
+
+        let from_key = get_asset_key(&from_asset);
+        let to_key = get_asset_key(&to_asset);
+
+        if !env.storage().persistent().has(&(symbol_short!("ASSET"), from_key.clone())) {
+            return Err(AssetRegistryError::AssetNotFound);
+        }
+        if !env.storage().persistent().has(&(symbol_short!("ASSET"), to_key.clone())) {
+            return Err(AssetRegistryError::AssetNotFound);
+        }
+
+        let key = (RATE_TIERS, from_key, to_key);
+        if tiers.is_empty() {
+            env.storage().persistent().remove(&key);
+            return Ok(());
+        }
+
+        let mut previous_min: Option<i128> = None;
+        for tier in tiers.iter() {
+            if tier.rate_bps == 0 {
+                return Err(AssetRegistryError::InvalidConversionRate);
+            }
+            if tier.min_amount < 0 || previous_min.map_or(false, |min| tier.min_amount <= min) {
+                return Err(AssetRegistryError::InvalidInput);
+            }
+            previous_min = Some(tier.min_amount);
+        }
+
+        env.storage().persistent().set(&key, &tiers);
+
+        Ok(())
+    }
+
+    /// Get the tiered rate schedule for a pair (empty if none is set)
+    pub fn get_conversion_tiers(env: Env, from_asset: Asset, to_asset: Asset) -> Vec<RateTier> {
+        env.storage()
+            .persistent()
+            .get(&(RATE_TIERS, get_asset_key(&from_asset), get_asset_key(&to_asset)))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Quote a conversion using the band of the pair's tiered schedule that
+    /// `amount` falls into, falling back to the flat rate when there is no
+    /// schedule or the amount is below the first band
+    pub fn get_conversion_quote(
+        env: Env,
+        from_asset: Asset,
+        to_asset: Asset,
+        amount: i128,
+    ) -> Result<i128, AssetRegistryError> {
+        if amount <= 0 {
+            return Err(AssetRegistryError::InvalidInput);
+        }
+
+        if get_asset_key(&from_asset) == get_asset_key(&to_asset) {
+            return Ok(amount);
+        }
+
+        let tiers = Self::get_conversion_tiers(env.clone(), from_asset.clone(), to_asset.clone());
+        let rate_bps = match select_tier_rate(&tiers, amount) {
+            Some(rate_bps) => rate_bps,
+            None => Self::get_conversion_rate(env, from_asset, to_asset)?.rate_bps,
+        };
 
-        // Convert: (amount * rate_bps) / 10000
-        let converted = amount
-            .checked_mul(rate.rate_bps as i128)
+        amount
+            .checked_mul(rate_bps as i128)
             .and_then(|v| v.checked_div(10000))
-            .ok_or(AssetRegistryError::InvalidConversionRate)?;
+            .ok_or(AssetRegistryError::InvalidConversionRate)
+    }
+
+    /// Allow a contract to report premium and claim volume (admin only)
+    pub fn register_trusted_contract(
+        env: Env,
+        contract_address: Address,
+    ) -> Result<(), AssetRegistryError> {
+        let admin = require_admin(&env)?;
+        admin.require_auth();
+
+        env.storage().persistent().set(&(TRUSTED, contract_address), &true);
+        Ok(())
+    }
+
+    /// Revoke a contract's permission to report volume (admin only)
+    pub fn unregister_trusted_contract(
+        env: Env,
+        contract_address: Address,
+    ) -> Result<(), AssetRegistryError> {
+        let admin = require_admin(&env)?;
+        admin.require_auth();
+
+        env.storage().persistent().remove(&(TRUSTED, contract_address));
+        Ok(())
+    }
+
+    /// Record premium volume paid in an asset (trusted contracts only)
+    pub fn record_premium_volume(
+        env: Env,
+        caller: Address,
+        asset: Asset,
+        amount: i128,
+    ) -> Result<(), AssetRegistryError> {
+        caller.require_auth();
+        require_trusted_contract(&env, &caller)?;
+
+        if amount <= 0 {
+            return Err(AssetRegistryError::InvalidInput);
+        }
+
+        let asset_key = get_asset_key(&asset);
+        if !env.storage().persistent().has(&(symbol_short!("ASSET"), asset_key.clone())) {
+            return Err(AssetRegistryError::AssetNotFound);
+        }
+
+        let mut utilization = get_utilization(&env, &asset_key);
+        utilization.premium_volume = utilization
+            .premium_volume
+            .checked_add(amount)
+            .ok_or(AssetRegistryError::InvalidInput)?;
+        utilization.premium_count += 1;
+        utilization.last_updated = env.ledger().timestamp();
+
+        env.storage().persistent().set(&(UTILIZATION, asset_key), &utilization);
+        Ok(())
+    }
+
+    /// Record claim payout volume paid in an asset (trusted contracts only)
+    pub fn record_claim_volume(
+        env: Env,
+        caller: Address,
+        asset: Asset,
+        amount: i128,
+    ) -> Result<(), AssetRegistryError> {
+        caller.require_auth();
+        require_trusted_contract(&env, &caller)?;
+
+        if amount <= 0 {
+            return Err(AssetRegistryError::InvalidInput);
+        }
+
+        let asset_key = get_asset_key(&asset);
+        if !env.storage().persistent().has(&(symbol_short!("ASSET"), asset_key.clone())) {
+            return Err(AssetRegistryError::AssetNotFound);
+        }
+
+        let mut utilization = get_utilization(&env, &asset_key);
+        utilization.claim_volume = utilization
+            .claim_volume
+            .checked_add(amount)
+            .ok_or(AssetRegistryError::InvalidInput)?;
+        utilization.claim_count += 1;
+        utilization.last_updated = env.ledger().timestamp();
+
+        env.storage().persistent().set(&(UTILIZATION, asset_key), &utilization);
+        Ok(())
+    }
 
-        Ok(converted)
+    /// Get premium and claim volume recorded for an asset
+    pub fn get_asset_utilization(
+        env: Env,
+        asset: Asset,
+    ) -> Result<AssetUtilization, AssetRegistryError> {
+        let asset_key = get_asset_key(&asset);
+        if !env.storage().persistent().has(&(symbol_short!("ASSET"), asset_key.clone())) {
+            return Err(AssetRegistryError::AssetNotFound);
+        }
+        Ok(get_utilization(&env, &asset_key))
     }
 
     /// Validate amount is within asset bounds
@@ -555,7 +1201,22 @@ impl AssetRegistryContract {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Events};
+    use soroban_sdk::{vec, xdr, IntoVal, TryFromVal, Val};
+
+    /// Last emitted event as `(contract, topics, data)`
+    fn last_event(env: &Env) -> (Address, Vec<Val>, Val) {
+        let event = env.events().all().events().last().unwrap().clone();
+        let xdr::ContractEventBody::V0(body) = event.body;
+        let contract =
+            Address::try_from_val(env, &xdr::ScAddress::Contract(event.contract_id.unwrap()))
+                .unwrap();
+        let mut topics = Vec::new(env);
+        for topic in body.topics.iter() {
+            topics.push_back(Val::try_from_val(env, topic).unwrap());
+        }
+        (contract, topics, Val::try_from_val(env, &body.data).unwrap())
+    }
 
     fn setup_env() -> (Env, Address) {
         let env = Env::default();
@@ -593,6 +1254,7 @@ mod tests {
             max_amount: 1_000_000_000_000_000,
             accept_for_premium: true,
             accept_for_claims: true,
+            price_feed_id: None,
         };
 
         let result = AssetRegistryContract::register_asset(env.clone(), registration);
@@ -618,6 +1280,7 @@ mod tests {
             max_amount: 1_000_000_000_000_000,
             accept_for_premium: true,
             accept_for_claims: true,
+            price_feed_id: None,
         };
 
         AssetRegistryContract::register_asset(env.clone(), registration.clone()).unwrap();
@@ -640,6 +1303,7 @@ mod tests {
             max_amount: 1_000_000_000_000_000,
             accept_for_premium: true,
             accept_for_claims: true,
+            price_feed_id: None,
         };
 
         AssetRegistryContract::register_asset(env.clone(), registration).unwrap();
@@ -666,6 +1330,7 @@ mod tests {
             max_amount: 1_000_000_000_000_000,
             accept_for_premium: true,
             accept_for_claims: true,
+            price_feed_id: None,
         };
         AssetRegistryContract::register_asset(env.clone(), registration).unwrap();
 
@@ -713,4 +1378,688 @@ mod tests {
         assert_eq!(summary.premium_assets, 1);
         assert_eq!(summary.claim_assets, 1);
     }
+
+    fn setup_client(env: &Env) -> (AssetRegistryContractClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register(AssetRegistryContract, ());
+        let client = AssetRegistryContractClient::new(env, &contract_id);
+        let admin = Address::generate(env);
+        client.initialize(&admin);
+        (client, admin)
+    }
+
+    fn usdc_registration(env: &Env) -> AssetRegistration {
+        AssetRegistration {
+            asset: Asset::Stellar((symbol_short!("USDC"), Address::generate(env))),
+            symbol: symbol_short!("USDC"),
+            name: Symbol::new(env, "USD_Coin"),
+            decimals: 7,
+            min_amount: 1_000_000,
+            max_amount: 1_000_000_000_000_000,
+            accept_for_premium: true,
+            accept_for_claims: true,
+            price_feed_id: None,
+        }
+    }
+
+    /// Recomputes the premium/claim lists and summary by scanning every asset and
+    /// asserts the cached getters agree.
+    fn assert_caches_match_scan(env: &Env, client: &AssetRegistryContractClient) {
+        let all_assets = client.get_supported_assets();
+        let mut premium: Vec<Asset> = Vec::new(env);
+        let mut claims: Vec<Asset> = Vec::new(env);
+        let mut active = 0u32;
+        for asset in all_assets.iter() {
+            if client.is_asset_active(&asset) {
+                active += 1;
+            }
+            if client.accepts_premium(&asset) {
+                premium.push_back(asset.clone());
+            }
+            if client.accepts_claims(&asset) {
+                claims.push_back(asset);
+            }
+        }
+
+        let cached_premium = client.get_premium_assets();
+        let cached_claims = client.get_claim_assets();
+        assert_eq!(cached_premium.len(), premium.len());
+        assert_eq!(cached_claims.len(), claims.len());
+        for asset in premium.iter() {
+            assert!(cached_premium.contains(&asset));
+        }
+        for asset in claims.iter() {
+            assert!(cached_claims.contains(&asset));
+        }
+
+        let summary = client.get_registry_summary();
+        assert_eq!(summary.total_assets, all_assets.len());
+        assert_eq!(summary.active_assets, active);
+        assert_eq!(summary.premium_assets, premium.len());
+        assert_eq!(summary.claim_assets, claims.len());
+    }
+
+    mod price_oracle {
+        use soroban_sdk::{contract, contractimpl, Env};
+
+        #[contract]
+        pub struct PriceOracle;
+
+        #[contractimpl]
+        impl PriceOracle {
+            pub fn set_price(env: Env, data_id: u64, price: i128) {
+                env.storage().instance().set(&data_id, &price);
+            }
+
+            pub fn get_oracle_data(env: Env, data_id: u64) -> i128 {
+                env.storage().instance().get(&data_id).expect("no data for feed")
+            }
+        }
+    }
+
+    fn setup_with_oracle(env: &Env, oracle: Option<Address>) -> AssetRegistryContractClient<'_> {
+        env.mock_all_auths();
+        let contract_id = env.register(AssetRegistryContract, ());
+        let client = AssetRegistryContractClient::new(env, &contract_id);
+        client.initialize_with_config(&RegistryConfig {
+            admin: Address::generate(env),
+            oracle_contract: oracle,
+            require_oracle: true,
+        });
+        client
+    }
+
+    #[test]
+    fn test_required_oracle_enforced_on_registration() {
+        let env = Env::default();
+        let oracle_id = env.register(price_oracle::PriceOracle, ());
+        price_oracle::PriceOracleClient::new(&env, &oracle_id).set_price(&7, &1_000);
+        let client = setup_with_oracle(&env, Some(oracle_id.clone()));
+        assert!(client.get_config().require_oracle);
+
+        // No feed named
+        let mut usdc = usdc_registration(&env);
+        assert_eq!(client.try_register_asset(&usdc), Err(Ok(AssetRegistryError::OracleRequired)));
+
+        // Feed the oracle has no data for
+        usdc.price_feed_id = Some(8);
+        assert_eq!(client.try_register_asset(&usdc), Err(Ok(AssetRegistryError::OracleRequired)));
+
+        usdc.price_feed_id = Some(7);
+        client.register_asset(&usdc);
+        assert!(client.is_asset_active(&usdc.asset));
+        assert_eq!(client.get_price_feed(&usdc.asset), Some(7));
+    }
+
+    #[test]
+    fn test_required_oracle_without_oracle_contract_rejects() {
+        let env = Env::default();
+        let client = setup_with_oracle(&env, None);
+
+        let mut usdc = usdc_registration(&env);
+        usdc.price_feed_id = Some(7);
+        assert_eq!(client.try_register_asset(&usdc), Err(Ok(AssetRegistryError::OracleRequired)));
+    }
+
+    #[test]
+    fn test_oracle_not_required_by_default() {
+        let env = Env::default();
+        let (client, admin) = setup_client(&env);
+
+        let config = client.get_config();
+        assert_eq!(config.admin, admin);
+        assert!(!config.require_oracle);
+
+        let usdc = usdc_registration(&env);
+        client.register_asset(&usdc);
+        assert_eq!(client.get_price_feed(&usdc.asset), None);
+        assert_eq!(
+            client.try_initialize_with_config(&config),
+            Err(Ok(AssetRegistryError::AlreadyInitialized))
+        );
+    }
+
+    #[test]
+    fn test_mutations_emit_events() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+
+        let usdc = usdc_registration(&env);
+        let usdc_key = AssetKey::Code(symbol_short!("USDC"));
+        let xlm_key = AssetKey::Code(symbol_short!("XLM"));
+
+        client.register_asset(&usdc);
+        let (contract, topics, data) = last_event(&env);
+        assert_eq!(contract, client.address);
+        assert_eq!(
+            topics,
+            vec![
+                &env,
+                Symbol::new(&env, "asset_registered").into_val(&env),
+                usdc_key.into_val(&env)
+            ]
+        );
+        let data: (Symbol, u32, bool, bool) = data.into_val(&env);
+        assert_eq!(data, (symbol_short!("USDC"), 7, true, true));
+
+        client.set_asset_status(&usdc.asset, &false);
+        let (_, topics, data) = last_event(&env);
+        assert_eq!(
+            topics,
+            vec![
+                &env,
+                Symbol::new(&env, "asset_status_updated").into_val(&env),
+                usdc_key.into_val(&env)
+            ]
+        );
+        let is_active: bool = data.into_val(&env);
+        assert!(!is_active);
+
+        client.update_asset_config(&usdc.asset, &Some(false), &None, &None, &Some(5_000_000));
+        let (_, topics, data) = last_event(&env);
+        assert_eq!(
+            topics,
+            vec![
+                &env,
+                Symbol::new(&env, "asset_config_updated").into_val(&env),
+                usdc_key.into_val(&env)
+            ]
+        );
+        let data: (bool, bool, i128, i128) = data.into_val(&env);
+        assert_eq!(data, (false, true, 1_000_000, 5_000_000));
+
+        client.set_conversion_rate(&Asset::Native, &usdc.asset, &2_500);
+        let (_, topics, data) = last_event(&env);
+        assert_eq!(
+            topics,
+            vec![
+                &env,
+                Symbol::new(&env, "conversion_rate_set").into_val(&env),
+                xlm_key.into_val(&env),
+                usdc_key.into_val(&env)
+            ]
+        );
+        let rate_bps: u32 = data.into_val(&env);
+        assert_eq!(rate_bps, 2_500);
+    }
+
+    #[test]
+    fn test_migrate_storage_moves_legacy_keys_and_rebuilds_caches() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(AssetRegistryContract, ());
+        let client = AssetRegistryContractClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let usdc = usdc_registration(&env);
+
+        let metadata = |asset: &Asset, symbol: Symbol, accept_for_claims: bool| AssetMetadata {
+            asset: asset.clone(),
+            symbol: symbol.clone(),
+            name: symbol,
+            decimals: 7,
+            is_active: true,
+            accept_for_premium: true,
+            accept_for_claims,
+            min_amount: 1,
+            max_amount: 1_000_000,
+            registered_at: 0,
+        };
+
+        // State as written before assets were keyed by `AssetKey`
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().persistent();
+            storage.set(&ADMIN, &admin);
+            storage.set(&ASSET_COUNT, &2u32);
+            storage.set(&SUPPORTED_ASSETS, &vec![&env, Asset::Native, usdc.asset.clone()]);
+            storage.set(
+                &(symbol_short!("ASSET"), symbol_short!("XLM")),
+                &metadata(&Asset::Native, symbol_short!("XLM"), true),
+            );
+            storage.set(
+                &(symbol_short!("ASSET"), symbol_short!("USDC")),
+                &metadata(&usdc.asset, symbol_short!("USDC"), false),
+            );
+            storage.set(
+                &(symbol_short!("RATE"), symbol_short!("XLM"), symbol_short!("USDC")),
+                &AssetConversionRate {
+                    from_asset: Asset::Native,
+                    to_asset: usdc.asset.clone(),
+                    rate_bps: 2_500,
+                    updated_at: 0,
+                    oracle_source: admin.clone(),
+                },
+            );
+        });
+        assert_eq!(
+            client.try_get_asset_metadata(&Asset::Native),
+            Err(Ok(AssetRegistryError::AssetNotFound))
+        );
+
+        client.migrate_storage();
+
+        assert_eq!(client.get_asset_metadata(&Asset::Native).symbol, symbol_short!("XLM"));
+        assert_eq!(client.get_asset_metadata(&usdc.asset).symbol, symbol_short!("USDC"));
+
+        // Rates move only for the pairs listed
+        let pairs = vec![&env, (Asset::Native, usdc.asset.clone())];
+        assert_eq!(client.migrate_conversion_rates(&pairs), 1);
+        assert_eq!(client.get_conversion_rate(&Asset::Native, &usdc.asset).rate_bps, 2_500);
+        assert_eq!(client.migrate_conversion_rates(&pairs), 0);
+        assert_eq!(
+            client.try_migrate_conversion_rates(&Vec::new(&env)),
+            Err(Ok(AssetRegistryError::InvalidInput))
+        );
+        assert_eq!(client.get_premium_assets(), vec![&env, Asset::Native, usdc.asset.clone()]);
+        assert_eq!(client.get_claim_assets(), vec![&env, Asset::Native]);
+        assert_eq!(client.get_registry_summary().active_assets, 2);
+        assert_eq!(
+            client.get_supported_assets_paginated(&0, &10).assets,
+            vec![&env, Asset::Native, usdc.asset.clone()]
+        );
+
+        // Running it again leaves the rebuilt state unchanged
+        client.migrate_storage();
+        assert_eq!(client.get_premium_assets().len(), 2);
+        assert_eq!(client.get_registry_summary().active_assets, 2);
+    }
+
+    #[test]
+    fn test_supported_assets_pagination() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+
+        for _ in 0..59 {
+            client.register_asset(&contract_registration(&env, symbol_short!("TOK")));
+        }
+        let all_assets = client.get_supported_assets();
+        assert_eq!(all_assets.len(), 60);
+
+        let first = client.get_supported_assets_paginated(&0, &25);
+        assert_eq!(first.total_count, 60);
+        assert_eq!(first.assets.len(), 25);
+        assert_eq!(first.assets.get(0).unwrap(), Asset::Native);
+
+        // Walking the pages reproduces the full list in order
+        let mut paged: Vec<Asset> = Vec::new(&env);
+        let mut start = 0u32;
+        loop {
+            let page = client.get_supported_assets_paginated(&start, &25);
+            if page.assets.is_empty() {
+                break;
+            }
+            start += page.assets.len();
+            paged.append(&page.assets);
+        }
+        assert_eq!(paged, all_assets);
+
+        // Limit is capped, zero means the maximum, and out-of-range is empty
+        assert_eq!(client.get_supported_assets_paginated(&0, &500).assets.len(), 50);
+        assert_eq!(client.get_supported_assets_paginated(&0, &0).assets.len(), 50);
+        assert_eq!(client.get_supported_assets_paginated(&55, &25).assets.len(), 5);
+        let past_end = client.get_supported_assets_paginated(&60, &25);
+        assert_eq!(past_end.assets.len(), 0);
+        assert_eq!(past_end.total_count, 60);
+    }
+
+    #[test]
+    fn test_convert_amount_derives_inverse_rate() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+
+        let usdc = usdc_registration(&env);
+        client.register_asset(&usdc);
+        let xlm = Asset::Native;
+
+        // Only XLM -> USDC is set: 1 XLM = 0.25 USDC
+        client.set_conversion_rate(&xlm, &usdc.asset, &2_500);
+        assert_eq!(
+            client.try_get_conversion_rate(&usdc.asset, &xlm),
+            Err(Ok(AssetRegistryError::ConversionRateNotFound))
+        );
+
+        assert_eq!(client.convert_amount(&xlm, &usdc.asset, &1_000), 250);
+        assert_eq!(client.convert_amount(&usdc.asset, &xlm, &250), 1_000);
+
+        // Round trips are consistent where the rate divides evenly
+        for amount in [4i128, 1_000, 123_456_788] {
+            let there = client.convert_amount(&xlm, &usdc.asset, &amount);
+            assert_eq!(client.convert_amount(&usdc.asset, &xlm, &there), amount);
+        }
+
+        // A direct rate takes precedence over the derived one
+        client.set_conversion_rate(&usdc.asset, &xlm, &30_000);
+        assert_eq!(client.convert_amount(&usdc.asset, &xlm, &250), 750);
+    }
+
+    #[test]
+    fn test_inverse_conversion_guards() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+
+        let usdc = usdc_registration(&env);
+        client.register_asset(&usdc);
+        let xlm = Asset::Native;
+
+        assert_eq!(
+            client.try_convert_amount(&usdc.asset, &xlm, &100),
+            Err(Ok(AssetRegistryError::ConversionRateNotFound))
+        );
+
+        // Inverse of a very large rate rounds small amounts to zero
+        client.set_conversion_rate(&xlm, &usdc.asset, &100_000_000);
+        assert_eq!(
+            client.try_convert_amount(&usdc.asset, &xlm, &1),
+            Err(Ok(AssetRegistryError::InvalidConversionRate))
+        );
+        assert_eq!(client.convert_amount(&usdc.asset, &xlm, &100_000), 10);
+    }
+
+    fn contract_registration(env: &Env, symbol: Symbol) -> AssetRegistration {
+        AssetRegistration {
+            asset: Asset::Contract(Address::generate(env)),
+            symbol: symbol.clone(),
+            name: symbol,
+            decimals: 7,
+            min_amount: 1,
+            max_amount: 1_000_000_000,
+            accept_for_premium: true,
+            accept_for_claims: true,
+            price_feed_id: None,
+        }
+    }
+
+    #[test]
+    fn test_contract_assets_are_keyed_by_address() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+
+        let first = contract_registration(&env, symbol_short!("TOKA"));
+        let second = contract_registration(&env, symbol_short!("TOKB"));
+        client.register_asset(&first);
+        client.register_asset(&second);
+
+        assert_eq!(client.get_asset_metadata(&first.asset).symbol, symbol_short!("TOKA"));
+        assert_eq!(client.get_asset_metadata(&second.asset).symbol, symbol_short!("TOKB"));
+        assert_eq!(
+            client.try_register_asset(&first),
+            Err(Ok(AssetRegistryError::AssetAlreadyExists))
+        );
+
+        client.set_asset_status(&first.asset, &false);
+        assert!(!client.is_asset_active(&first.asset));
+        assert!(client.is_asset_active(&second.asset));
+        assert!(!client.accepts_claims(&first.asset));
+        assert!(client.accepts_claims(&second.asset));
+        assert_caches_match_scan(&env, &client);
+    }
+
+    #[test]
+    fn test_cached_asset_lists_track_mutations() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+        assert_caches_match_scan(&env, &client);
+
+        let usdc = usdc_registration(&env);
+        let usdc_asset = usdc.asset.clone();
+        client.register_asset(&usdc);
+
+        let mut eurc = usdc_registration(&env);
+        eurc.asset = Asset::Stellar((symbol_short!("EURC"), Address::generate(&env)));
+        eurc.symbol = symbol_short!("EURC");
+        eurc.accept_for_claims = false;
+        let eurc_asset = eurc.asset.clone();
+        client.register_asset(&eurc);
+        assert_caches_match_scan(&env, &client);
+        assert_eq!(client.get_claim_assets().len(), 2);
+
+        client.update_asset_config(&usdc_asset, &Some(false), &None, &None, &None);
+        assert_caches_match_scan(&env, &client);
+        assert!(!client.get_premium_assets().contains(&usdc_asset));
+
+        client.update_asset_config(&eurc_asset, &None, &Some(true), &None, &None);
+        assert_caches_match_scan(&env, &client);
+
+        // Deactivation drops the asset from both lists; reactivation restores it
+        client.set_asset_status(&eurc_asset, &false);
+        assert_caches_match_scan(&env, &client);
+        assert!(!client.get_claim_assets().contains(&eurc_asset));
+        assert_eq!(client.get_registry_summary().active_assets, 2);
+
+        // Flag changes while inactive must not leak into the cached lists
+        client.update_asset_config(&eurc_asset, &Some(false), &None, &None, &None);
+        assert_caches_match_scan(&env, &client);
+
+        client.set_asset_status(&eurc_asset, &true);
+        client.set_asset_status(&eurc_asset, &true);
+        assert_caches_match_scan(&env, &client);
+        assert!(client.get_claim_assets().contains(&eurc_asset));
+        assert!(!client.get_premium_assets().contains(&eurc_asset));
+    }
+
+    #[test]
+    fn test_asset_utilization_tracking() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+
+        let registration = usdc_registration(&env);
+        let usdc_asset = registration.asset.clone();
+        client.register_asset(&registration);
+
+        let policy_contract = Address::generate(&env);
+        client.register_trusted_contract(&policy_contract);
+
+        client.record_premium_volume(&policy_contract, &Asset::Native, &5_000);
+        client.record_premium_volume(&policy_contract, &Asset::Native, &2_500);
+        client.record_claim_volume(&policy_contract, &Asset::Native, &1_000);
+        client.record_premium_volume(&policy_contract, &usdc_asset, &40_000);
+
+        let xlm = client.get_asset_utilization(&Asset::Native);
+        assert_eq!(xlm.premium_volume, 7_500);
+        assert_eq!(xlm.premium_count, 2);
+        assert_eq!(xlm.claim_volume, 1_000);
+        assert_eq!(xlm.claim_count, 1);
+
+        let usdc = client.get_asset_utilization(&usdc_asset);
+        assert_eq!(usdc.premium_volume, 40_000);
+        assert_eq!(usdc.claim_volume, 0);
+    }
+
+    #[test]
+    fn test_asset_utilization_rejects_untrusted_caller() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+
+        let untrusted = Address::generate(&env);
+        let result = client.try_record_premium_volume(&untrusted, &Asset::Native, &5_000);
+        assert_eq!(result, Err(Ok(AssetRegistryError::NotTrustedContract)));
+
+        let result = client.try_record_claim_volume(&untrusted, &Asset::Native, &5_000);
+        assert_eq!(result, Err(Ok(AssetRegistryError::NotTrustedContract)));
+
+        assert_eq!(client.get_asset_utilization(&Asset::Native).premium_volume, 0);
+    }
+
+    #[test]
+    fn test_conversion_quote_uses_tier_for_amount() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+
+        let registration = usdc_registration(&env);
+        let usdc_asset = registration.asset.clone();
+        client.register_asset(&registration);
+
+        client.set_conversion_rate(&Asset::Native, &usdc_asset, &1000);
+        client.set_conversion_tiers(
+            &Asset::Native,
+            &usdc_asset,
+            &vec![
+                &env,
+                RateTier { min_amount: 1_000, rate_bps: 990 },
+                RateTier { min_amount: 1_000_000, rate_bps: 950 },
+            ],
+        );
+
+        // Below the first band: flat rate
+        assert_eq!(client.get_conversion_quote(&Asset::Native, &usdc_asset, &500), 50);
+        // Small band
+        assert_eq!(client.get_conversion_quote(&Asset::Native, &usdc_asset, &10_000), 990);
+        // Large band, boundary inclusive
+        assert_eq!(
+            client.get_conversion_quote(&Asset::Native, &usdc_asset, &1_000_000),
+            95_000
+        );
+        assert_eq!(
+            client.get_conversion_quote(&Asset::Native, &usdc_asset, &5_000_000),
+            475_000
+        );
+    }
+
+    #[test]
+    fn test_conversion_quote_falls_back_to_flat_rate() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+
+        let registration = usdc_registration(&env);
+        let usdc_asset = registration.asset.clone();
+        client.register_asset(&registration);
+
+        assert_eq!(
+            client.try_get_conversion_quote(&Asset::Native, &usdc_asset, &10_000),
+            Err(Ok(AssetRegistryError::ConversionRateNotFound))
+        );
+
+        client.set_conversion_rate(&Asset::Native, &usdc_asset, &1000);
+        assert_eq!(client.get_conversion_quote(&Asset::Native, &usdc_asset, &10_000), 1_000);
+        assert_eq!(
+            client.get_conversion_quote(&Asset::Native, &usdc_asset, &10_000),
+            client.convert_amount(&Asset::Native, &usdc_asset, &10_000)
+        );
+
+        // Clearing the schedule restores the flat rate
+        client.set_conversion_tiers(
+            &Asset::Native,
+            &usdc_asset,
+            &vec![&env, RateTier { min_amount: 0, rate_bps: 500 }],
+        );
+        assert_eq!(client.get_conversion_quote(&Asset::Native, &usdc_asset, &10_000), 500);
+        client.set_conversion_tiers(&Asset::Native, &usdc_asset, &Vec::new(&env));
+        assert_eq!(client.get_conversion_quote(&Asset::Native, &usdc_asset, &10_000), 1_000);
+
+        let unsorted = vec![
+            &env,
+            RateTier { min_amount: 1_000, rate_bps: 990 },
+            RateTier { min_amount: 1_000, rate_bps: 950 },
+        ];
+        assert_eq!(
+            client.try_set_conversion_tiers(&Asset::Native, &usdc_asset, &unsorted),
+            Err(Ok(AssetRegistryError::InvalidInput))
+        );
+    }
+
+    /// Distinct registration for asset code `code`
+    fn coded_registration(env: &Env, code: &str) -> AssetRegistration {
+        let mut registration = usdc_registration(env);
+        registration.asset = Asset::Stellar((Symbol::new(env, code), Address::generate(env)));
+        registration.symbol = Symbol::new(env, code);
+        registration
+    }
+
+    #[test]
+    fn test_register_assets_batch() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+
+        let mut eurc = coded_registration(&env, "EURC");
+        eurc.accept_for_premium = false;
+        let token = Address::generate(&env);
+        let mut wrapped = coded_registration(&env, "WBTC");
+        wrapped.asset = Asset::Contract(token.clone());
+        let batch = vec![&env, coded_registration(&env, "USDC"), eurc.clone(), wrapped];
+
+        assert_eq!(client.validate_asset_batch(&batch), None);
+        assert_eq!(
+            client.register_assets(&batch),
+            BatchRegistrationResult::Registered(3)
+        );
+
+        assert_eq!(client.get_supported_assets().len(), 4);
+        assert_eq!(client.get_asset_metadata(&eurc.asset).symbol, eurc.symbol);
+        assert!(client.is_asset_active(&Asset::Contract(token)));
+        assert!(!client.accepts_premium(&eurc.asset));
+        assert_caches_match_scan(&env, &client);
+    }
+
+    #[test]
+    fn test_register_assets_rolls_back_on_duplicate() {
+        let env = Env::default();
+        let (client, _admin) = setup_client(&env);
+        client.register_asset(&coded_registration(&env, "USDC"));
+        let summary_before = client.get_registry_summary();
+
+        // Third entry collides with an already registered asset
+        let eurc = coded_registration(&env, "EURC");
+        let gbpc = coded_registration(&env, "GBPC");
+        let batch = vec![&env, eurc.clone(), gbpc.clone(), coded_registration(&env, "USDC")];
+        assert_eq!(
+            client.validate_asset_batch(&batch),
+            Some((2, AssetRegistryError::AssetAlreadyExists))
+        );
+        assert_eq!(
+            client.register_assets(&batch),
+            BatchRegistrationResult::Rejected(BatchFailure {
+                index: 2,
+                error_code: AssetRegistryError::AssetAlreadyExists as u32,
+            })
+        );
+        let (_, topics, data) = last_event(&env);
+        assert_eq!(
+            topics,
+            vec![&env, Symbol::new(&env, "batch_rejected").into_val(&env), 2u32.into_val(&env)]
+        );
+        let code: u32 = data.into_val(&env);
+        assert_eq!(code, AssetRegistryError::AssetAlreadyExists as u32);
+
+        // Second entry repeats the first within the batch
+        let batch = vec![&env, eurc.clone(), coded_registration(&env, "EURC")];
+        assert_eq!(
+            client.validate_asset_batch(&batch),
+            Some((1, AssetRegistryError::AssetAlreadyExists))
+        );
+        assert_eq!(
+            client.register_assets(&batch),
+            BatchRegistrationResult::Rejected(BatchFailure {
+                index: 1,
+                error_code: AssetRegistryError::AssetAlreadyExists as u32,
+            })
+        );
+
+        // Invalid config is reported at its own index
+        let mut bad = gbpc.clone();
+        bad.decimals = 19;
+        let batch = vec![&env, eurc.clone(), bad];
+        assert_eq!(
+            client.validate_asset_batch(&batch),
+            Some((1, AssetRegistryError::InvalidAssetConfig))
+        );
+        assert_eq!(
+            client.register_assets(&batch),
+            BatchRegistrationResult::Rejected(BatchFailure {
+                index: 1,
+                error_code: AssetRegistryError::InvalidAssetConfig as u32,
+            })
+        );
+
+        // Nothing from the failed batches was committed
+        assert_eq!(client.get_registry_summary(), summary_before);
+        assert_eq!(
+            client.try_get_asset_metadata(&eurc.asset),
+            Err(Ok(AssetRegistryError::AssetNotFound))
+        );
+        assert!(!client.is_asset_active(&gbpc.asset));
+
+        assert_eq!(
+            client.try_register_assets(&Vec::new(&env)),
+            Err(Ok(AssetRegistryError::InvalidInput))
+        );
+    }
 }
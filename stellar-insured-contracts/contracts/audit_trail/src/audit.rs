@@ -1,6 +1,4 @@
-#![no_std]
-
-use soroban_sdk::{contract, contractimpl, Address, Bytes, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String, Vec};
 
 use crate::{
     compliance,
@@ -12,6 +10,12 @@ use crate::{
     },
 };
 
+/// Maximum number of entries returned by a single paginated query
+const MAX_QUERY_LIMIT: u32 = 100;
+
+/// Width of the entry id window a single paginated query examines
+const MAX_QUERY_SCAN: u64 = 100;
+
 #[contract]
 pub struct AuditTrailContract;
 
@@ -122,6 +126,7 @@ impl AuditTrailContract {
             compliance_status: ComplianceStatus::Compliant,
             related_entry_id,
             metadata,
+            prev_hash: storage::get_last_entry_hash(&env),
         };
 
         storage::save_entry(&env, &entry);
@@ -170,6 +175,7 @@ impl AuditTrailContract {
             compliance_status: ComplianceStatus::Flagged,
             related_entry_id: None,
             metadata,
+            prev_hash: storage::get_last_entry_hash(&env),
         };
 
         storage::save_entry(&env, &entry);
@@ -232,6 +238,92 @@ impl AuditTrailContract {
         Ok(results)
     }
 
+    /// Query audit entries matching `filter`, one page at a time. Named
+    /// `query_entries_paginated` because `query_entries` already exists.
+    /// Skips the first `start` matches and returns at most `limit` (capped at 100).
+    /// Only entry ids in `[filter.from_entry_id, filter.from_entry_id + 100)`
+    /// are examined, so callers page through a larger log by advancing
+    /// `from_entry_id`; the scan also stops at the first entry past
+    /// `filter.to_timestamp`, since timestamps only increase. When the filter
+    /// names a single actor or action, only that index is scanned.
+    /// `filter.limit` is ignored in favour of `limit`.
+    pub fn query_entries_paginated(
+        env: Env,
+        caller: Address,
+        filter: AuditFilter,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<AuditEntry>, AuditError> {
+        Self::require_query_permission(&env, &caller)?;
+
+        let limit = limit.min(MAX_QUERY_LIMIT);
+        let mut results: Vec<AuditEntry> = Vec::new(&env);
+        if limit == 0 {
+            return Ok(results);
+        }
+
+        let index = if filter.actor.len() == 1 {
+            Some(storage::actor_index(&filter.actor.get_unchecked(0)))
+        } else if filter.action.len() == 1 {
+            Some(storage::action_index(&filter.action.get_unchecked(0)))
+        } else {
+            None
+        };
+
+        let scan_from = filter.from_entry_id.max(1);
+        let scan_end = scan_from.saturating_add(MAX_QUERY_SCAN);
+        let mut skipped: u32 = 0;
+        let mut collect = |entry_id: u64| -> bool {
+            if entry_id < scan_from {
+                return true;
+            }
+            if entry_id >= scan_end {
+                return false;
+            }
+            if let Some(entry) = storage::get_entry(&env, entry_id) {
+                if filter.to_timestamp.is_some_and(|to_ts| entry.timestamp > to_ts) {
+                    return false;
+                }
+                if Self::matches_filter(&filter, &entry) {
+                    if skipped < start {
+                        skipped += 1;
+                    } else {
+                        results.push_back(entry);
+                    }
+                }
+            }
+            results.len() < limit
+        };
+
+        match index {
+            Some(index) => {
+                // Read the index a chunk at a time, stopping once the page is full
+                'chunks: for chunk in 0..storage::get_index_chunk_count(&env, &index) {
+                    for entry_id in storage::get_index_chunk(&env, &index, chunk).iter() {
+                        if !collect(entry_id) {
+                            break 'chunks;
+                        }
+                    }
+                }
+            }
+            None => {
+                let total = storage::get_entry_count(&env);
+                for entry_id in scan_from..=total {
+                    if !collect(entry_id) {
+                        break;
+                    }
+                }
+            }
+        }
+
+        env.events().publish(
+            (soroban_sdk::symbol_short!("queried"),),
+            (caller, filter.from_entry_id, results.len()),
+        );
+
+        Ok(results)
+    }
+
     /// Get entries for a specific actor (paginated).
     pub fn get_entries_by_actor(
         env: Env,
@@ -333,6 +425,7 @@ impl AuditTrailContract {
             compliance_status: ComplianceStatus::Compliant,
             related_entry_id: None,
             metadata: meta,
+            prev_hash: storage::get_last_entry_hash(&env),
         };
         storage::save_entry(&env, &report_entry);
 
@@ -489,6 +582,7 @@ impl AuditTrailContract {
             compliance_status: ComplianceStatus::Compliant,
             related_entry_id: None,
             metadata: Bytes::new(&env),
+            prev_hash: storage::get_last_entry_hash(&env),
         };
         storage::save_entry(&env, &export_entry);
 
@@ -500,6 +594,50 @@ impl AuditTrailContract {
         Ok(results)
     }
 
+    // ── Integrity ────────────────────────────────────────────────────────────
+
+    /// Verify the hash chain over entries `start..=end`.
+    /// Each entry's `prev_hash` must match the recomputed hash of its
+    /// predecessor (zeroes for entry 1). The hash of `end` itself is checked
+    /// against the next entry's `prev_hash`, or against the stored chain head
+    /// when `end` is the latest entry, so tampering with the last entry in the
+    /// span is detected too. Returns false for an empty or out-of-range span.
+    pub fn verify_chain(env: Env, start: u64, end: u64) -> bool {
+        let total = storage::get_entry_count(&env);
+        if start == 0 || start > end || end > total {
+            return false;
+        }
+
+        let mut expected = if start == 1 {
+            BytesN::from_array(&env, &[0u8; 32])
+        } else {
+            match storage::get_entry(&env, start - 1) {
+                Some(prev) => storage::entry_hash(&env, &prev),
+                None => return false,
+            }
+        };
+
+        for entry_id in start..=end {
+            let entry = match storage::get_entry(&env, entry_id) {
+                Some(entry) => entry,
+                None => return false,
+            };
+            if entry.prev_hash != expected {
+                return false;
+            }
+            expected = storage::entry_hash(&env, &entry);
+        }
+
+        if end < total {
+            match storage::get_entry(&env, end + 1) {
+                Some(next) => next.prev_hash == expected,
+                None => false,
+            }
+        } else {
+            expected == storage::get_last_entry_hash(&env)
+        }
+    }
+
     // ── Admin Utilities ──────────────────────────────────────────────────────
 
     /// Get the current admin address.
@@ -582,25 +720,19 @@ impl AuditTrailContract {
     }
 
     fn matches_filter(filter: &AuditFilter, entry: &AuditEntry) -> bool {
-        if let Some(ref actor) = filter.actor {
-            if entry.actor != *actor {
-                return false;
-            }
+        if !filter.actor.is_empty() && !filter.actor.contains(&entry.actor) {
+            return false;
         }
-        if let Some(ref action) = filter.action {
-            if core::mem::discriminant(&entry.action) != core::mem::discriminant(action) {
-                return false;
-            }
+        if !filter.action.is_empty() && !filter.action.contains(&entry.action) {
+            return false;
         }
-        if let Some(ref severity) = filter.severity {
-            if core::mem::discriminant(&entry.severity) != core::mem::discriminant(severity) {
-                return false;
-            }
+        if !filter.severity.is_empty() && !filter.severity.contains(&entry.severity) {
+            return false;
         }
-        if let Some(ref status) = filter.compliance_status {
-            if core::mem::discriminant(&entry.compliance_status) != core::mem::discriminant(status) {
-                return false;
-            }
+        if !filter.compliance_status.is_empty()
+            && !filter.compliance_status.contains(&entry.compliance_status)
+        {
+            return false;
         }
         if let Some(from_ts) = filter.from_timestamp {
             if entry.timestamp < from_ts {
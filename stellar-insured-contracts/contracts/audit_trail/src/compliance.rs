@@ -1,11 +1,9 @@
-#![no_std]
-
 use soroban_sdk::{Address, Bytes, Env};
 
 use crate::{
     errors::AuditError,
     storage,
-    types::{ActionCategory, AuditEntry, ComplianceReport, ComplianceStatus, Severity},
+    types::{ComplianceReport, ComplianceStatus, Severity},
 };
 
 /// Scan entries in [from_id, to_id) range and produce a ComplianceReport.
@@ -0,0 +1,21 @@
+use soroban_sdk::contracterror;
+
+/// Errors returned by the audit trail contract
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AuditError {
+    // Access control
+    Unauthorized = 1,
+    CallerNotAuthorized = 2,
+    InsufficientPermissions = 3,
+    // External auditors
+    AuditorNotRegistered = 4,
+    AuditorInactive = 5,
+    // Lookups
+    EntryNotFound = 6,
+    ReportNotFound = 7,
+    // Query validation
+    InvalidTimeRange = 8,
+    LimitExceeded = 9,
+}
@@ -6,7 +6,7 @@ mod errors;
 mod storage;
 mod types;
 
-pub use audit::AuditTrailContract;
+pub use audit::{AuditTrailContract, AuditTrailContractClient};
 pub use errors::AuditError;
 pub use types::{
     ActionCategory, AuditEntry, AuditFilter, AuditorPermissions, ComplianceReport,
@@ -1,8 +1,6 @@
-#![no_std]
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
 
-use soroban_sdk::{Address, Env};
-
-use crate::types::{AuditEntry, ComplianceReport, DataKey, ExternalAuditor};
+use crate::types::{ActionCategory, AuditEntry, ComplianceReport, DataKey, ExternalAuditor};
 
 // ── Ledger TTL constants ─────────────────────────────────────────────────────
 // Audit entries must persist long-term for regulatory compliance.
@@ -12,6 +10,9 @@ const AUDIT_TTL_LEDGERS: u32 = 63_072_000;
 const REPORT_TTL_LEDGERS: u32 = 63_072_000;
 const ADMIN_TTL_LEDGERS: u32 = 63_072_000;
 
+/// Entry IDs stored per index chunk, so no single index entry grows unbounded
+pub const INDEX_CHUNK_SIZE: u32 = 50;
+
 // ── Admin ────────────────────────────────────────────────────────────────────
 
 pub fn set_admin(env: &Env, admin: &Address) {
@@ -76,6 +77,12 @@ pub fn increment_entry_count(env: &Env) -> u64 {
 
 pub fn save_entry(env: &Env, entry: &AuditEntry) {
     let key = DataKey::Entry(entry.entry_id);
+    // Index new entries only; updates (e.g. compliance flags) keep their slots
+    if !env.storage().persistent().has(&key) {
+        push_index(env, action_index(&entry.action), entry.entry_id);
+        push_index(env, actor_index(&entry.actor), entry.entry_id);
+        set_last_entry_hash(env, &entry_hash(env, entry));
+    }
     env.storage().persistent().set(&key, entry);
     env.storage()
         .persistent()
@@ -97,6 +104,107 @@ pub fn bump_entry_ttl(env: &Env, entry_id: u64) {
     }
 }
 
+// ── Hash Chain ───────────────────────────────────────────────────────────────
+
+/// SHA-256 over the entry's recorded fields, including `prev_hash`.
+/// `compliance_status` is excluded: it is updated in place by review
+/// (flag / clear) and is not part of the original record.
+pub fn entry_hash(env: &Env, entry: &AuditEntry) -> BytesN<32> {
+    let mut payload = Bytes::new(env);
+    payload.append(&entry.entry_id.to_xdr(env));
+    payload.append(&entry.ledger.to_xdr(env));
+    payload.append(&entry.timestamp.to_xdr(env));
+    payload.append(&entry.actor.clone().to_xdr(env));
+    payload.append(&entry.subject.clone().to_xdr(env));
+    payload.append(&entry.action.clone().to_xdr(env));
+    payload.append(&entry.source_contract.clone().to_xdr(env));
+    payload.append(&entry.data_hash.clone().to_xdr(env));
+    payload.append(&entry.description.clone().to_xdr(env));
+    payload.append(&entry.severity.clone().to_xdr(env));
+    payload.append(&entry.related_entry_id.to_xdr(env));
+    payload.append(&entry.metadata.clone().to_xdr(env));
+    payload.append(&entry.prev_hash.clone().to_xdr(env));
+    env.crypto().sha256(&payload)
+}
+
+/// Chain hash of the latest entry, or all zeroes before the first entry
+pub fn get_last_entry_hash(env: &Env) -> BytesN<32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LastEntryHash)
+        .unwrap_or(BytesN::from_array(env, &[0u8; 32]))
+}
+
+fn set_last_entry_hash(env: &Env, hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastEntryHash, hash);
+    env.storage()
+        .persistent()
+        .extend_ttl(&DataKey::LastEntryHash, AUDIT_TTL_LEDGERS, AUDIT_TTL_LEDGERS);
+}
+
+// ── Entry Indexes ────────────────────────────────────────────────────────────
+
+// An index key holds the number of indexed IDs; the IDs themselves live in
+// chunks of `INDEX_CHUNK_SIZE`, keyed by chunk number.
+
+/// Index key for an action category
+pub fn action_index(action: &ActionCategory) -> DataKey {
+    DataKey::ActionIndex(action.clone() as u32)
+}
+
+/// Index key for an actor
+pub fn actor_index(actor: &Address) -> DataKey {
+    DataKey::ActorIndex(actor.clone())
+}
+
+fn index_chunk_key(index: &DataKey, chunk: u32) -> DataKey {
+    match index {
+        DataKey::ActionIndex(action) => DataKey::ActionIndexChunk(*action, chunk),
+        DataKey::ActorIndex(actor) => DataKey::ActorIndexChunk(actor.clone(), chunk),
+        _ => panic!("not an entry index"),
+    }
+}
+
+fn get_index_len(env: &Env, index: &DataKey) -> u32 {
+    env.storage().persistent().get(index).unwrap_or(0u32)
+}
+
+fn push_index(env: &Env, index: DataKey, entry_id: u64) {
+    let len = get_index_len(env, &index);
+    let chunk_key = index_chunk_key(&index, len / INDEX_CHUNK_SIZE);
+    let mut ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&chunk_key)
+        .unwrap_or(Vec::new(env));
+    ids.push_back(entry_id);
+    env.storage().persistent().set(&chunk_key, &ids);
+    env.storage()
+        .persistent()
+        .extend_ttl(&chunk_key, AUDIT_TTL_LEDGERS, AUDIT_TTL_LEDGERS);
+
+    env.storage().persistent().set(&index, &(len + 1));
+    env.storage()
+        .persistent()
+        .extend_ttl(&index, AUDIT_TTL_LEDGERS, AUDIT_TTL_LEDGERS);
+}
+
+/// Number of chunks an index spans
+pub fn get_index_chunk_count(env: &Env, index: &DataKey) -> u32 {
+    let len = get_index_len(env, index);
+    len.div_ceil(INDEX_CHUNK_SIZE)
+}
+
+/// Entry IDs in one chunk of an index, in ascending order
+pub fn get_index_chunk(env: &Env, index: &DataKey, chunk: u32) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&index_chunk_key(index, chunk))
+        .unwrap_or(Vec::new(env))
+}
+
 // ── Report Count ─────────────────────────────────────────────────────────────
 
 pub fn get_report_count(env: &Env) -> u64 {
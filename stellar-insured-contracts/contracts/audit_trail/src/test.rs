@@ -1,13 +1,14 @@
 #![cfg(test)]
 
 use soroban_sdk::{
-    testutils::{Address as _, Ledger, LedgerInfo},
-    Address, Bytes, Env, String,
+    testutils::{Address as _, Events, Ledger, LedgerInfo},
+    symbol_short, vec, Address, Bytes, BytesN, Env, IntoVal, String, Vec,
 };
 
 use crate::{
     audit::AuditTrailContract,
     errors::AuditError,
+    storage,
     types::{ActionCategory, AuditFilter, AuditorPermissions, ComplianceStatus, Severity},
     AuditTrailContractClient,
 };
@@ -305,10 +306,10 @@ fn test_query_with_filter_by_severity() {
     );
 
     let filter = AuditFilter {
-        actor: None,
-        action: None,
-        severity: Some(Severity::Warning),
-        compliance_status: None,
+        actor: Vec::new(&env),
+        action: Vec::new(&env),
+        severity: vec![&env, Severity::Warning],
+        compliance_status: Vec::new(&env),
         from_timestamp: None,
         to_timestamp: None,
         from_entry_id: 1,
@@ -320,6 +321,205 @@ fn test_query_with_filter_by_severity() {
     assert!(matches!(results.get(0).unwrap().severity, Severity::Warning));
 }
 
+/// Logs a mix of entries across two actors, two categories, two severities and
+/// two timestamps. Returns (actor_a, actor_b).
+fn log_mixed_entries(env: &Env, client: &AuditTrailContractClient) -> (Address, Address) {
+    let actor_a = Address::generate(env);
+    let actor_b = Address::generate(env);
+    let source = Address::generate(env);
+    client.authorize_caller(&source);
+
+    // ids 1..=6 at t0, ids 7..=12 at t0 + 1000
+    for i in 0u8..12 {
+        if i == 6 {
+            env.ledger().with_mut(|l| l.timestamp += 1_000);
+        }
+        let actor = if i % 2 == 0 { &actor_a } else { &actor_b };
+        let action = if i % 3 == 0 {
+            ActionCategory::ClaimSubmitted
+        } else {
+            ActionCategory::PolicyCreated
+        };
+        let severity = if i % 4 == 0 { Severity::Warning } else { Severity::Info };
+        client.log_entry(
+            actor, &sample_bytes(env, i), &action,
+            &source, &sample_bytes(env, i), &sample_string(env, "Mixed"),
+            &severity, &None, &Bytes::new(env),
+        );
+    }
+
+    (actor_a, actor_b)
+}
+
+fn empty_filter(env: &Env) -> AuditFilter {
+    AuditFilter {
+        actor: Vec::new(env), action: Vec::new(env), severity: Vec::new(env),
+        compliance_status: Vec::new(env),
+        from_timestamp: None, to_timestamp: None, from_entry_id: 0, limit: 0,
+    }
+}
+
+#[test]
+fn test_query_entries_paginated_combined_filter() {
+    let (env, contract_id, admin) = setup_env();
+    let client = get_client(&env, &contract_id);
+    client.initialize(&admin);
+    let (actor_a, _) = log_mixed_entries(&env, &client);
+
+    // actor_a logged ids 1,3,5,7,9,11; of those, ClaimSubmitted are 1 and 7
+    let mut filter = empty_filter(&env);
+    filter.actor = vec![&env, actor_a.clone()];
+    filter.action = vec![&env, ActionCategory::ClaimSubmitted];
+    let results = client.query_entries_paginated(&admin, &filter, &0, &10);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results.get(0).unwrap().entry_id, 1);
+    assert_eq!(results.get(1).unwrap().entry_id, 7);
+
+    // Restrict to the later time window and Warning severity: id 9 only
+    let mut filter = empty_filter(&env);
+    filter.actor = vec![&env, actor_a];
+    filter.severity = vec![&env, Severity::Warning];
+    filter.from_timestamp = Some(1_700_000_500);
+    let results = client.query_entries_paginated(&admin, &filter, &0, &10);
+    assert_eq!(results.len(), 1);
+    let entry = results.get(0).unwrap();
+    assert_eq!(entry.entry_id, 9);
+    assert!(matches!(entry.severity, Severity::Warning));
+}
+
+#[test]
+fn test_query_entries_paginated_pages() {
+    let (env, contract_id, admin) = setup_env();
+    let client = get_client(&env, &contract_id);
+    client.initialize(&admin);
+    log_mixed_entries(&env, &client);
+
+    // PolicyCreated entries are ids 2,3,5,6,8,9,11,12
+    let mut filter = empty_filter(&env);
+    filter.action = vec![&env, ActionCategory::PolicyCreated];
+
+    let page = client.query_entries_paginated(&admin, &filter, &0, &3);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().entry_id, 2);
+    assert_eq!(page.get(2).unwrap().entry_id, 5);
+
+    let page = client.query_entries_paginated(&admin, &filter, &3, &3);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().entry_id, 6);
+    assert_eq!(page.get(2).unwrap().entry_id, 9);
+
+    let page = client.query_entries_paginated(&admin, &filter, &6, &3);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(1).unwrap().entry_id, 12);
+
+    // Unindexed time-range filter scans all entries
+    let mut filter = empty_filter(&env);
+    filter.to_timestamp = Some(1_700_000_000);
+    let page = client.query_entries_paginated(&admin, &filter, &4, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().entry_id, 5);
+    assert_eq!(page.get(1).unwrap().entry_id, 6);
+}
+
+#[test]
+fn test_query_entries_paginated_spans_index_chunks() {
+    let (env, contract_id, admin) = setup_env();
+    let client = get_client(&env, &contract_id);
+    let actor = Address::generate(&env);
+    let source = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.authorize_caller(&source);
+
+    let total = storage::INDEX_CHUNK_SIZE + 5;
+    for i in 0..total {
+        client.log_entry(
+            &actor,
+            &sample_bytes(&env, i as u8),
+            &ActionCategory::PolicyCreated,
+            &source,
+            &sample_bytes(&env, i as u8),
+            &sample_string(&env, "Policy created"),
+            &Severity::Info,
+            &None,
+            &Bytes::new(&env),
+        );
+    }
+
+    env.as_contract(&contract_id, || {
+        let index = storage::actor_index(&actor);
+        assert_eq!(storage::get_index_chunk_count(&env, &index), 2);
+        assert_eq!(
+            storage::get_index_chunk(&env, &index, 0).len(),
+            storage::INDEX_CHUNK_SIZE
+        );
+        assert_eq!(storage::get_index_chunk(&env, &index, 1).len(), 5);
+    });
+
+    // A page straddling the chunk boundary stays in entry order
+    let mut filter = empty_filter(&env);
+    filter.actor = vec![&env, actor];
+    let start = storage::INDEX_CHUNK_SIZE - 3;
+    let page = client.query_entries_paginated(&admin, &filter, &start, &6);
+    assert_eq!(page.len(), 6);
+    for (i, entry) in page.iter().enumerate() {
+        assert_eq!(entry.entry_id, (start + i as u32 + 1) as u64);
+    }
+
+    let page = client.query_entries_paginated(&admin, &filter, &0, &100);
+    assert_eq!(page.len(), total);
+}
+
+#[test]
+fn test_query_entries_paginated_bounds_the_scan_window() {
+    let (env, contract_id, admin) = setup_env();
+    let client = get_client(&env, &contract_id);
+    let actor = Address::generate(&env);
+    let source = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.authorize_caller(&source);
+
+    // Only the last five of 105 entries are warnings
+    for i in 0u8..105 {
+        let severity = if i < 100 { Severity::Info } else { Severity::Warning };
+        client.log_entry(
+            &actor, &sample_bytes(&env, i), &ActionCategory::PremiumPaid,
+            &source, &sample_bytes(&env, i), &sample_string(&env, "Premium"),
+            &severity, &None, &Bytes::new(&env),
+        );
+    }
+
+    // The first window covers ids 1..=100 and holds no warnings
+    let mut filter = empty_filter(&env);
+    filter.severity = vec![&env, Severity::Warning];
+    assert_eq!(client.query_entries_paginated(&admin, &filter, &0, &10).len(), 0);
+
+    filter.from_entry_id = 101;
+    let page = client.query_entries_paginated(&admin, &filter, &0, &10);
+    assert_eq!(page.len(), 5);
+    assert_eq!(page.get(0).unwrap().entry_id, 101);
+
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("queried"),).into_val(&env));
+    let data: (Address, u64, u32) = data.into_val(&env);
+    assert_eq!(data, (admin, 101, 5));
+}
+
+#[test]
+fn test_query_entries_paginated_caps_limit() {
+    let (env, contract_id, admin) = setup_env();
+    let client = get_client(&env, &contract_id);
+    client.initialize(&admin);
+    log_mixed_entries(&env, &client);
+
+    let results = client.query_entries_paginated(&admin, &empty_filter(&env), &0, &1_000);
+    assert_eq!(results.len(), 12);
+
+    let results = client.query_entries_paginated(&admin, &empty_filter(&env), &0, &0);
+    assert_eq!(results.len(), 0);
+}
+
 #[test]
 fn test_get_flagged_entries() {
     let (env, contract_id, admin) = setup_env();
@@ -422,7 +622,7 @@ fn test_report_with_mixed_compliance_statuses() {
     client.authorize_caller(&source);
 
     // Normal entry
-    let id1 = client.log_entry(
+    client.log_entry(
         &actor, &sample_bytes(&env, 1), &ActionCategory::PolicyCreated,
         &source, &sample_bytes(&env, 1), &sample_string(&env, "Normal"),
         &Severity::Info, &None, &Bytes::new(&env),
@@ -488,7 +688,8 @@ fn test_register_and_use_external_auditor() {
     );
 
     let filter = AuditFilter {
-        actor: None, action: None, severity: None, compliance_status: None,
+        actor: Vec::new(&env), action: Vec::new(&env), severity: Vec::new(&env),
+        compliance_status: Vec::new(&env),
         from_timestamp: None, to_timestamp: None, from_entry_id: 1, limit: 10,
     };
 
@@ -536,7 +737,8 @@ fn test_deactivate_auditor() {
 
     // Inactive auditor can't query
     let filter = AuditFilter {
-        actor: None, action: None, severity: None, compliance_status: None,
+        actor: Vec::new(&env), action: Vec::new(&env), severity: Vec::new(&env),
+        compliance_status: Vec::new(&env),
         from_timestamp: None, to_timestamp: None, from_entry_id: 1, limit: 10,
     };
     let result = client.try_query_entries(&auditor_addr, &filter);
@@ -608,6 +810,99 @@ fn test_flag_and_clear_entry() {
     assert!(matches!(cleared.compliance_status, ComplianceStatus::Compliant));
 }
 
+// ── Hash Chain Tests ──────────────────────────────────────────────────────────
+
+fn log_chain(env: &Env, client: &AuditTrailContractClient, len: u8) -> Address {
+    let actor = Address::generate(env);
+    let source = Address::generate(env);
+    client.authorize_caller(&source);
+    for i in 0..len {
+        client.log_entry(
+            &actor, &sample_bytes(env, i), &ActionCategory::PremiumPaid,
+            &source, &sample_bytes(env, i), &sample_string(env, "Premium paid"),
+            &Severity::Info, &None, &Bytes::new(env),
+        );
+    }
+    actor
+}
+
+#[test]
+fn test_entries_are_hash_chained() {
+    let (env, contract_id, admin) = setup_env();
+    let client = get_client(&env, &contract_id);
+    client.initialize(&admin);
+    log_chain(&env, &client, 4);
+
+    let first = client.get_entry(&1u64);
+    assert_eq!(first.prev_hash, BytesN::from_array(&env, &[0u8; 32]));
+
+    env.as_contract(&contract_id, || {
+        for id in 2u64..=4 {
+            let prev = storage::get_entry(&env, id - 1).unwrap();
+            let entry = storage::get_entry(&env, id).unwrap();
+            assert_eq!(entry.prev_hash, storage::entry_hash(&env, &prev));
+        }
+    });
+
+    assert!(client.verify_chain(&1u64, &4u64));
+    assert!(client.verify_chain(&2u64, &3u64));
+    assert!(!client.verify_chain(&0u64, &4u64));
+    assert!(!client.verify_chain(&3u64, &2u64));
+    assert!(!client.verify_chain(&1u64, &5u64));
+}
+
+#[test]
+fn test_verify_chain_survives_compliance_flagging() {
+    let (env, contract_id, admin) = setup_env();
+    let client = get_client(&env, &contract_id);
+    client.initialize(&admin);
+    log_chain(&env, &client, 3);
+
+    client.flag_entry(&admin, &2u64, &sample_string(&env, "Review"));
+    assert!(client.verify_chain(&1u64, &3u64));
+}
+
+#[test]
+fn test_verify_chain_detects_mutated_entry() {
+    let (env, contract_id, admin) = setup_env();
+    let client = get_client(&env, &contract_id);
+    client.initialize(&admin);
+    log_chain(&env, &client, 4);
+
+    // Rewrite entry 2's description directly in storage
+    env.as_contract(&contract_id, || {
+        let mut entry = storage::get_entry(&env, 2).unwrap();
+        entry.description = sample_string(&env, "Premium refunded");
+        storage::save_entry(&env, &entry);
+    });
+
+    assert!(!client.verify_chain(&1u64, &4u64));
+    assert!(!client.verify_chain(&2u64, &3u64));
+    // The tampered entry is caught as the last one in the span too
+    assert!(!client.verify_chain(&1u64, &2u64));
+    assert!(!client.verify_chain(&2u64, &2u64));
+    // Entries before the tampered one still verify
+    assert!(client.verify_chain(&1u64, &1u64));
+}
+
+#[test]
+fn test_verify_chain_detects_mutated_head() {
+    let (env, contract_id, admin) = setup_env();
+    let client = get_client(&env, &contract_id);
+    client.initialize(&admin);
+    let actor = log_chain(&env, &client, 3);
+
+    env.as_contract(&contract_id, || {
+        let mut entry = storage::get_entry(&env, 3).unwrap();
+        entry.actor = Address::generate(&env);
+        storage::save_entry(&env, &entry);
+    });
+
+    assert_ne!(client.get_entry(&3u64).actor, actor);
+    assert!(client.verify_chain(&1u64, &2u64));
+    assert!(!client.verify_chain(&1u64, &3u64));
+}
+
 // ── Edge Case Tests ───────────────────────────────────────────────────────────
 
 #[test]
@@ -629,7 +924,8 @@ fn test_query_limit_exceeded_fails() {
     client.initialize(&admin);
 
     let filter = AuditFilter {
-        actor: None, action: None, severity: None, compliance_status: None,
+        actor: Vec::new(&env), action: Vec::new(&env), severity: Vec::new(&env),
+        compliance_status: Vec::new(&env),
         from_timestamp: None, to_timestamp: None, from_entry_id: 1, limit: 200, // > 100
     };
 
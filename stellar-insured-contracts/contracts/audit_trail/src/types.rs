@@ -1,6 +1,4 @@
-#![no_std]
-
-use soroban_sdk::{contracttype, Address, Bytes, String, Symbol};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, String, Vec};
 
 /// Categories of auditable actions in the insurance platform
 #[contracttype]
@@ -61,7 +59,7 @@ pub enum ComplianceStatus {
 
 /// A single immutable audit log entry
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AuditEntry {
     /// Unique sequential ID
     pub entry_id: u64,
@@ -89,11 +87,13 @@ pub struct AuditEntry {
     pub related_entry_id: Option<u64>,
     /// Metadata key-value pairs encoded as bytes
     pub metadata: Bytes,
+    /// Chain hash of the previous entry (all zeroes for the first entry)
+    pub prev_hash: BytesN<32>,
 }
 
 /// Compliance report summary
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ComplianceReport {
     pub report_id: u64,
     pub generated_at: u64,
@@ -109,14 +109,17 @@ pub struct ComplianceReport {
     pub report_hash: Bytes,        // Hash of the full report for integrity
 }
 
-/// Query filter for audit trail searches
+/// Query filter for audit trail searches.
+/// An empty list leaves its field unconstrained; otherwise an entry must
+/// match one of the listed values. (Contract types cannot hold an optional
+/// enum or address, so lists stand in for `Option`.)
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct AuditFilter {
-    pub actor: Option<Address>,
-    pub action: Option<ActionCategory>,
-    pub severity: Option<Severity>,
-    pub compliance_status: Option<ComplianceStatus>,
+    pub actor: Vec<Address>,
+    pub action: Vec<ActionCategory>,
+    pub severity: Vec<Severity>,
+    pub compliance_status: Vec<ComplianceStatus>,
     pub from_timestamp: Option<u64>,
     pub to_timestamp: Option<u64>,
     pub from_entry_id: u64,
@@ -153,12 +156,20 @@ pub enum DataKey {
     ReportCount,
     Report(u64),
     ExternalAuditor(Address),
-    // Index: action category -> list of entry IDs
-    ActionIndex(u8),
+    // Index: action category -> number of indexed entry IDs
+    ActionIndex(u32),
     // Index: actor -> latest entry ID for efficient lookups
     ActorLatest(Address),
+    // Index: actor -> number of indexed entry IDs
+    ActorIndex(Address),
     // Index: ledger -> entry ID (for time-based queries)
     LedgerIndex(u32),
     // Authorized caller contracts
     AuthorizedCaller(Address),
+    // Chain hash of the most recently appended entry
+    LastEntryHash,
+    // Index chunk: (action category, chunk number) -> entry IDs
+    ActionIndexChunk(u32, u32),
+    // Index chunk: (actor, chunk number) -> entry IDs
+    ActorIndexChunk(Address, u32),
 }
\ No newline at end of file
@@ -38,7 +38,8 @@ pub enum Role {
 #[contracttype]
 #[derive(Clone)]
 pub enum RoleKey {
-    /// Maps Address -> Role
+    /// Legacy single-role assignment: Address -> Role. Read as a fallback
+    /// until the address is migrated to `UserRoles`.
     UserRole(Address),
     /// Contract-level admin address
     ContractAdmin,
@@ -49,6 +50,51 @@ pub enum RoleKey {
     Threshold(Role),
     /// NEW: Maps a unique action hash to a list of addresses that approved it
     Approvals(soroban_sdk::BytesN<32>),
+    /// Bounded, append-only history of role changes
+    RoleChangeAudit,
+    /// Set once a legacy contract admin has been imported into RBAC
+    AdminMigrated,
+    /// Final signer list of a completed multisig action
+    MultisigAudit(soroban_sdk::BytesN<32>),
+    /// Addresses currently holding a non-User role
+    RoleHolders,
+    /// Maps (Address, Role) -> timestamp after which that role lapses
+    RoleExpiry(Address, Role),
+    /// Maps Address -> address that delegated its current role
+    DelegatedBy(Address),
+    /// Seconds a multisig approval for a Role stays valid
+    ApprovalTtl(Role),
+    /// Maps Address -> Vec<Role> of the non-User roles it holds
+    UserRoles(Address),
+}
+
+/// Maximum number of role changes retained in the audit history
+pub const MAX_ROLE_CHANGE_AUDIT: u32 = 200;
+
+/// A single entry in the role change audit history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleChange {
+    /// Address whose role changed
+    pub target: Address,
+    /// Role held before the change
+    pub old_role: Role,
+    /// Role held after the change
+    pub new_role: Role,
+    /// Address that performed the change
+    pub changed_by: Address,
+    /// Ledger timestamp of the change
+    pub timestamp: u64,
+}
+
+/// A signature collected towards a multisig action
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Approval {
+    /// Address that signed
+    pub signer: Address,
+    /// Ledger timestamp of the signature
+    pub approved_at: u64,
 }
 
 /// Authorization errors
@@ -108,12 +154,188 @@ impl Role {
     }
 }
 
+/// Append a role change to the audit history, dropping the oldest entry once
+/// `MAX_ROLE_CHANGE_AUDIT` is reached
+fn record_role_change(env: &Env, target: &Address, old_role: Role, new_role: Role, changed_by: &Address) {
+    let mut audit: soroban_sdk::Vec<RoleChange> = env
+        .storage()
+        .persistent()
+        .get(&RoleKey::RoleChangeAudit)
+        .unwrap_or(soroban_sdk::Vec::new(env));
+
+    if audit.len() >= MAX_ROLE_CHANGE_AUDIT {
+        audit.pop_front();
+    }
+    audit.push_back(RoleChange {
+        target: target.clone(),
+        old_role,
+        new_role,
+        changed_by: changed_by.clone(),
+        timestamp: env.ledger().timestamp(),
+    });
+
+    env.storage().persistent().set(&RoleKey::RoleChangeAudit, &audit);
+}
+
+/// Keep the role holder index in step with whether an address holds any
+/// non-User role
+fn update_role_index(env: &Env, target: &Address, holds_role: bool) {
+    let mut holders: soroban_sdk::Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&RoleKey::RoleHolders)
+        .unwrap_or(soroban_sdk::Vec::new(env));
+
+    match (holders.first_index_of(target), holds_role) {
+        (Some(index), false) => {
+            holders.remove(index);
+        }
+        (None, true) => holders.push_back(target.clone()),
+        _ => return,
+    }
+
+    env.storage().persistent().set(&RoleKey::RoleHolders, &holders);
+}
+
+/// Make one of the address's roles permanent again
+fn clear_role_expiry(env: &Env, target: &Address, role: &Role) {
+    env.storage()
+        .persistent()
+        .remove(&RoleKey::RoleExpiry(target.clone(), role.clone()));
+}
+
+/// Whether a time-bounded grant of `role` to the address has lapsed
+fn role_expired(env: &Env, address: &Address, role: &Role) -> bool {
+    match get_role_expiry(env, address, role.clone()) {
+        Some(expires_at) => env.ledger().timestamp() > expires_at,
+        None => false,
+    }
+}
+
+/// Roles stored for an address, including any whose grant has lapsed.
+/// Falls back to a legacy single-role entry not yet migrated.
+fn stored_roles(env: &Env, address: &Address) -> soroban_sdk::Vec<Role> {
+    if let Some(roles) = env
+        .storage()
+        .persistent()
+        .get(&RoleKey::UserRoles(address.clone()))
+    {
+        return roles;
+    }
+
+    match env
+        .storage()
+        .persistent()
+        .get::<_, Role>(&RoleKey::UserRole(address.clone()))
+    {
+        Some(role) => single_role(env, &role),
+        None => soroban_sdk::Vec::new(env), // No roles assigned means User
+    }
+}
+
+/// Role set holding just `role`; User is stored as no roles
+fn single_role(env: &Env, role: &Role) -> soroban_sdk::Vec<Role> {
+    let mut roles = soroban_sdk::Vec::new(env);
+    if *role != Role::User {
+        roles.push_back(role.clone());
+    }
+    roles
+}
+
+/// Replace the roles held by an address, keeping the holder index in step.
+/// Any delegation link is dropped; `delegate_role` records a new one after,
+/// as are the expiries of roles the address no longer holds.
+fn set_roles(env: &Env, target: &Address, roles: &soroban_sdk::Vec<Role>) {
+    for role in stored_roles(env, target).iter() {
+        if !roles.contains(&role) {
+            clear_role_expiry(env, target, &role);
+        }
+    }
+
+    let key = RoleKey::UserRoles(target.clone());
+    if roles.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, roles);
+    }
+    env.storage().persistent().remove(&RoleKey::UserRole(target.clone()));
+    env.storage().persistent().remove(&RoleKey::DelegatedBy(target.clone()));
+    update_role_index(env, target, !roles.is_empty());
+}
+
+/// Highest-privilege role of a set. Role variants are declared from most to
+/// least privileged, so this is the smallest one.
+fn highest_role(roles: &soroban_sdk::Vec<Role>) -> Role {
+    roles.iter().min().unwrap_or(Role::User)
+}
+
+/// Whether a role set includes `role`; an empty set holds only User
+fn holds_role(roles: &soroban_sdk::Vec<Role>, role: &Role) -> bool {
+    if roles.is_empty() {
+        *role == Role::User
+    } else {
+        roles.contains(role)
+    }
+}
+
+/// Whether any role held by the address passes `permission_check`
+fn any_role_permits<F>(env: &Env, address: &Address, permission_check: F) -> bool
+where
+    F: Fn(&Role) -> bool,
+{
+    let roles = get_roles(env, address);
+    if roles.is_empty() {
+        permission_check(&Role::User)
+    } else {
+        roles.iter().any(|role| permission_check(&role))
+    }
+}
+
 /// Core authorization functions
 
 /// Initialize contract admin (call once during contract initialization)
 pub fn initialize_admin(env: &Env, admin: Address) {
+    let old_role = get_role(env, &admin);
     env.storage().persistent().set(&RoleKey::ContractAdmin, &admin);
-    env.storage().persistent().set(&RoleKey::UserRole(admin.clone()), &Role::Admin);
+    set_roles(env, &admin, &single_role(env, &Role::Admin));
+    clear_role_expiry(env, &admin, &Role::Admin);
+    record_role_change(env, &admin, old_role, Role::Admin, &admin);
+}
+
+/// One-time import of a contract's legacy `ADMIN` address into the shared
+/// role store. The host contract reads its own legacy admin key and passes it
+/// in; the legacy admin must authorize. If an admin is already recorded it
+/// must match `legacy_admin`. Later calls fail with `Unauthorized`.
+pub fn migrate_admin_to_rbac(env: &Env, legacy_admin: &Address) -> Result<(), AuthError> {
+    legacy_admin.require_auth();
+
+    if is_admin_migrated(env) {
+        return Err(AuthError::Unauthorized);
+    }
+
+    let old_role = get_role(env, legacy_admin);
+    match get_admin(env) {
+        Some(admin) if admin != *legacy_admin => return Err(AuthError::Unauthorized),
+        Some(_) => {}
+        None => env.storage().persistent().set(&RoleKey::ContractAdmin, legacy_admin),
+    }
+    set_roles(env, legacy_admin, &single_role(env, &Role::Admin));
+    env.storage().persistent().set(&RoleKey::AdminMigrated, &true);
+    clear_role_expiry(env, legacy_admin, &Role::Admin);
+
+    record_role_change(env, legacy_admin, old_role, Role::Admin, legacy_admin);
+
+    env.events().publish(
+        (soroban_sdk::Symbol::new(env, "admin_migrated"),),
+        legacy_admin.clone(),
+    );
+
+    Ok(())
+}
+
+/// Whether `migrate_admin_to_rbac` has already run
+pub fn is_admin_migrated(env: &Env) -> bool {
+    env.storage().persistent().get(&RoleKey::AdminMigrated).unwrap_or(false)
 }
 
 /// Get the contract admin address
@@ -121,7 +343,7 @@ pub fn get_admin(env: &Env) -> Option<Address> {
     env.storage().persistent().get(&RoleKey::ContractAdmin)
 }
 
-/// Grant a role to an address (admin only)
+/// Grant a role to an address, replacing any roles it held (admin only)
 pub fn grant_role(
     env: &Env,
     caller: &Address,
@@ -131,24 +353,105 @@ pub fn grant_role(
     // Verify caller is admin
     require_role(env, caller, Role::Admin)?;
 
+    let old_role = get_role(env, target);
+
     // Grant the role
+    set_roles(env, target, &single_role(env, &role));
+    clear_role_expiry(env, target, &role);
 
-    env.storage()
-        .persistent()
-        .set(&RoleKey::UserRole(target.clone()), &role);
-    
     // Emit event for role change logging
     env.events()
         .publish(("role_granted", target.clone(), role.clone()), caller.clone());
-    
 
-    env.storage().persistent().set(&RoleKey::UserRole(target.clone()), &role);
+    record_role_change(env, target, old_role, role, caller);
+
+    Ok(())
+}
+
+/// Grant a role that degrades to User once the ledger passes `expires_at`
+/// (admin only). The expiry applies to `role` alone, so roles added later stay
+/// permanent; an expiry that has already passed is rejected as `InvalidRole`.
+pub fn grant_role_until(
+    env: &Env,
+    caller: &Address,
+    target: &Address,
+    role: Role,
+    expires_at: u64,
+) -> Result<(), AuthError> {
+    if expires_at <= env.ledger().timestamp() {
+        return Err(AuthError::InvalidRole);
+    }
+
+    grant_role(env, caller, target, role.clone())?;
+    env.storage()
+        .persistent()
+        .set(&RoleKey::RoleExpiry(target.clone(), role.clone()), &expires_at);
+
+    env.events()
+        .publish(("role_expiry_set", target.clone(), role), expires_at);
+
+    Ok(())
+}
+
+/// Expiry timestamp of a time-bounded grant of `role` to an address, if any
+pub fn get_role_expiry(env: &Env, address: &Address, role: Role) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::RoleExpiry(address.clone(), role))
+}
+
+/// Add a role alongside the roles an address already holds (admin only)
+pub fn add_role(env: &Env, caller: &Address, target: &Address, role: Role) -> Result<(), AuthError> {
+    require_role(env, caller, Role::Admin)?;
+
+    if role == Role::User {
+        return Err(AuthError::InvalidRole);
+    }
 
+    let mut roles = get_roles(env, target);
+    if roles.contains(&role) {
+        return Ok(());
+    }
+    let old_role = highest_role(&roles);
+
+    roles.push_back(role.clone());
+    set_roles(env, target, &roles);
+    // A lapsed grant of the same role must not cut the new one short
+    clear_role_expiry(env, target, &role);
+
+    env.events()
+        .publish(("role_added", target.clone(), role), caller.clone());
+
+    record_role_change(env, target, old_role, highest_role(&roles), caller);
 
     Ok(())
 }
 
-/// Revoke a role from an address (admin only)
+/// Remove one role from an address, keeping its others (admin only)
+pub fn remove_role(env: &Env, caller: &Address, target: &Address, role: Role) -> Result<(), AuthError> {
+    require_role(env, caller, Role::Admin)?;
+
+    // Prevent admin from removing their own admin role (safeguard)
+    if caller == target && role == Role::Admin {
+        return Err(AuthError::Unauthorized);
+    }
+
+    let mut roles = get_roles(env, target);
+    let index = roles.first_index_of(&role).ok_or(AuthError::RoleNotFound)?;
+    let old_role = highest_role(&roles);
+
+    roles.remove(index);
+    set_roles(env, target, &roles);
+
+    env.events()
+        .publish(("role_removed", target.clone(), role), caller.clone());
+
+    record_role_change(env, target, old_role, highest_role(&roles), caller);
+
+    Ok(())
+}
+
+/// Revoke all roles from an address (admin only)
 pub fn revoke_role(env: &Env, caller: &Address, target: &Address) -> Result<(), AuthError> {
     // Verify caller is admin
     require_role(env, caller, Role::Admin)?;
@@ -158,39 +461,68 @@ pub fn revoke_role(env: &Env, caller: &Address, target: &Address) -> Result<(),
         return Err(AuthError::Unauthorized);
     }
 
-    // Revoke by setting to User role (lowest privilege)
-    env.storage()
-        .persistent()
-        .set(&RoleKey::UserRole(target.clone()), &Role::User);
-    
+    let old_role = get_role(env, target);
+
+    // Revoke by dropping back to User role (lowest privilege)
+    set_roles(env, target, &soroban_sdk::Vec::new(env));
+
     // Emit event for role change logging
     env.events()
         .publish(("role_revoked", target.clone()), caller.clone());
-    
-    env.storage().persistent().set(&RoleKey::UserRole(target.clone()), &Role::User);
+
+    record_role_change(env, target, old_role, Role::User, caller);
 
     Ok(())
 }
 
-/// Get the role of an address
-pub fn get_role(env: &Env, address: &Address) -> Role {
-    env.storage()
+/// Get the non-User roles held by an address, leaving out time-bounded
+/// grants that have lapsed
+pub fn get_roles(env: &Env, address: &Address) -> soroban_sdk::Vec<Role> {
+    let mut roles = soroban_sdk::Vec::new(env);
+    for role in stored_roles(env, address).iter() {
+        if !role_expired(env, address, &role) {
+            roles.push_back(role);
+        }
+    }
+    roles
+}
+
+/// Move an address's legacy single-role entry to the multi-role store and add
+/// it to the holder index. Returns whether there was anything to migrate.
+pub fn migrate_user_role(env: &Env, address: &Address) -> bool {
+    let legacy: Option<Role> = env
+        .storage()
         .persistent()
-        .get(&RoleKey::UserRole(address.clone()))
-        .unwrap_or(Role::User) // Default to User if no role assigned
+        .get(&RoleKey::UserRole(address.clone()));
+    let Some(role) = legacy else {
+        return false;
+    };
+
+    // A delegation link survives the move
+    let delegator = get_delegator(env, address);
+    set_roles(env, address, &single_role(env, &role));
+    if let Some(delegator) = delegator {
+        env.storage()
+            .persistent()
+            .set(&RoleKey::DelegatedBy(address.clone()), &delegator);
+    }
+    true
+}
+
+/// Get the highest-privilege role of an address; an expired time-bounded
+/// grant reads as User
+pub fn get_role(env: &Env, address: &Address) -> Role {
+    highest_role(&get_roles(env, address))
 }
 
-/// Check if an address has a specific role
+/// Check if an address holds a specific role
 pub fn has_role(env: &Env, address: &Address, required_role: Role) -> bool {
-    let user_role = get_role(env, address);
-    user_role == required_role
+    holds_role(&get_roles(env, address), &required_role)
 }
 
-/// Require that the caller has a specific role (throws error if not)
+/// Require that the caller holds a specific role (throws error if not)
 pub fn require_role(env: &Env, address: &Address, required_role: Role) -> Result<(), AuthError> {
-    let user_role = get_role(env, address);
-
-    if user_role == required_role {
+    if has_role(env, address, required_role) {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
@@ -202,10 +534,10 @@ pub fn require_admin(env: &Env, address: &Address) -> Result<(), AuthError> {
     require_role(env, address, Role::Admin)
 }
 
-/// Check if an address has any of the specified roles
+/// Check if an address holds any of the specified roles
 pub fn has_any_role(env: &Env, address: &Address, roles: &[Role]) -> bool {
-    let user_role = get_role(env, address);
-    roles.contains(&user_role)
+    let held = get_roles(env, address);
+    roles.iter().any(|role| holds_role(&held, role))
 }
 
 /// Require that the caller has one of the specified roles
@@ -219,7 +551,8 @@ pub fn require_any_role(env: &Env, address: &Address, roles: &[Role]) -> Result<
 
 /// Role delegation functions
 
-/// Delegate a role to another address (role-dependent permission)
+/// Delegate a role to another address, replacing any roles it held
+/// (role-dependent permission)
 pub fn delegate_role(env: &Env, caller: &Address, target: &Address, role: Role) -> Result<(), AuthError> {
     caller.require_auth();
     
@@ -228,31 +561,19 @@ pub fn delegate_role(env: &Env, caller: &Address, target: &Address, role: Role)
         Role::Admin => require_admin(env, caller), // Only admin can delegate admin role
         Role::PolicyManager => {
             // Admin or PolicyManager can delegate PolicyManager role
-            if !matches!(get_role(env, caller), Role::Admin | Role::PolicyManager) {
-                return Err(AuthError::Unauthorized);
-            }
-            Ok(())
+            require_any_role(env, caller, &[Role::Admin, Role::PolicyManager])
         },
         Role::ClaimProcessor => {
             // Admin or Governance can delegate ClaimProcessor role
-            if !matches!(get_role(env, caller), Role::Admin | Role::Governance) {
-                return Err(AuthError::Unauthorized);
-            }
-            Ok(())
+            require_any_role(env, caller, &[Role::Admin, Role::Governance])
         },
         Role::RiskPoolManager => {
             // Admin or Governance can delegate RiskPoolManager role
-            if !matches!(get_role(env, caller), Role::Admin | Role::Governance) {
-                return Err(AuthError::Unauthorized);
-            }
-            Ok(())
+            require_any_role(env, caller, &[Role::Admin, Role::Governance])
         },
         Role::Auditor => {
             // Admin or Governance can delegate Auditor role
-            if !matches!(get_role(env, caller), Role::Admin | Role::Governance) {
-                return Err(AuthError::Unauthorized);
-            }
-            Ok(())
+            require_any_role(env, caller, &[Role::Admin, Role::Governance])
         },
         Role::Governance => {
             // Only admin can delegate governance role
@@ -264,65 +585,106 @@ pub fn delegate_role(env: &Env, caller: &Address, target: &Address, role: Role)
         },
     }?;
     
+    let old_role = get_role(env, target);
+
     // Grant the role to the target
+    set_roles(env, target, &single_role(env, &role));
+    clear_role_expiry(env, target, &role);
     env.storage()
         .persistent()
-        .set(&RoleKey::UserRole(target.clone()), &role);
+        .set(&RoleKey::DelegatedBy(target.clone()), caller);
     
     // Emit event for role delegation logging
     env.events()
         .publish(("role_delegated", target.clone(), role.clone()), caller.clone());
     
+    record_role_change(env, target, old_role, role, caller);
+
     Ok(())
 }
 
 /// Check if an address can delegate a specific role
 pub fn can_delegate_role(env: &Env, address: &Address, role: Role) -> bool {
     match role {
-        Role::Admin => matches!(get_role(env, address), Role::Admin),
-        Role::PolicyManager => matches!(get_role(env, address), Role::Admin | Role::PolicyManager),
-        Role::ClaimProcessor => matches!(get_role(env, address), Role::Admin | Role::Governance),
-        Role::RiskPoolManager => matches!(get_role(env, address), Role::Admin | Role::Governance),
-        Role::Auditor => matches!(get_role(env, address), Role::Admin | Role::Governance),
-        Role::Governance => matches!(get_role(env, address), Role::Admin),
+        Role::Admin => has_role(env, address, Role::Admin),
+        Role::PolicyManager => has_any_role(env, address, &[Role::Admin, Role::PolicyManager]),
+        Role::ClaimProcessor => has_any_role(env, address, &[Role::Admin, Role::Governance]),
+        Role::RiskPoolManager => has_any_role(env, address, &[Role::Admin, Role::Governance]),
+        Role::Auditor => has_any_role(env, address, &[Role::Admin, Role::Governance]),
+        Role::Governance => has_role(env, address, Role::Admin),
         Role::User => true, // Anyone can delegate User role
     }
 }
 
+/// Get the address that delegated an address's current role, if it was delegated
+pub fn get_delegator(env: &Env, target: &Address) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::DelegatedBy(target.clone()))
+}
+
 /// Revoke a delegated role (admin or the original delegator can revoke)
 pub fn revoke_delegated_role(env: &Env, caller: &Address, target: &Address) -> Result<(), AuthError> {
     caller.require_auth();
     
-    // Only admin can revoke a role in this implementation
-    if !matches!(get_role(env, caller), Role::Admin) {
+    let delegator = get_delegator(env, target);
+    if !has_role(env, caller, Role::Admin) && delegator.as_ref() != Some(caller) {
         return Err(AuthError::Unauthorized);
     }
     
+    let old_role = get_role(env, target);
+
     // Revert to User role (lowest privilege)
-    env.storage()
-        .persistent()
-        .set(&RoleKey::UserRole(target.clone()), &Role::User);
+    set_roles(env, target, &soroban_sdk::Vec::new(env));
     
     // Emit event for role revocation logging
-    env.events()
-        .publish(("role_delegation_revoked", target.clone()), caller.clone());
+    env.events().publish(
+        ("role_delegation_revoked", target.clone()),
+        (caller.clone(), delegator, old_role.clone()),
+    );
     
+    record_role_change(env, target, old_role, Role::User, caller);
+
     Ok(())
 }
 
-/// Get all roles assigned to an address (for audit purposes)
+/// Get every `(address, role)` assignment of a non-User role (for audit purposes)
 pub fn get_all_roles(env: &Env) -> soroban_sdk::Vec<(Address, Role)> {
-    // This is a simplified implementation since Soroban storage doesn't support iteration
-    // In a real implementation, we'd need to maintain a separate mapping for this
-    soroban_sdk::Vec::new(env)
+    let holders: soroban_sdk::Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&RoleKey::RoleHolders)
+        .unwrap_or(soroban_sdk::Vec::new(env));
+
+    let mut assignments = soroban_sdk::Vec::new(env);
+    for holder in holders.iter() {
+        for role in get_roles(env, &holder).iter() {
+            assignments.push_back((holder.clone(), role));
+        }
+    }
+    assignments
+}
+
+/// Get a page of the role change audit history, oldest entry first
+pub fn get_role_change_audit(env: &Env, start: u32, limit: u32) -> soroban_sdk::Vec<RoleChange> {
+    let audit: soroban_sdk::Vec<RoleChange> = env
+        .storage()
+        .persistent()
+        .get(&RoleKey::RoleChangeAudit)
+        .unwrap_or(soroban_sdk::Vec::new(env));
+
+    let end = start.saturating_add(limit).min(audit.len());
+    if start >= end {
+        return soroban_sdk::Vec::new(env);
+    }
+    audit.slice(start..end)
 }
 
 /// Permission-based authorization (more granular than role-based)
 
 /// Require permission to manage policies
 pub fn require_policy_management(env: &Env, address: &Address) -> Result<(), AuthError> {
-    let role = get_role(env, address);
-    if role.can_manage_policies() {
+    if any_role_permits(env, address, Role::can_manage_policies) {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
@@ -331,8 +693,7 @@ pub fn require_policy_management(env: &Env, address: &Address) -> Result<(), Aut
 
 /// Require permission to process claims
 pub fn require_claim_processing(env: &Env, address: &Address) -> Result<(), AuthError> {
-    let role = get_role(env, address);
-    if role.can_process_claims() {
+    if any_role_permits(env, address, Role::can_process_claims) {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
@@ -341,8 +702,7 @@ pub fn require_claim_processing(env: &Env, address: &Address) -> Result<(), Auth
 
 /// Require permission to manage risk pool
 pub fn require_risk_pool_management(env: &Env, address: &Address) -> Result<(), AuthError> {
-    let role = get_role(env, address);
-    if role.can_manage_risk_pool() {
+    if any_role_permits(env, address, Role::can_manage_risk_pool) {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
@@ -351,8 +711,7 @@ pub fn require_risk_pool_management(env: &Env, address: &Address) -> Result<(),
 
 /// Require permission to participate in governance
 pub fn require_governance_permission(env: &Env, address: &Address) -> Result<(), AuthError> {
-    let role = get_role(env, address);
-    if role.can_govern() {
+    if any_role_permits(env, address, Role::can_govern) {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
@@ -422,7 +781,7 @@ pub fn verify_and_require_role(
     require_role(env, caller, required_role)
 }
 
-/// Utility: Verify identity and check permission
+/// Utility: Verify identity and check that any held role grants the permission
 pub fn verify_and_check_permission<F>(
     env: &Env,
     caller: &Address,
@@ -433,8 +792,7 @@ where
 {
     caller.require_auth();
 
-    let role = get_role(env, caller);
-    if permission_check(&role) {
+    if any_role_permits(env, caller, permission_check) {
         Ok(())
     } else {
         Err(AuthError::Unauthorized)
@@ -446,14 +804,96 @@ pub fn get_threshold(env: &Env, role: Role) -> u32 {
     env.storage().persistent().get(&RoleKey::Threshold(role)).unwrap_or(1) // Default to 1 (standard single-sig)
 }
 
+/// Returns how long a multisig approval for a role stays valid, in seconds.
+/// 0 means approvals never go stale.
+pub fn get_approval_ttl(env: &Env, role: Role) -> u64 {
+    env.storage().persistent().get(&RoleKey::ApprovalTtl(role)).unwrap_or(0)
+}
+
+/// Approvals collected for an action, oldest first
+fn load_approvals(env: &Env, action_hash: &soroban_sdk::BytesN<32>) -> soroban_sdk::Vec<Approval> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::Approvals(action_hash.clone()))
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+/// Drop approvals older than the role's TTL
+fn prune_stale_approvals(
+    env: &Env,
+    approvals: &soroban_sdk::Vec<Approval>,
+    role: &Role,
+) -> soroban_sdk::Vec<Approval> {
+    let ttl = get_approval_ttl(env, role.clone());
+    if ttl == 0 {
+        return approvals.clone();
+    }
+
+    let now = env.ledger().timestamp();
+    let mut fresh = soroban_sdk::Vec::new(env);
+    for approval in approvals.iter() {
+        if now.saturating_sub(approval.approved_at) <= ttl {
+            fresh.push_back(approval);
+        }
+    }
+    fresh
+}
+
+/// Signer addresses of a list of approvals
+fn approval_signers(env: &Env, approvals: &soroban_sdk::Vec<Approval>) -> soroban_sdk::Vec<Address> {
+    let mut signers = soroban_sdk::Vec::new(env);
+    for approval in approvals.iter() {
+        signers.push_back(approval.signer);
+    }
+    signers
+}
+
 /// Returns the list of addresses that have already signed a specific action.
-/// Useful for frontends to show "2 of 3 signed".
+/// Useful for frontends to show "2 of 3 signed". Stale approvals are only
+/// pruned when the next signature arrives.
 pub fn get_approvals(env: &Env, action_hash: soroban_sdk::BytesN<32>) -> soroban_sdk::Vec<Address> {
+    approval_signers(env, &load_approvals(env, &action_hash))
+}
+/// Drop every pending approval for an action and return how many were removed.
+/// Callers decide who may abandon an action; completed audit trails are kept.
+pub fn clear_approvals(env: &Env, action_hash: soroban_sdk::BytesN<32>) -> u32 {
+    let pending = load_approvals(env, &action_hash).len();
+    env.storage().persistent().remove(&RoleKey::Approvals(action_hash));
+    pending
+}
+
+/// Returns the addresses that signed a completed multisig action, in signing order.
+/// Empty while the action is still collecting approvals.
+pub fn get_multisig_audit_trail(
+    env: &Env,
+    action_hash: soroban_sdk::BytesN<32>,
+) -> soroban_sdk::Vec<Address> {
     env.storage()
         .persistent()
-        .get(&RoleKey::Approvals(action_hash))
+        .get(&RoleKey::MultisigAudit(action_hash))
         .unwrap_or(soroban_sdk::Vec::new(env))
 }
+
+/// Returns `(collected, threshold)` for an action without requiring auth.
+/// Stale approvals are not counted; a completed action reports its final signers.
+pub fn approval_progress(env: &Env, action_hash: soroban_sdk::BytesN<32>, role: Role) -> (u32, u32) {
+    let threshold = get_threshold(env, role.clone());
+
+    let completed = get_multisig_audit_trail(env, action_hash.clone());
+    if !completed.is_empty() {
+        return (completed.len(), threshold);
+    }
+
+    let fresh = prune_stale_approvals(env, &load_approvals(env, &action_hash), &role);
+    (fresh.len(), threshold)
+}
+
+/// Whether an action has collected enough valid approvals for `role`
+pub fn is_action_approved(env: &Env, action_hash: soroban_sdk::BytesN<32>, role: Role) -> bool {
+    let (collected, threshold) = approval_progress(env, action_hash, role);
+    collected >= threshold
+}
+
 /// Core Multi-Sig Logic with Event Logging
 pub fn check_multisig_auth(
     env: &Env,
@@ -474,14 +914,15 @@ pub fn check_multisig_auth(
         return Ok(true);
     }
 
-    let mut approvals: soroban_sdk::Vec<Address> = env
-        .storage()
-        .persistent()
-        .get(&RoleKey::Approvals(action_hash.clone()))
-        .unwrap_or(soroban_sdk::Vec::new(env));
+    // Signatures older than the role's TTL no longer count
+    let mut approvals =
+        prune_stale_approvals(env, &load_approvals(env, &action_hash), &required_role);
 
-    if !approvals.contains(caller) {
-        approvals.push_back(caller.clone());
+    if !approvals.iter().any(|approval| approval.signer == *caller) {
+        approvals.push_back(Approval {
+            signer: caller.clone(),
+            approved_at: env.ledger().timestamp(),
+        });
         env.storage()
             .persistent()
             .set(&RoleKey::Approvals(action_hash.clone()), &approvals);
@@ -502,6 +943,11 @@ pub fn check_multisig_auth(
             required_role,
         );
 
+        // Keep who signed before the active approvals are cleared
+        let signers = approval_signers(env, &approvals);
+        env.storage()
+            .persistent()
+            .set(&RoleKey::MultisigAudit(action_hash.clone()), &signers);
         env.storage().persistent().remove(&RoleKey::Approvals(action_hash));
         Ok(true)
     } else {
@@ -527,3 +973,546 @@ pub fn set_threshold(
 
     Ok(())
 }
+
+/// Set how long multisig approvals for a role stay valid (0 disables expiry)
+pub fn set_approval_ttl(
+    env: &Env,
+    admin: Address,
+    role: Role,
+    ttl_seconds: u64,
+) -> Result<(), AuthError> {
+    admin.require_auth();
+    require_admin(env, &admin)?;
+
+    env.storage().persistent().set(&RoleKey::ApprovalTtl(role.clone()), &ttl_seconds);
+
+    env.events()
+        .publish((soroban_sdk::Symbol::new(env, "msig_ttl_set"), role), ttl_seconds);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Events, Ledger};
+    use soroban_sdk::{contract, contractimpl, xdr, IntoVal, TryFromVal, Val};
+
+    #[contract]
+    struct AuthHost;
+
+    #[contractimpl]
+    impl AuthHost {}
+
+    #[test]
+    fn test_role_change_audit_records_operations_in_order() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let processor = Address::generate(&env);
+        let auditor = Address::generate(&env);
+
+        env.as_contract(&host, || initialize_admin(&env, admin.clone()));
+        env.ledger().with_mut(|l| l.timestamp = 100);
+        env.as_contract(&host, || grant_role(&env, &admin, &processor, Role::ClaimProcessor))
+            .unwrap();
+        env.ledger().with_mut(|l| l.timestamp = 200);
+        env.as_contract(&host, || delegate_role(&env, &admin, &auditor, Role::Auditor)).unwrap();
+        env.as_contract(&host, || revoke_role(&env, &admin, &processor)).unwrap();
+        env.as_contract(&host, || revoke_delegated_role(&env, &admin, &auditor)).unwrap();
+
+        // Failed operations leave no trace
+        let result = env.as_contract(&host, || grant_role(&env, &processor, &auditor, Role::Admin));
+        assert_eq!(result, Err(AuthError::Unauthorized));
+
+        env.as_contract(&host, || {
+            let audit = get_role_change_audit(&env, 0, 10);
+            assert_eq!(audit.len(), 5);
+
+            let expected = [
+                (&admin, Role::User, Role::Admin, 0u64),
+                (&processor, Role::User, Role::ClaimProcessor, 100),
+                (&auditor, Role::User, Role::Auditor, 200),
+                (&processor, Role::ClaimProcessor, Role::User, 200),
+                (&auditor, Role::Auditor, Role::User, 200),
+            ];
+            for (i, (target, old_role, new_role, timestamp)) in expected.into_iter().enumerate() {
+                let entry = audit.get(i as u32).unwrap();
+                assert_eq!(&entry.target, target);
+                assert_eq!(entry.old_role, old_role);
+                assert_eq!(entry.new_role, new_role);
+                assert_eq!(entry.changed_by, admin);
+                assert_eq!(entry.timestamp, timestamp);
+            }
+
+            // Pagination
+            let page = get_role_change_audit(&env, 3, 10);
+            assert_eq!(page.len(), 2);
+            assert_eq!(page.get(0).unwrap().target, processor);
+            assert_eq!(get_role_change_audit(&env, 5, 10).len(), 0);
+        });
+    }
+
+    #[test]
+    fn test_role_change_audit_is_bounded() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let target = Address::generate(&env);
+
+        env.as_contract(&host, || initialize_admin(&env, admin.clone()));
+        for _ in 0..MAX_ROLE_CHANGE_AUDIT {
+            env.as_contract(&host, || {
+                grant_role(&env, &admin, &target, Role::Auditor).unwrap();
+            });
+        }
+
+        env.as_contract(&host, || {
+            let audit = get_role_change_audit(&env, 0, MAX_ROLE_CHANGE_AUDIT + 1);
+            assert_eq!(audit.len(), MAX_ROLE_CHANGE_AUDIT);
+            // The initial admin assignment was evicted first
+            assert_eq!(audit.get(0).unwrap().target, target);
+        });
+    }
+
+    #[test]
+    fn test_migrate_admin_to_rbac_runs_once() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let legacy_admin = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            assert_eq!(get_role(&env, &legacy_admin), Role::User);
+            assert!(!is_admin_migrated(&env));
+        });
+
+        env.as_contract(&host, || migrate_admin_to_rbac(&env, &legacy_admin)).unwrap();
+
+        env.as_contract(&host, || {
+            assert_eq!(get_role(&env, &legacy_admin), Role::Admin);
+            assert_eq!(get_admin(&env), Some(legacy_admin.clone()));
+            assert!(is_admin_migrated(&env));
+
+            let audit = get_role_change_audit(&env, 0, 10);
+            assert_eq!(audit.len(), 1);
+            assert_eq!(audit.get(0).unwrap().new_role, Role::Admin);
+        });
+
+        let other = Address::generate(&env);
+        let result = env.as_contract(&host, || migrate_admin_to_rbac(&env, &other));
+        assert_eq!(result, Err(AuthError::Unauthorized));
+        env.as_contract(&host, || assert_eq!(get_role(&env, &other), Role::User));
+    }
+
+    #[test]
+    fn test_migrate_admin_to_rbac_rejects_other_than_recorded_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let impostor = Address::generate(&env);
+
+        env.as_contract(&host, || initialize_admin(&env, admin.clone()));
+
+        let result = env.as_contract(&host, || migrate_admin_to_rbac(&env, &impostor));
+        assert_eq!(result, Err(AuthError::Unauthorized));
+        env.as_contract(&host, || {
+            assert_eq!(get_role(&env, &impostor), Role::User);
+            assert!(!is_admin_migrated(&env));
+        });
+
+        env.as_contract(&host, || migrate_admin_to_rbac(&env, &admin)).unwrap();
+        env.as_contract(&host, || assert!(is_admin_migrated(&env)));
+    }
+
+    #[test]
+    fn test_multisig_audit_trail_survives_completion() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let signer1 = Address::generate(&env);
+        let signer2 = Address::generate(&env);
+        let action_hash = soroban_sdk::BytesN::from_array(&env, &[9; 32]);
+
+        env.as_contract(&host, || {
+            initialize_admin(&env, admin.clone());
+            grant_role(&env, &admin, &signer1, Role::Governance).unwrap();
+            grant_role(&env, &admin, &signer2, Role::Governance).unwrap();
+            set_threshold(&env, admin.clone(), Role::Governance, 2).unwrap();
+        });
+
+        let done = env.as_contract(&host, || {
+            check_multisig_auth(&env, &signer1, action_hash.clone(), Role::Governance)
+        });
+        assert_eq!(done, Ok(false));
+        env.as_contract(&host, || {
+            assert_eq!(get_approvals(&env, action_hash.clone()).len(), 1);
+            assert_eq!(get_multisig_audit_trail(&env, action_hash.clone()).len(), 0);
+        });
+
+        let done = env.as_contract(&host, || {
+            check_multisig_auth(&env, &signer2, action_hash.clone(), Role::Governance)
+        });
+        assert_eq!(done, Ok(true));
+
+        env.as_contract(&host, || {
+            assert_eq!(get_approvals(&env, action_hash.clone()).len(), 0);
+
+            let trail = get_multisig_audit_trail(&env, action_hash.clone());
+            assert_eq!(trail, soroban_sdk::vec![&env, signer1.clone(), signer2.clone()]);
+        });
+    }
+
+    #[test]
+    fn test_get_all_roles_tracks_current_assignments() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let manager = Address::generate(&env);
+        let processor = Address::generate(&env);
+        let auditor = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            assert_eq!(get_all_roles(&env).len(), 0);
+
+            initialize_admin(&env, admin.clone());
+            grant_role(&env, &admin, &manager, Role::PolicyManager).unwrap();
+            grant_role(&env, &admin, &processor, Role::ClaimProcessor).unwrap();
+        });
+        env.as_contract(&host, || {
+            delegate_role(&env, &admin, &auditor, Role::Auditor).unwrap();
+        });
+        env.as_contract(&host, || {
+            // Regranting changes the role without duplicating the entry
+            grant_role(&env, &admin, &manager, Role::RiskPoolManager).unwrap();
+
+            assert_eq!(
+                get_all_roles(&env),
+                soroban_sdk::vec![
+                    &env,
+                    (admin.clone(), Role::Admin),
+                    (manager.clone(), Role::RiskPoolManager),
+                    (processor.clone(), Role::ClaimProcessor),
+                    (auditor.clone(), Role::Auditor),
+                ]
+            );
+
+            revoke_role(&env, &admin, &processor).unwrap();
+        });
+        env.as_contract(&host, || {
+            revoke_delegated_role(&env, &admin, &auditor).unwrap();
+            assert_eq!(
+                get_all_roles(&env),
+                soroban_sdk::vec![
+                    &env,
+                    (admin.clone(), Role::Admin),
+                    (manager.clone(), Role::RiskPoolManager),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn test_time_bounded_grant_expires_to_user() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let auditor = Address::generate(&env);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        env.as_contract(&host, || {
+            initialize_admin(&env, admin.clone());
+            assert_eq!(
+                grant_role_until(&env, &admin, &auditor, Role::Auditor, 1_000),
+                Err(AuthError::InvalidRole)
+            );
+            grant_role_until(&env, &admin, &auditor, Role::Auditor, 2_000).unwrap();
+
+            assert_eq!(get_role(&env, &auditor), Role::Auditor);
+            assert_eq!(get_role_expiry(&env, &auditor, Role::Auditor), Some(2_000));
+            assert!(require_role(&env, &auditor, Role::Auditor).is_ok());
+        });
+
+        // Still valid at the expiry timestamp itself
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        env.as_contract(&host, || assert_eq!(get_role(&env, &auditor), Role::Auditor));
+
+        env.ledger().with_mut(|l| l.timestamp = 2_001);
+        env.as_contract(&host, || {
+            assert_eq!(get_role(&env, &auditor), Role::User);
+            assert_eq!(
+                require_role(&env, &auditor, Role::Auditor),
+                Err(AuthError::Unauthorized)
+            );
+            assert!(!get_all_roles(&env).iter().any(|(holder, _)| holder == auditor));
+
+            // A permanent grant clears the expiry
+            grant_role(&env, &admin, &auditor, Role::Auditor).unwrap();
+            assert_eq!(get_role_expiry(&env, &auditor, Role::Auditor), None);
+            assert_eq!(get_role(&env, &auditor), Role::Auditor);
+        });
+    }
+
+    #[test]
+    fn test_role_expiry_is_per_role() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let member = Address::generate(&env);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        env.as_contract(&host, || {
+            initialize_admin(&env, admin.clone());
+            grant_role_until(&env, &admin, &member, Role::Auditor, 2_000).unwrap();
+            add_role(&env, &admin, &member, Role::PolicyManager).unwrap();
+            assert_eq!(get_role_expiry(&env, &member, Role::PolicyManager), None);
+        });
+
+        // Only the time-bounded role lapses
+        env.ledger().with_mut(|l| l.timestamp = 2_001);
+        env.as_contract(&host, || {
+            assert_eq!(
+                get_roles(&env, &member),
+                soroban_sdk::vec![&env, Role::PolicyManager]
+            );
+            assert!(!has_role(&env, &member, Role::Auditor));
+
+            // Re-adding the lapsed role makes it permanent
+            add_role(&env, &admin, &member, Role::Auditor).unwrap();
+            assert_eq!(get_role_expiry(&env, &member, Role::Auditor), None);
+            assert!(has_role(&env, &member, Role::Auditor));
+        });
+    }
+
+    #[test]
+    fn test_legacy_single_role_is_read_and_migrated() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let legacy = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            initialize_admin(&env, admin.clone());
+            // Entry written before roles were stored as a list
+            env.storage()
+                .persistent()
+                .set(&RoleKey::UserRole(legacy.clone()), &Role::ClaimProcessor);
+
+            assert_eq!(get_role(&env, &legacy), Role::ClaimProcessor);
+            assert!(require_role(&env, &legacy, Role::ClaimProcessor).is_ok());
+            assert!(!get_all_roles(&env).iter().any(|(holder, _)| holder == legacy));
+
+            assert!(migrate_user_role(&env, &legacy));
+            assert!(!migrate_user_role(&env, &legacy));
+            assert!(!env.storage().persistent().has(&RoleKey::UserRole(legacy.clone())));
+            assert_eq!(get_roles(&env, &legacy), soroban_sdk::vec![&env, Role::ClaimProcessor]);
+            assert!(get_all_roles(&env).contains((legacy.clone(), Role::ClaimProcessor)));
+
+            // Later writes go to the new key
+            add_role(&env, &admin, &legacy, Role::Auditor).unwrap();
+            assert_eq!(
+                get_roles(&env, &legacy),
+                soroban_sdk::vec![&env, Role::ClaimProcessor, Role::Auditor]
+            );
+        });
+    }
+
+    #[test]
+    fn test_address_can_hold_multiple_roles() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let member = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            initialize_admin(&env, admin.clone());
+            grant_role(&env, &admin, &member, Role::Auditor).unwrap();
+            add_role(&env, &admin, &member, Role::PolicyManager).unwrap();
+            assert_eq!(
+                add_role(&env, &admin, &member, Role::User),
+                Err(AuthError::InvalidRole)
+            );
+
+            assert!(require_role(&env, &member, Role::PolicyManager).is_ok());
+            assert!(require_role(&env, &member, Role::Auditor).is_ok());
+            assert!(require_policy_management(&env, &member).is_ok());
+            assert!(verify_and_check_permission(&env, &member, Role::can_audit).is_ok());
+            assert!(has_any_role(&env, &member, &[Role::ClaimProcessor, Role::Auditor]));
+            assert!(!has_role(&env, &member, Role::User));
+            // Highest privilege wins for single-role readers
+            assert_eq!(get_role(&env, &member), Role::PolicyManager);
+            assert_eq!(get_all_roles(&env).len(), 3);
+
+            remove_role(&env, &admin, &member, Role::PolicyManager).unwrap();
+            assert_eq!(get_role(&env, &member), Role::Auditor);
+            assert!(require_policy_management(&env, &member).is_err());
+            assert_eq!(
+                remove_role(&env, &admin, &member, Role::PolicyManager),
+                Err(AuthError::RoleNotFound)
+            );
+
+            remove_role(&env, &admin, &member, Role::Auditor).unwrap();
+            assert_eq!(get_role(&env, &member), Role::User);
+            assert!(has_role(&env, &member, Role::User));
+            assert_eq!(get_all_roles(&env), soroban_sdk::vec![&env, (admin.clone(), Role::Admin)]);
+        });
+    }
+
+    #[test]
+    fn test_delegator_can_revoke_own_delegation() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let governance = Address::generate(&env);
+        let auditor = Address::generate(&env);
+        let outsider = Address::generate(&env);
+
+        env.as_contract(&host, || {
+            initialize_admin(&env, admin.clone());
+            grant_role(&env, &admin, &governance, Role::Governance).unwrap();
+        });
+        env.as_contract(&host, || {
+            delegate_role(&env, &governance, &auditor, Role::Auditor).unwrap();
+            assert_eq!(get_delegator(&env, &auditor), Some(governance.clone()));
+        });
+
+        env.as_contract(&host, || {
+            assert_eq!(
+                revoke_delegated_role(&env, &outsider, &auditor),
+                Err(AuthError::Unauthorized)
+            );
+            assert_eq!(get_role(&env, &auditor), Role::Auditor);
+        });
+
+        env.as_contract(&host, || {
+            revoke_delegated_role(&env, &governance, &auditor).unwrap();
+            assert_eq!(get_role(&env, &auditor), Role::User);
+            assert_eq!(get_delegator(&env, &auditor), None);
+        });
+        let event = env.events().all().events().last().unwrap().clone();
+        let xdr::ContractEventBody::V0(body) = event.body;
+        let expected: Val =
+            (governance.clone(), Some(governance.clone()), Role::Auditor).into_val(&env);
+        assert_eq!(body.data, xdr::ScVal::try_from_val(&env, &expected).unwrap());
+
+        // A direct grant is not a delegation the old delegator can undo
+        env.as_contract(&host, || {
+            delegate_role(&env, &governance, &auditor, Role::Auditor).unwrap();
+            grant_role(&env, &admin, &auditor, Role::Auditor).unwrap();
+        });
+        env.as_contract(&host, || {
+            assert_eq!(
+                revoke_delegated_role(&env, &governance, &auditor),
+                Err(AuthError::Unauthorized)
+            );
+        });
+    }
+
+    #[test]
+    fn test_stale_multisig_approvals_are_pruned() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let signer1 = Address::generate(&env);
+        let signer2 = Address::generate(&env);
+        let action_hash = soroban_sdk::BytesN::from_array(&env, &[3; 32]);
+
+        env.as_contract(&host, || {
+            initialize_admin(&env, admin.clone());
+            grant_role(&env, &admin, &signer1, Role::Governance).unwrap();
+            grant_role(&env, &admin, &signer2, Role::Governance).unwrap();
+            set_threshold(&env, admin.clone(), Role::Governance, 2).unwrap();
+        });
+        env.as_contract(&host, || {
+            set_approval_ttl(&env, admin.clone(), Role::Governance, 3_600).unwrap();
+            assert_eq!(get_approval_ttl(&env, Role::Governance), 3_600);
+        });
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let done = env.as_contract(&host, || {
+            check_multisig_auth(&env, &signer1, action_hash.clone(), Role::Governance)
+        });
+        assert_eq!(done, Ok(false));
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000 + 3_601);
+        let done = env.as_contract(&host, || {
+            check_multisig_auth(&env, &signer2, action_hash.clone(), Role::Governance)
+        });
+        // The first signature expired, so the threshold is not met
+        assert_eq!(done, Ok(false));
+        env.as_contract(&host, || {
+            assert_eq!(
+                get_approvals(&env, action_hash.clone()),
+                soroban_sdk::vec![&env, signer2.clone()]
+            );
+        });
+
+        let done = env.as_contract(&host, || {
+            check_multisig_auth(&env, &signer1, action_hash.clone(), Role::Governance)
+        });
+        assert_eq!(done, Ok(true));
+    }
+
+    #[test]
+    fn test_approval_progress_is_read_only() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(AuthHost, ());
+
+        let admin = Address::generate(&env);
+        let signers = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        let action_hash = soroban_sdk::BytesN::from_array(&env, &[5; 32]);
+
+        env.as_contract(&host, || {
+            initialize_admin(&env, admin.clone());
+            for signer in signers.iter() {
+                grant_role(&env, &admin, signer, Role::Governance).unwrap();
+            }
+            set_threshold(&env, admin.clone(), Role::Governance, 3).unwrap();
+            assert_eq!(approval_progress(&env, action_hash.clone(), Role::Governance), (0, 3));
+        });
+
+        for signer in signers[..2].iter() {
+            env.as_contract(&host, || {
+                check_multisig_auth(&env, signer, action_hash.clone(), Role::Governance).unwrap();
+            });
+        }
+
+        env.as_contract(&host, || {
+            assert_eq!(approval_progress(&env, action_hash.clone(), Role::Governance), (2, 3));
+            assert!(!is_action_approved(&env, action_hash.clone(), Role::Governance));
+            // Querying left the collected approvals untouched
+            assert_eq!(get_approvals(&env, action_hash.clone()).len(), 2);
+        });
+
+        env.as_contract(&host, || {
+            check_multisig_auth(&env, &signers[2], action_hash.clone(), Role::Governance)
+                .unwrap();
+            assert_eq!(approval_progress(&env, action_hash.clone(), Role::Governance), (3, 3));
+            assert!(is_action_approved(&env, action_hash.clone(), Role::Governance));
+        });
+    }
+}
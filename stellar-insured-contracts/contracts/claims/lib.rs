@@ -1,44 +1,9 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, IntoVal,
-    Symbol,
+    contract, contracterror, contractimpl, contracttype, symbol_short, xdr::ToXdr, Address,
+    Bytes, BytesN, Env, IntoVal, Symbol, Vec,
 };
 
-// Import the Policy contract interface to verify ownership and coverage
-// NOTE: policy contract client import omitted in this workspace build; it requires
-// a pre-built wasm artifact at build-time.
-use soroban_sdk::{contract, contractimpl, contracterror, contracttype, Address, Env, Symbol, symbol_short, IntoVal, Vec};
-
-// Import the Policy contract interface to verify ownership and coverage
-#[cfg(not(test))]
-mod policy_contract {
-    soroban_sdk::contractimport!(file = "../../target/wasm32-unknown-unknown/release/policy_contract.wasm");
-}
-
-// Mock policy contract client for tests
-#[cfg(test)]
-mod policy_contract {
-    use soroban_sdk::{Address, Env, contractclient};
-
-    // Mock client that returns test data
-    pub struct Client<'a> {
-        _env: &'a Env,
-        _contract_id: &'a Address,
-    }
-
-    impl<'a> Client<'a> {
-        pub fn new(env: &'a Env, contract_id: &'a Address) -> Self {
-            Self { _env: env, _contract_id: contract_id }
-        }
-
-        // Mock get_policy returns (holder, coverage_amount, ...)
-        pub fn get_policy(&self, _policy_id: &u64) -> (Address, i128, i128, u64, u64) {
-            // Return mock data - this won't be used in our unit tests
-            panic!("Mock policy_contract::Client::get_policy called - use unit tests that don't call submit_claim")
-        }
-    }
-}
-
 // Import shared types and authorization from the common library
 use insurance_contracts::authorization::{
     get_role, initialize_admin, register_trusted_contract, require_admin, require_claim_processing,
@@ -73,11 +38,16 @@ const CLAIM_ORACLE_ID: Symbol = symbol_short!("CLM_OID");
 // NOTE: Keys used for storing oracle data IDs per claim.
 const ORACLE_CFG: Symbol = ORACLE_CONFIG;
 const CLM_ORA: Symbol = CLAIM_ORACLE_ID;
-const CLM_ORA: Symbol = symbol_short!("CLM_ORA");
 
 // New storage keys for claim indexing
 const CLAIM_LIST: Symbol = symbol_short!("CLM_LST");
 const CLAIM_COUNTER: Symbol = symbol_short!("CLM_CNT");
+// Per-claim lock held while the risk pool payout call is in flight
+const SETTLE_LOCK: Symbol = symbol_short!("STL_LCK");
+const MSIG_LIMIT: Symbol = symbol_short!("MSIG_LMT");
+/// Claims above this amount need multisig settlement unless `set_msig_threshold`
+/// overrides it (5,000 units of a 7-decimal token)
+const DEFAULT_MSIG_THRESHOLD: i128 = 5_000 * 10_000_000;
 const CLAIM_SUBMIT_SCOPE: &str = "claim_submit";
 const DEFAULT_CLAIM_SUBMIT_RATE_LIMIT_MAX_CALLS: u32 = 3;
 const DEFAULT_CLAIM_SUBMIT_RATE_LIMIT_WINDOW_SECS: u64 = 60;
@@ -106,6 +76,8 @@ pub enum ContractError {
     InvalidRole = 15,
     RoleNotFound = 16,
     NotTrustedContract = 17,
+    // Settlement errors
+    InProgress = 18,
     // Invariant violation errors (100-199)
     InvalidClaimState = 102,
     InvalidAmount = 103,
@@ -134,6 +106,23 @@ impl From<insurance_contracts::authorization::AuthError> for ContractError {
     }
 }
 
+impl From<shared::ContractError> for ContractError {
+    fn from(err: shared::ContractError) -> Self {
+        match err {
+            shared::ContractError::Unauthorized => ContractError::Unauthorized,
+            shared::ContractError::Paused | shared::ContractError::FunctionPaused => {
+                ContractError::Paused
+            }
+            shared::ContractError::InvalidInput => ContractError::InvalidInput,
+            shared::ContractError::NotFound => ContractError::NotFound,
+            shared::ContractError::AlreadyExists => ContractError::AlreadyExists,
+            shared::ContractError::NotInitialized => ContractError::NotInitialized,
+            shared::ContractError::AlreadyInitialized => ContractError::AlreadyInitialized,
+            _ => ContractError::InvalidState,
+        }
+    }
+}
+
 impl From<InvariantError> for ContractError {
     fn from(err: InvariantError) -> Self {
         match err {
@@ -233,6 +222,14 @@ fn validate_coverage_constraint(
     Ok(())
 }
 
+/// Multisig action hash binding a settlement approval to the claim and its amount
+fn settlement_action_hash(env: &Env, claim_id: u64, amount: i128) -> BytesN<32> {
+    let mut payload = Bytes::new(env);
+    payload.append(&claim_id.to_xdr(env));
+    payload.append(&amount.to_xdr(env));
+    env.crypto().sha256(&payload).into()
+}
+
 #[contractimpl]
 impl ClaimsContract {
     pub fn initialize(
@@ -290,6 +287,26 @@ impl ClaimsContract {
         Ok(())
     }
 
+    /// Set the claim amount above which settlement requires ClaimProcessor multisig (admin only)
+    pub fn set_msig_threshold(env: Env, admin: Address, limit: i128) -> Result<(), ContractError> {
+        admin.require_auth();
+        require_admin(&env, &admin)?;
+
+        if limit <= 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        env.storage().persistent().set(&MSIG_LIMIT, &limit);
+
+        env.events().publish((Symbol::new(&env, "msig_threshold_set"), ()), (admin, limit));
+
+        Ok(())
+    }
+
+    pub fn get_msig_threshold(env: Env) -> i128 {
+        env.storage().persistent().get(&MSIG_LIMIT).unwrap_or(DEFAULT_MSIG_THRESHOLD)
+    }
+
     /// Initialize oracle validation for the claims contract
     pub fn set_oracle_config(
         env: Env,
@@ -404,7 +421,7 @@ impl ClaimsContract {
         }
         
         // Check for emergency pause
-        EmergencyPause::validate_not_paused(&env, Some(&symbol_short!("submit_claim")))?;
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "submit_claim")))?;
 
         rate_limit::enforce(
             &env,
@@ -494,10 +511,6 @@ impl ClaimsContract {
     Ok(claim_id)
     }
 
-    pub fn get_claim(
-        env: Env,
-        claim_id: u64,
-    ) -> Result<(u64, Address, i128, ClaimStatus, u64), ContractError> {
     /// Gets the next sequential claim ID and increments the counter.
     fn next_claim_id(env: &Env) -> u64 {
         let current_id: u64 = env
@@ -533,7 +546,7 @@ impl ClaimsContract {
         require_claim_processing(&env, &processor)?;
         
         // Check for emergency pause
-        EmergencyPause::validate_not_paused(&env, Some(&symbol_short!("approve_claim")))?;
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "approve_claim")))?;
 
         let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
             .storage()
@@ -591,7 +604,7 @@ impl ClaimsContract {
 
         env.storage().persistent().set(&(CLAIM, claim_id), &claim);
 
-        env.events().publish((symbol_short!("clm_app"), claim_id), (claim.1, claim.2));
+        env.events().publish((symbol_short!("clm_app"), claim_id), (claim.1.clone(), claim.2));
 
 
         env.events().publish((Symbol::new(&env, "ClaimApproved"), claim_id), (processor, claim.1, claim.2, env.ledger().timestamp()));
@@ -606,7 +619,7 @@ impl ClaimsContract {
         require_claim_processing(&env, &processor)?;
         
         // Check for emergency pause
-        EmergencyPause::validate_not_paused(&env, Some(&symbol_short!("start_review")))?;
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "start_review")))?;
 
         let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
             .storage()
@@ -625,7 +638,7 @@ impl ClaimsContract {
         env.storage().persistent().set(&(CLAIM, claim_id), &claim);
 
         env.events()
-            .publish((Symbol::new(&env, "claim_under_review"), claim_id), (claim.1, claim.2));
+            .publish((Symbol::new(&env, "claim_under_review"), claim_id), (claim.1.clone(), claim.2));
 
 
         env.events().publish((Symbol::new(&env, "ClaimReviewStarted"), claim_id), (processor, claim.1, claim.2, env.ledger().timestamp()));
@@ -640,7 +653,7 @@ impl ClaimsContract {
         require_claim_processing(&env, &processor)?;
         
         // Check for emergency pause
-        EmergencyPause::validate_not_paused(&env, Some(&symbol_short!("reject_claim")))?;
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "reject_claim")))?;
 
         let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
             .storage()
@@ -659,7 +672,7 @@ impl ClaimsContract {
         env.storage().persistent().set(&(CLAIM, claim_id), &claim);
 
         env.events()
-            .publish((Symbol::new(&env, "claim_rejected"), claim_id), (claim.1, claim.2));
+            .publish((Symbol::new(&env, "claim_rejected"), claim_id), (claim.1.clone(), claim.2));
 
 
         env.events().publish((Symbol::new(&env, "ClaimRejected"), claim_id), (processor, claim.1, claim.2, env.ledger().timestamp()));
@@ -674,12 +687,17 @@ impl ClaimsContract {
         claim_id: u64,
         payout_asset: Option<shared::types::Asset>,
     ) -> Result<(), ContractError> {
-        // Verify identity and require claim processing permission
-        processor.require_auth();
+        // Require claim processing permission; the processor's auth is taken below,
+        // by the multisig check for high-value claims
         require_claim_processing(&env, &processor)?;
         
         // Check for emergency pause
-        EmergencyPause::validate_not_paused(&env, Some(&symbol_short!("settle_claim")))?;
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "settle_claim")))?;
+
+        // Reject re-entrant settlement of a claim whose payout is still in flight
+        if env.storage().persistent().has(&(SETTLE_LOCK, claim_id)) {
+            return Err(ContractError::InProgress);
+        }
 
         let mut claim: (u64, Address, i128, ClaimStatus, u64) = env
             .storage()
@@ -719,20 +737,51 @@ impl ClaimsContract {
         // Verify risk pool is a trusted contract before invoking
         require_trusted_contract(&env, &risk_pool_contract)?;
 
+        // High-value claims settle only once enough processors have signed
+        if claim.2 > Self::get_msig_threshold(env.clone()) {
+            let action_hash = settlement_action_hash(&env, claim_id, claim.2);
+            let is_authorized = insurance_contracts::authorization::check_multisig_auth(
+                &env,
+                &processor,
+                action_hash.clone(),
+                Role::ClaimProcessor,
+            )?;
+
+            if !is_authorized {
+                // Carry the hash and progress so signers can be gathered for this action
+                let (collected, threshold) = insurance_contracts::authorization::approval_progress(
+                    &env,
+                    action_hash.clone(),
+                    Role::ClaimProcessor,
+                );
+                env.events().publish(
+                    (Symbol::new(&env, "settlement_pending"), claim_id),
+                    (processor, action_hash, collected, threshold),
+                );
+                return Ok(());
+            }
+        } else {
+            processor.require_auth();
+        }
+
+        // I3: Transition to Settled state and take the processing lock before the
+        // external call, so a re-entrant call observes the committed state
+        claim.3 = ClaimStatus::Settled;
+
+        env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+        env.storage().persistent().set(&(SETTLE_LOCK, claim_id), &true);
+
         // Call risk pool to payout the claim amount with asset preference
         env.invoke_contract::<()>(
             &risk_pool_contract,
-            &Symbol::new(&env, "payout_reserved_claim_multi_asset"),
+            &Symbol::new(&env, "payout_reserved_claim_asset"),
             (claim_id, claim.1.clone(), final_payout_asset).into_val(&env),
         );
 
-        // I3: Transition to Settled state
-        claim.3 = ClaimStatus::Settled;
-
-        env.storage().persistent().set(&(CLAIM, claim_id), &claim);
+        env.storage().persistent().remove(&(SETTLE_LOCK, claim_id));
 
         env.events()
-            .publish((Symbol::new(&env, "claim_settled"), claim_id), (claim.1, claim.2));
+            .publish((Symbol::new(&env, "claim_settled"), claim_id), (claim.1.clone(), claim.2));
 
 
         env.events().publish((Symbol::new(&env, "ClaimSettled"), claim_id), (processor, claim.1, claim.2, env.ledger().timestamp()));
@@ -741,6 +790,33 @@ impl ClaimsContract {
     Ok(())
     }
 
+    /// Abandon a partially signed high-value settlement by discarding its collected
+    /// approvals (admin or claim processor)
+    pub fn cancel_settlement(
+        env: Env,
+        processor: Address,
+        claim_id: u64,
+    ) -> Result<(), ContractError> {
+        processor.require_auth();
+        require_claim_processing(&env, &processor)?;
+
+        let claim: (u64, Address, i128, ClaimStatus, u64) = env
+            .storage()
+            .persistent()
+            .get(&(CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+
+        let action_hash = settlement_action_hash(&env, claim_id, claim.2);
+        if insurance_contracts::authorization::clear_approvals(&env, action_hash) == 0 {
+            return Err(ContractError::NotFound);
+        }
+
+        env.events()
+            .publish((Symbol::new(&env, "settlement_cancelled"), claim_id), processor);
+
+        Ok(())
+    }
+
     /// Get claim payout preference
     pub fn get_claim_payout_preference(
         env: Env,
@@ -786,7 +862,7 @@ impl ClaimsContract {
         admin.require_auth();
         require_admin(&env, &admin)?;
         
-        EmergencyPause::activate_emergency_pause(&env, &admin, reason, max_duration_seconds)
+        Ok(EmergencyPause::activate_emergency_pause(&env, &admin, reason, max_duration_seconds)?)
     }
 
     /// Deactivate emergency pause
@@ -798,7 +874,7 @@ impl ClaimsContract {
         admin.require_auth();
         require_admin(&env, &admin)?;
         
-        EmergencyPause::deactivate_emergency_pause(&env, &admin, reason)
+        Ok(EmergencyPause::deactivate_emergency_pause(&env, &admin, reason)?)
     }
 
     /// Pause specific functions
@@ -811,7 +887,7 @@ impl ClaimsContract {
         admin.require_auth();
         require_admin(&env, &admin)?;
         
-        EmergencyPause::pause_functions(&env, &admin, &functions, reason)
+        Ok(EmergencyPause::pause_functions(&env, &admin, &functions, reason)?)
     }
 
     /// Unpause specific functions
@@ -824,12 +900,12 @@ impl ClaimsContract {
         admin.require_auth();
         require_admin(&env, &admin)?;
         
-        EmergencyPause::unpause_functions(&env, &admin, &functions, reason)
+        Ok(EmergencyPause::unpause_functions(&env, &admin, &functions, reason)?)
     }
 
     /// Get emergency pause configuration
     pub fn get_emergency_pause_config(env: Env) -> Result<insurance_contracts::emergency_pause::EmergencyPauseConfig, ContractError> {
-        EmergencyPause::get_pause_config(&env)
+        Ok(EmergencyPause::get_pause_config(&env)?)
     }
 
     /// Get emergency pause history
@@ -847,7 +923,7 @@ impl ClaimsContract {
         require_admin(&env, &admin)?;
         
         // Check for emergency pause
-        EmergencyPause::validate_not_paused(&env, Some(&symbol_short!("grant_processor_role")))?;
+        EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "grant_processor_role")))?;
 
         insurance_contracts::authorization::grant_role(
             &env,
@@ -1106,7 +1182,7 @@ impl ClaimsContract {
     ) -> Result<(), ContractError> {
         delegator.require_auth();
 
-        insurance_contracts::authorization::delegate_role(&env, &delegator, &delegatee, role)?;
+        insurance_contracts::authorization::delegate_role(&env, &delegator, &delegatee, role.clone())?;
 
         env.events()
             .publish((symbol_short!("role_del"), delegatee.clone(), role.clone()), delegator);
@@ -1135,34 +1211,44 @@ impl ClaimsContract {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::{Address as _, Ledger};
-    use soroban_sdk::{Env, Address};
-
-    fn with_contract_env<T>(env: &Env, f: impl FnOnce() -> T) -> T {
-        let cid = env.register_contract(None, ClaimsContract);
-        env.as_contract(&cid, f)
+    use soroban_sdk::testutils::{Address as _, Events};
+    use soroban_sdk::{xdr, Env, Address, TryFromVal, Val};
+
+    /// Last emitted event as `(contract, topics, data)`
+    fn last_event(env: &Env) -> (Address, Vec<Val>, Val) {
+        let event = env.events().all().events().last().unwrap().clone();
+        let xdr::ContractEventBody::V0(body) = event.body;
+        let contract =
+            Address::try_from_val(env, &xdr::ScAddress::Contract(event.contract_id.unwrap()))
+                .unwrap();
+        let mut topics = Vec::new(env);
+        for topic in body.topics.iter() {
+            topics.push_back(Val::try_from_val(env, topic).unwrap());
+        }
+        (contract, topics, Val::try_from_val(env, &body.data).unwrap())
     }
 
     // Test helper functions
-    fn setup_test_env() -> (Env, Address, Address, Address, Address) {
+    fn setup_test_env() -> (Env, ClaimsContractClient<'static>, Address, Address, Address, Address) {
         let env = Env::default();
         env.mock_all_auths();
 
+        let client = ClaimsContractClient::new(&env, &env.register(ClaimsContract, ()));
         let admin = Address::generate(&env);
         let policy_contract = Address::generate(&env);
-        let risk_pool = Address::generate(&env);
+        let risk_pool = env.register(PassiveRiskPool, ());
         let user = Address::generate(&env);
 
-        (env, admin, policy_contract, risk_pool, user)
+        (env, client, admin, policy_contract, risk_pool, user)
     }
 
-    fn initialize_contract(env: &Env, admin: &Address, policy_contract: &Address, risk_pool: &Address) {
-        ClaimsContract::initialize(
-            env.clone(),
-            admin.clone(),
-            policy_contract.clone(),
-            risk_pool.clone(),
-        ).unwrap();
+    fn initialize_contract(
+        client: &ClaimsContractClient,
+        admin: &Address,
+        policy_contract: &Address,
+        risk_pool: &Address,
+    ) {
+        client.initialize(admin, policy_contract, risk_pool);
     }
 
     // ============================================================
@@ -1171,32 +1257,22 @@ mod tests {
 
     #[test]
     fn test_initialize_success() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
+        let (_, client, admin, policy_contract, risk_pool, _) = setup_test_env();
 
-        let result = ClaimsContract::initialize(
-            env.clone(),
-            admin.clone(),
-            policy_contract.clone(),
-            risk_pool.clone(),
-        );
+        let result = client.try_initialize(&admin, &policy_contract, &risk_pool);
 
         assert!(result.is_ok());
     }
 
     #[test]
     fn test_initialize_already_initialized() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
+        let (_, client, admin, policy_contract, risk_pool, _) = setup_test_env();
 
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
-        let result = ClaimsContract::initialize(
-            env.clone(),
-            admin.clone(),
-            policy_contract.clone(),
-            risk_pool.clone(),
-        );
+        let result = client.try_initialize(&admin, &policy_contract, &risk_pool);
 
-        assert_eq!(result, Err(ContractError::AlreadyInitialized));
+        assert_eq!(result, Err(Ok(ContractError::AlreadyInitialized)));
     }
 
     // ============================================================
@@ -1205,25 +1281,20 @@ mod tests {
 
     #[test]
     fn test_submit_claim_success() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (_, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let policy_id = 1;
         let claim_amount = 1000;
 
-        let result = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            policy_id,
-            claim_amount,
-        );
+        let result = client.try_submit_claim(&user, &policy_id, &claim_amount, &None);
 
         assert!(result.is_ok());
-        let claim_id = result.unwrap();
+        let claim_id = result.unwrap().unwrap();
         assert!(claim_id > 0);
 
         // Verify claim stored correctly
-        let claim = ClaimsContract::get_claim(env.clone(), claim_id).unwrap();
+        let claim = client.get_claim(&claim_id);
         assert_eq!(claim.0, policy_id);
         assert_eq!(claim.1, user);
         assert_eq!(claim.2, claim_amount);
@@ -1232,18 +1303,13 @@ mod tests {
 
     #[test]
     fn test_submit_claim_maximum_coverage_amount() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (_, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let policy_id = 1;
         let max_amount = i128::MAX / 2; // Use a large but safe value
 
-        let result = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            policy_id,
-            max_amount,
-        );
+        let result = client.try_submit_claim(&user, &policy_id, &max_amount, &None);
 
         assert!(result.is_ok());
     }
@@ -1254,93 +1320,60 @@ mod tests {
 
     #[test]
     fn test_submit_claim_invalid_amount_zero() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
-
-        let result = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            0,
-        );
+        let (_, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
+
+        let result = client.try_submit_claim(&user, &1, &0, &None);
 
-        assert_eq!(result, Err(ContractError::InvalidInput));
+        assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
     }
 
     #[test]
     fn test_submit_claim_invalid_amount_negative() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
-
-        let result = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            -100,
-        );
+        let (_, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
+
+        let result = client.try_submit_claim(&user, &1, &-100, &None);
 
-        assert_eq!(result, Err(ContractError::InvalidInput));
+        assert_eq!(result, Err(Ok(ContractError::InvalidAmount)));
     }
 
     #[test]
     fn test_submit_claim_duplicate_for_same_policy() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (_, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let policy_id = 1;
 
         // Submit first claim
-        ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            policy_id,
-            1000,
-        ).unwrap();
+        client.submit_claim(&user, &policy_id, &1000, &None);
 
         // Try to submit second claim for same policy
-        let result = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            policy_id,
-            500,
-        );
+        let result = client.try_submit_claim(&user, &policy_id, &500, &None);
 
-        assert_eq!(result, Err(ContractError::AlreadyExists));
+        assert_eq!(result, Err(Ok(ContractError::AlreadyExists)));
     }
 
     #[test]
     fn test_submit_claim_when_paused() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (_, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         // Pause the contract
-        ClaimsContract::pause(env.clone(), admin.clone()).unwrap();
+        client.pause(&admin);
 
-        let result = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        );
+        let result = client.try_submit_claim(&user, &1, &1000, &None);
 
-        assert_eq!(result, Err(ContractError::Paused));
+        assert_eq!(result, Err(Ok(ContractError::Paused)));
     }
 
     #[test]
     fn test_submit_claim_not_initialized() {
-        let env = Env::default();
-        env.mock_all_auths();
+        let (_, client, _, _, _, user) = setup_test_env();
 
-        let user = Address::generate(&env);
-
-        let result = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        );
+        let result = client.try_submit_claim(&user, &1, &1000, &None);
 
-        assert_eq!(result, Err(ContractError::NotInitialized));
+        assert_eq!(result, Err(Ok(ContractError::NotInitialized)));
     }
 
     // ============================================================
@@ -1349,78 +1382,63 @@ mod tests {
 
     #[test]
     fn test_start_review_success() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
-        let result = ClaimsContract::start_review(env.clone(), processor.clone(), claim_id);
+        let result = client.try_start_review(&processor, &claim_id);
         assert!(result.is_ok());
 
         // Verify state changed
-        let claim = ClaimsContract::get_claim(env.clone(), claim_id).unwrap();
+        let claim = client.get_claim(&claim_id);
         assert_eq!(claim.3, ClaimStatus::UnderReview);
     }
 
     #[test]
     fn test_start_review_unauthorized() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let unauthorized_user = Address::generate(&env);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
-        let result = ClaimsContract::start_review(env.clone(), unauthorized_user.clone(), claim_id);
-        assert_eq!(result, Err(ContractError::Unauthorized));
+        let result = client.try_start_review(&unauthorized_user, &claim_id);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
     }
 
     #[test]
     fn test_start_review_invalid_state_transition() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
         // Start review successfully
-        ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+        client.start_review(&processor, &claim_id);
 
         // Try to start review again (invalid: UnderReview -> UnderReview)
-        let result = ClaimsContract::start_review(env.clone(), processor.clone(), claim_id);
-        assert_eq!(result, Err(ContractError::InvalidClaimState));
+        let result = client.try_start_review(&processor, &claim_id);
+        assert_eq!(result, Err(Ok(ContractError::InvalidClaimState)));
     }
 
     #[test]
     fn test_start_review_nonexistent_claim() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
-        let result = ClaimsContract::start_review(env.clone(), processor.clone(), 99999);
-        assert_eq!(result, Err(ContractError::NotFound));
+        let result = client.try_start_review(&processor, &99999);
+        assert_eq!(result, Err(Ok(ContractError::NotFound)));
     }
 
     // ============================================================
@@ -1429,73 +1447,54 @@ mod tests {
 
     #[test]
     fn test_approve_claim_success() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
-        ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+        client.start_review(&processor, &claim_id);
 
-        // Note: This will fail in real test due to cross-contract call to risk_pool
-        // but tests the logic flow
-        let result = ClaimsContract::approve_claim(env.clone(), processor.clone(), claim_id, None);
+        let result = client.try_approve_claim(&processor, &claim_id, &None);
+        assert!(result.is_ok());
 
-        // In unit tests without mocked cross-contract calls, this may panic
-        // In integration tests with proper mocks, verify:
-        // assert!(result.is_ok());
-        // let claim = ClaimsContract::get_claim(env.clone(), claim_id).unwrap();
-        // assert_eq!(claim.3, ClaimStatus::Approved);
+        let claim = client.get_claim(&claim_id);
+        assert_eq!(claim.3, ClaimStatus::Approved);
     }
 
     #[test]
     fn test_approve_claim_invalid_state_submitted() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
         // Try to approve without starting review (Submitted -> Approved)
-        let result = ClaimsContract::approve_claim(env.clone(), processor.clone(), claim_id, None);
-        assert_eq!(result, Err(ContractError::InvalidClaimState));
+        let result = client.try_approve_claim(&processor, &claim_id, &None);
+        assert_eq!(result, Err(Ok(ContractError::InvalidClaimState)));
     }
 
     #[test]
     fn test_approve_claim_unauthorized() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
         let unauthorized_user = Address::generate(&env);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
-        ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+        client.start_review(&processor, &claim_id);
 
-        let result = ClaimsContract::approve_claim(env.clone(), unauthorized_user.clone(), claim_id, None);
-        assert_eq!(result, Err(ContractError::Unauthorized));
+        let result = client.try_approve_claim(&unauthorized_user, &claim_id, &None);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
     }
 
     // ============================================================
@@ -1504,69 +1503,54 @@ mod tests {
 
     #[test]
     fn test_reject_claim_success() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
-        ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+        client.start_review(&processor, &claim_id);
 
-        let result = ClaimsContract::reject_claim(env.clone(), processor.clone(), claim_id);
+        let result = client.try_reject_claim(&processor, &claim_id);
         assert!(result.is_ok());
 
-        let claim = ClaimsContract::get_claim(env.clone(), claim_id).unwrap();
+        let claim = client.get_claim(&claim_id);
         assert_eq!(claim.3, ClaimStatus::Rejected);
     }
 
     #[test]
     fn test_reject_claim_invalid_state_submitted() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
         // Try to reject without starting review
-        let result = ClaimsContract::reject_claim(env.clone(), processor.clone(), claim_id);
-        assert_eq!(result, Err(ContractError::InvalidClaimState));
+        let result = client.try_reject_claim(&processor, &claim_id);
+        assert_eq!(result, Err(Ok(ContractError::InvalidClaimState)));
     }
 
     #[test]
     fn test_reject_claim_unauthorized() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
         let unauthorized_user = Address::generate(&env);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
-        ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+        client.start_review(&processor, &claim_id);
 
-        let result = ClaimsContract::reject_claim(env.clone(), unauthorized_user.clone(), claim_id);
-        assert_eq!(result, Err(ContractError::Unauthorized));
+        let result = client.try_reject_claim(&unauthorized_user, &claim_id);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
     }
 
     // ============================================================
@@ -1575,66 +1559,299 @@ mod tests {
 
     #[test]
     fn test_settle_claim_invalid_state_submitted() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
         // Try to settle without approval
-        let result = ClaimsContract::settle_claim(env.clone(), processor.clone(), claim_id);
-        assert_eq!(result, Err(ContractError::InvalidClaimState));
+        let result = client.try_settle_claim(&processor, &claim_id, &None);
+        assert_eq!(result, Err(Ok(ContractError::InvalidClaimState)));
     }
 
     #[test]
     fn test_settle_claim_invalid_state_under_review() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
-        ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
+        client.start_review(&processor, &claim_id);
 
         // Try to settle while still under review
-        let result = ClaimsContract::settle_claim(env.clone(), processor.clone(), claim_id);
-        assert_eq!(result, Err(ContractError::InvalidClaimState));
+        let result = client.try_settle_claim(&processor, &claim_id, &None);
+        assert_eq!(result, Err(Ok(ContractError::InvalidClaimState)));
     }
 
     #[test]
     fn test_settle_claim_unauthorized() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
         let unauthorized_user = Address::generate(&env);
 
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
 
         // Even if we got it to approved state, unauthorized user can't settle
-        let result = ClaimsContract::settle_claim(env.clone(), unauthorized_user.clone(), claim_id);
-        assert_eq!(result, Err(ContractError::Unauthorized));
+        let result = client.try_settle_claim(&unauthorized_user, &claim_id, &None);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    }
+
+    // ============================================================
+    // RE-ENTRANCY TESTS - Settle Claim
+    // ============================================================
+
+    /// Risk pool mock whose payout hook tries to settle the same claim again.
+    #[contract]
+    pub struct ReentrantRiskPool;
+
+    #[contractimpl]
+    impl ReentrantRiskPool {
+        pub fn set_target(env: Env, claims: Address, processor: Address) {
+            env.storage().instance().set(&symbol_short!("TARGET"), &(claims, processor));
+        }
+
+        pub fn reserve_liquidity(_env: Env, _claim_id: u64, _amount: i128) {}
+
+        pub fn payout_reserved_claim_asset(
+            env: Env,
+            claim_id: u64,
+            _recipient: Address,
+            _asset: shared::types::Asset,
+        ) {
+            let (claims, processor): (Address, Address) =
+                env.storage().instance().get(&symbol_short!("TARGET")).unwrap();
+            let client = ClaimsContractClient::new(&env, &claims);
+
+            let reentry = client.try_settle_claim(&processor, &claim_id, &None);
+            env.storage().instance().set(&symbol_short!("REENTRY"), &reentry.is_err());
+        }
+
+        pub fn reentry_rejected(env: Env) -> bool {
+            env.storage().instance().get(&symbol_short!("REENTRY")).unwrap_or(false)
+        }
+    }
+
+    fn setup_reentrant_pool(env: &Env) -> (ClaimsContractClient<'_>, Address, Address, u64) {
+        let admin = Address::generate(env);
+        let policy_contract = Address::generate(env);
+        let user = Address::generate(env);
+        let processor = Address::generate(env);
+
+        let claims_id = env.register(ClaimsContract, ());
+        let pool_id = env.register(ReentrantRiskPool, ());
+        let client = ClaimsContractClient::new(env, &claims_id);
+
+        client.initialize(&admin, &policy_contract, &pool_id);
+        client.grant_processor_role(&admin, &processor);
+        ReentrantRiskPoolClient::new(env, &pool_id).set_target(&claims_id, &processor);
+
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
+        client.start_review(&processor, &claim_id);
+        client.approve_claim(&processor, &claim_id, &None);
+
+        (client, processor, pool_id, claim_id)
+    }
+
+    #[test]
+    fn test_settle_claim_rejects_reentrant_payout() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, processor, pool_id, claim_id) = setup_reentrant_pool(&env);
+
+        client.settle_claim(&processor, &claim_id, &None);
+
+        let pool = ReentrantRiskPoolClient::new(&env, &pool_id);
+        assert!(pool.reentry_rejected());
+
+        // The single settlement completed and released its lock
+        let claim = client.get_claim(&claim_id);
+        assert_eq!(claim.3, ClaimStatus::Settled);
+        env.as_contract(&client.address, || {
+            assert!(!env.storage().persistent().has(&(SETTLE_LOCK, claim_id)));
+        });
+    }
+
+    #[test]
+    fn test_settle_claim_in_progress() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, processor, _, claim_id) = setup_reentrant_pool(&env);
+
+        // Simulate a payout that is still in flight for this claim
+        env.as_contract(&client.address, || {
+            env.storage().persistent().set(&(SETTLE_LOCK, claim_id), &true);
+        });
+
+        let result = client.try_settle_claim(&processor, &claim_id, &None);
+        assert_eq!(result, Err(Ok(ContractError::InProgress)));
+        assert_eq!(client.get_claim(&claim_id).3, ClaimStatus::Approved);
+    }
+
+    // ============================================================
+    // MULTISIG SETTLEMENT THRESHOLD TESTS
+    // ============================================================
+
+    /// Risk pool mock that accepts reservations and payouts without side effects.
+    #[contract]
+    pub struct PassiveRiskPool;
+
+    #[contractimpl]
+    impl PassiveRiskPool {
+        pub fn reserve_liquidity(_env: Env, _claim_id: u64, _amount: i128) {}
+
+        pub fn payout_reserved_claim_asset(
+            _env: Env,
+            _claim_id: u64,
+            _recipient: Address,
+            _asset: shared::types::Asset,
+        ) {
+        }
+    }
+
+    /// An approved 1,000-unit claim with two processors and a ClaimProcessor
+    /// multisig threshold of 2.
+    fn setup_msig_claim(
+        env: &Env,
+    ) -> (ClaimsContractClient<'_>, Address, Address, Address, u64) {
+        let admin = Address::generate(env);
+        let user = Address::generate(env);
+        let processor = Address::generate(env);
+        let second_processor = Address::generate(env);
+
+        let claims_id = env.register(ClaimsContract, ());
+        let pool_id = env.register(PassiveRiskPool, ());
+        let client = ClaimsContractClient::new(env, &claims_id);
+
+        client.initialize(&admin, &Address::generate(env), &pool_id);
+        client.grant_processor_role(&admin, &processor);
+        client.grant_processor_role(&admin, &second_processor);
+        env.as_contract(&claims_id, || {
+            insurance_contracts::authorization::set_threshold(
+                env,
+                admin.clone(),
+                Role::ClaimProcessor,
+                2,
+            )
+            .unwrap();
+        });
+
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
+        client.start_review(&processor, &claim_id);
+        client.approve_claim(&processor, &claim_id, &None);
+
+        (client, admin, processor, second_processor, claim_id)
+    }
+
+    #[test]
+    fn test_msig_threshold_defaults_and_validates() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, admin, _, _, _) = setup_msig_claim(&env);
+
+        assert_eq!(client.get_msig_threshold(), DEFAULT_MSIG_THRESHOLD);
+
+        let result = client.try_set_msig_threshold(&admin, &0);
+        assert_eq!(result, Err(Ok(ContractError::InvalidInput)));
+
+        let result = client.try_set_msig_threshold(&Address::generate(&env), &500);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_lower_msig_threshold_requires_multisig() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, admin, processor, second_processor, claim_id) = setup_msig_claim(&env);
+
+        // 1,000 is far below the default limit, so it would settle single-sig;
+        // lowering the limit pulls it into multisig
+        client.set_msig_threshold(&admin, &500);
+
+        client.settle_claim(&processor, &claim_id, &None);
+        assert_eq!(client.get_claim(&claim_id).3, ClaimStatus::Approved);
+
+        client.settle_claim(&second_processor, &claim_id, &None);
+        assert_eq!(client.get_claim(&claim_id).3, ClaimStatus::Settled);
+    }
+
+    #[test]
+    fn test_raised_msig_threshold_allows_single_sig() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, admin, processor, _, claim_id) = setup_msig_claim(&env);
+
+        client.set_msig_threshold(&admin, &500);
+        client.settle_claim(&processor, &claim_id, &None);
+        assert_eq!(client.get_claim(&claim_id).3, ClaimStatus::Approved);
+
+        // At or above the claim amount, one processor is enough again
+        client.set_msig_threshold(&admin, &1000);
+        client.settle_claim(&processor, &claim_id, &None);
+        assert_eq!(client.get_claim(&claim_id).3, ClaimStatus::Settled);
+    }
+
+    #[test]
+    fn test_settlement_pending_event_carries_action_hash() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, admin, processor, _, claim_id) = setup_msig_claim(&env);
+        client.set_msig_threshold(&admin, &500);
+
+        client.settle_claim(&processor, &claim_id, &None);
+
+        let (_, topics, data) = last_event(&env);
+        assert_eq!(
+            topics,
+            soroban_sdk::vec![
+                &env,
+                Symbol::new(&env, "settlement_pending").into_val(&env),
+                claim_id.into_val(&env)
+            ]
+        );
+        let (signer, action_hash, collected, threshold): (Address, BytesN<32>, u32, u32) =
+            data.into_val(&env);
+        assert_eq!(signer, processor);
+        assert_eq!(action_hash, settlement_action_hash(&env, claim_id, 1000));
+        assert_eq!((collected, threshold), (1, 2));
+    }
+
+    #[test]
+    fn test_cancel_settlement_clears_approvals() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let (client, admin, processor, second_processor, claim_id) = setup_msig_claim(&env);
+        client.set_msig_threshold(&admin, &500);
+
+        client.settle_claim(&processor, &claim_id, &None);
+        let action_hash = settlement_action_hash(&env, claim_id, 1000);
+        env.as_contract(&client.address, || {
+            let approvals =
+                insurance_contracts::authorization::get_approvals(&env, action_hash.clone());
+            assert_eq!(approvals.len(), 1);
+        });
+
+        client.cancel_settlement(&second_processor, &claim_id);
+        env.as_contract(&client.address, || {
+            let approvals =
+                insurance_contracts::authorization::get_approvals(&env, action_hash.clone());
+            assert!(approvals.is_empty());
+        });
+
+        // Nothing left to cancel, and the next signature starts a fresh round
+        let result = client.try_cancel_settlement(&processor, &claim_id);
+        assert_eq!(result, Err(Ok(ContractError::NotFound)));
+        client.settle_claim(&second_processor, &claim_id, &None);
+        assert_eq!(client.get_claim(&claim_id).3, ClaimStatus::Approved);
     }
 
     // ============================================================
@@ -1643,23 +1860,17 @@ mod tests {
 
     #[test]
     fn test_set_oracle_config_success() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let oracle_contract = Address::generate(&env);
 
-        let result = ClaimsContract::set_oracle_config(
-            env.clone(),
-            admin.clone(),
-            oracle_contract.clone(),
-            true,
-            3,
-        );
+        let result = client.try_set_oracle_config(&admin, &oracle_contract, &true, &3);
 
         assert!(result.is_ok());
 
         // Verify config stored
-        let config = ClaimsContract::get_oracle_config(env.clone()).unwrap();
+        let config = client.get_oracle_config();
         assert_eq!(config.oracle_contract, oracle_contract);
         assert_eq!(config.require_oracle_validation, true);
         assert_eq!(config.min_oracle_submissions, 3);
@@ -1667,30 +1878,24 @@ mod tests {
 
     #[test]
     fn test_set_oracle_config_unauthorized() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let unauthorized_user = Address::generate(&env);
         let oracle_contract = Address::generate(&env);
 
-        let result = ClaimsContract::set_oracle_config(
-            env.clone(),
-            unauthorized_user.clone(),
-            oracle_contract.clone(),
-            true,
-            3,
-        );
+        let result = client.try_set_oracle_config(&unauthorized_user, &oracle_contract, &true, &3);
 
-        assert_eq!(result, Err(ContractError::Unauthorized));
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
     }
 
     #[test]
     fn test_get_oracle_config_not_set() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (_, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
-        let result = ClaimsContract::get_oracle_config(env.clone());
-        assert_eq!(result, Err(ContractError::NotFound));
+        let result = client.try_get_oracle_config();
+        assert_eq!(result, Err(Ok(ContractError::NotFound)));
     }
 
     // ============================================================
@@ -1699,50 +1904,50 @@ mod tests {
 
     #[test]
     fn test_pause_success() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
-        let result = ClaimsContract::pause(env.clone(), admin.clone());
+        let result = client.try_pause(&admin);
         assert!(result.is_ok());
 
-        assert!(is_paused(&env));
+        assert!(env.as_contract(&client.address, || is_paused(&env)));
     }
 
     #[test]
     fn test_pause_unauthorized() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let unauthorized_user = Address::generate(&env);
 
-        let result = ClaimsContract::pause(env.clone(), unauthorized_user.clone());
-        assert_eq!(result, Err(ContractError::Unauthorized));
+        let result = client.try_pause(&unauthorized_user);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
     }
 
     #[test]
     fn test_unpause_success() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
-        ClaimsContract::pause(env.clone(), admin.clone()).unwrap();
+        client.pause(&admin);
 
-        let result = ClaimsContract::unpause(env.clone(), admin.clone());
+        let result = client.try_unpause(&admin);
         assert!(result.is_ok());
 
-        assert!(!is_paused(&env));
+        assert!(!env.as_contract(&client.address, || is_paused(&env)));
     }
 
     #[test]
     fn test_unpause_unauthorized() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
-        ClaimsContract::pause(env.clone(), admin.clone()).unwrap();
+        client.pause(&admin);
 
         let unauthorized_user = Address::generate(&env);
 
-        let result = ClaimsContract::unpause(env.clone(), unauthorized_user.clone());
-        assert_eq!(result, Err(ContractError::Unauthorized));
+        let result = client.try_unpause(&unauthorized_user);
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
     }
 
     // ============================================================
@@ -1751,86 +1956,62 @@ mod tests {
 
     #[test]
     fn test_grant_processor_role_success() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
 
-        let result = ClaimsContract::grant_processor_role(
-            env.clone(),
-            admin.clone(),
-            processor.clone(),
-        );
+        let result = client.try_grant_processor_role(&admin, &processor);
 
         assert!(result.is_ok());
 
-        let role = ClaimsContract::get_user_role(env.clone(), processor.clone());
+        let role = client.get_user_role(&processor);
         assert_eq!(role, Role::ClaimProcessor);
     }
 
     #[test]
     fn test_grant_processor_role_unauthorized() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let unauthorized_user = Address::generate(&env);
         let processor = Address::generate(&env);
 
-        let result = ClaimsContract::grant_processor_role(
-            env.clone(),
-            unauthorized_user.clone(),
-            processor.clone(),
-        );
+        let result = client.try_grant_processor_role(&unauthorized_user, &processor);
 
-        assert_eq!(result, Err(ContractError::Unauthorized));
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
     }
 
     #[test]
     fn test_revoke_processor_role_success() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
 
-        ClaimsContract::grant_processor_role(
-            env.clone(),
-            admin.clone(),
-            processor.clone(),
-        ).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
-        let result = ClaimsContract::revoke_processor_role(
-            env.clone(),
-            admin.clone(),
-            processor.clone(),
-        );
+        let result = client.try_revoke_processor_role(&admin, &processor);
 
         assert!(result.is_ok());
 
-        let role = ClaimsContract::get_user_role(env.clone(), processor.clone());
+        let role = client.get_user_role(&processor);
         assert_eq!(role, Role::User);
     }
 
     #[test]
     fn test_revoke_processor_role_unauthorized() {
-        let (env, admin, policy_contract, risk_pool, _) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, _) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
         let unauthorized_user = Address::generate(&env);
 
-        ClaimsContract::grant_processor_role(
-            env.clone(),
-            admin.clone(),
-            processor.clone(),
-        ).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
-        let result = ClaimsContract::revoke_processor_role(
-            env.clone(),
-            unauthorized_user.clone(),
-            processor.clone(),
-        );
+        let result = client.try_revoke_processor_role(&unauthorized_user, &processor);
 
-        assert_eq!(result, Err(ContractError::Unauthorized));
+        assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
     }
 
     // ============================================================
@@ -1839,64 +2020,49 @@ mod tests {
 
     #[test]
     fn test_full_claim_lifecycle_rejection_path() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (env, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         let processor = Address::generate(&env);
-        ClaimsContract::grant_processor_role(env.clone(), admin.clone(), processor.clone()).unwrap();
+        client.grant_processor_role(&admin, &processor);
 
         // Submit claim
-        let claim_id = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
-
-        let claim = ClaimsContract::get_claim(env.clone(), claim_id).unwrap();
+        let claim_id = client.submit_claim(&user, &1, &1000, &None);
+
+        let claim = client.get_claim(&claim_id);
         assert_eq!(claim.3, ClaimStatus::Submitted);
 
         // Start review
-        ClaimsContract::start_review(env.clone(), processor.clone(), claim_id).unwrap();
-        let claim = ClaimsContract::get_claim(env.clone(), claim_id).unwrap();
+        client.start_review(&processor, &claim_id);
+        let claim = client.get_claim(&claim_id);
         assert_eq!(claim.3, ClaimStatus::UnderReview);
 
         // Reject claim
-        ClaimsContract::reject_claim(env.clone(), processor.clone(), claim_id).unwrap();
-        let claim = ClaimsContract::get_claim(env.clone(), claim_id).unwrap();
+        client.reject_claim(&processor, &claim_id);
+        let claim = client.get_claim(&claim_id);
         assert_eq!(claim.3, ClaimStatus::Rejected);
 
         // Verify can't change state after rejection (terminal state)
-        let result = ClaimsContract::start_review(env.clone(), processor.clone(), claim_id);
-        assert_eq!(result, Err(ContractError::InvalidClaimState));
+        let result = client.try_start_review(&processor, &claim_id);
+        assert_eq!(result, Err(Ok(ContractError::InvalidClaimState)));
     }
 
     #[test]
     fn test_multiple_claims_different_policies() {
-        let (env, admin, policy_contract, risk_pool, user) = setup_test_env();
-        initialize_contract(&env, &admin, &policy_contract, &risk_pool);
+        let (_, client, admin, policy_contract, risk_pool, user) = setup_test_env();
+        initialize_contract(&client, &admin, &policy_contract, &risk_pool);
 
         // Submit claim for policy 1
-        let claim_id_1 = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            1,
-            1000,
-        ).unwrap();
+        let claim_id_1 = client.submit_claim(&user, &1, &1000, &None);
 
         // Submit claim for policy 2
-        let claim_id_2 = ClaimsContract::submit_claim(
-            env.clone(),
-            user.clone(),
-            2,
-            2000,
-        ).unwrap();
+        let claim_id_2 = client.submit_claim(&user, &2, &2000, &None);
 
         // Both should succeed
         assert_ne!(claim_id_1, claim_id_2);
 
-        let claim1 = ClaimsContract::get_claim(env.clone(), claim_id_1).unwrap();
-        let claim2 = ClaimsContract::get_claim(env.clone(), claim_id_2).unwrap();
+        let claim1 = client.get_claim(&claim_id_1);
+        let claim2 = client.get_claim(&claim_id_2);
 
         assert_eq!(claim1.0, 1);
         assert_eq!(claim2.0, 2);
@@ -1,7 +1,7 @@
 use soroban_sdk::{Env, Address, String};
 
 use crate::storage::DataKey;
-use crate::types::{Proposal, VoteChoice};
+use crate::types::{DaoConfig, Proposal, ProposalStatus, VoteChoice};
 use crate::utils::current_time;
 
 // ── Validation Constants ──────────────────────────────────────────────────────
@@ -16,6 +16,9 @@ const MAX_DESCRIPTION_LEN: u32 = 2_048;
 const MIN_VOTING_DURATION_SECS: u64 = 3_600;
 /// Maximum voting duration in seconds (30 days).
 const MAX_VOTING_DURATION_SECS: u64 = 30 * 86_400;
+/// Majority thresholds must sit strictly above 50 % and at most 100 %.
+const MIN_THRESHOLD_PERCENTAGE: u32 = 51;
+const MAX_THRESHOLD_PERCENTAGE: u32 = 100;
 
 // ── Domain Errors ─────────────────────────────────────────────────────────────
 
@@ -40,6 +43,16 @@ pub enum DaoError {
     InvalidVotingDuration = 6,
     /// The contract is paused.
     Paused = 7,
+    /// `initialize` has already been called.
+    AlreadyInitialized = 8,
+    /// The contract has not been initialized.
+    NotInitialized = 9,
+    /// The quorum is zero or the threshold is outside (50 %, 100 %].
+    InvalidConfig = 10,
+    /// The voting period has not ended yet.
+    VotingActive = 11,
+    /// The proposal already has a terminal status.
+    AlreadyFinalized = 12,
 }
 
 // ── Validation Helpers ────────────────────────────────────────────────────────
@@ -74,11 +87,68 @@ fn validate_voting_duration(duration_secs: u64) -> Result<(), DaoError> {
     Ok(())
 }
 
+/// Validate the quorum and majority threshold supplied at initialization.
+fn validate_config(quorum: u32, threshold_percentage: u32) -> Result<(), DaoError> {
+    if quorum == 0 {
+        return Err(DaoError::InvalidConfig);
+    }
+    if !(MIN_THRESHOLD_PERCENTAGE..=MAX_THRESHOLD_PERCENTAGE).contains(&threshold_percentage) {
+        return Err(DaoError::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Decide the terminal status of a proposal from its final tally.
+fn resolve_status(proposal: &Proposal, config: &DaoConfig) -> ProposalStatus {
+    let total_votes = proposal.yes_votes as u64 + proposal.no_votes as u64;
+    if total_votes < config.quorum as u64 {
+        return ProposalStatus::Expired;
+    }
+    if proposal.yes_votes as u64 * 100 >= total_votes * config.threshold_percentage as u64 {
+        ProposalStatus::Passed
+    } else {
+        ProposalStatus::Rejected
+    }
+}
+
 // ── Contract Implementation ───────────────────────────────────────────────────
 
 pub struct DaoContract;
 
 impl DaoContract {
+    // ── Initialization ────────────────────────────────────────────────────
+
+    /// Fix the quorum and majority threshold used by [`DaoContract::finalize`].
+    ///
+    /// # Validation
+    /// - `quorum`: at least one vote
+    /// - `threshold_percentage`: 51–100
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        quorum: u32,
+        threshold_percentage: u32,
+    ) -> Result<(), DaoError> {
+        admin.require_auth();
+
+        if env.storage().instance().has(&DataKey::Config) {
+            return Err(DaoError::AlreadyInitialized);
+        }
+
+        validate_config(quorum, threshold_percentage)?;
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(
+            &DataKey::Config,
+            &DaoConfig {
+                quorum,
+                threshold_percentage,
+            },
+        );
+
+        Ok(())
+    }
+
     // ── Proposal Creation ─────────────────────────────────────────────────
 
     /// Create a new governance proposal.
@@ -125,6 +195,7 @@ impl DaoContract {
             yes_votes: 0,
             no_votes: 0,
             executed: false,
+            status: ProposalStatus::Active,
         };
 
         env.storage()
@@ -190,6 +261,46 @@ impl DaoContract {
         Ok(())
     }
 
+    // ── Finalization ──────────────────────────────────────────────────────
+
+    /// Close a proposal whose voting period has ended and record its outcome.
+    ///
+    /// # Validation
+    /// - The proposal must exist and still be `Active`.
+    /// - The voting window must have ended (`now > end_time`).
+    ///
+    /// # Returns
+    /// The terminal [`ProposalStatus`] written onto the proposal.
+    pub fn finalize(env: Env, proposal_id: u64) -> Result<ProposalStatus, DaoError> {
+        let config: DaoConfig = env
+            .storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(DaoError::NotInitialized)?;
+
+        let mut proposal: Proposal = env
+            .storage()
+            .instance()
+            .get(&DataKey::Proposal(proposal_id))
+            .ok_or(DaoError::ProposalNotFound)?;
+
+        if proposal.status != ProposalStatus::Active {
+            return Err(DaoError::AlreadyFinalized);
+        }
+
+        if current_time(&env) <= proposal.end_time {
+            return Err(DaoError::VotingActive);
+        }
+
+        proposal.status = resolve_status(&proposal, &config);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::Proposal(proposal_id), &proposal);
+
+        Ok(proposal.status)
+    }
+
     // ── Read-only Queries ─────────────────────────────────────────────────
 
     /// Retrieve a proposal by its ID.
@@ -203,6 +314,14 @@ impl DaoContract {
             .ok_or(DaoError::ProposalNotFound)
     }
 
+    /// Retrieve the quorum and threshold set at initialization.
+    pub fn get_config(env: Env) -> Result<DaoConfig, DaoError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(DaoError::NotInitialized)
+    }
+
     /// Return the total number of proposals created so far.
     pub fn proposal_count(env: Env) -> u64 {
         env.storage()
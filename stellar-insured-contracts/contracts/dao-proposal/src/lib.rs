@@ -7,6 +7,9 @@ mod storage;
 mod types;
 mod utils;
 
+#[cfg(test)]
+mod test;
+
 use contract::{DaoContract, DaoError};
 
 #[contract]
@@ -14,6 +17,24 @@ pub struct Dao;
 
 #[contractimpl]
 impl Dao {
+    /// Set the resolution rules for all proposals.
+    ///
+    /// # Arguments
+    /// * `admin`                – Address initializing the contract (must sign)
+    /// * `quorum`               – Minimum number of votes for a proposal to be decided
+    /// * `threshold_percentage` – Yes share required to pass: 51–100
+    ///
+    /// # Returns
+    /// `Ok(())` or a [`DaoError`] on repeat initialization or invalid rules.
+    pub fn initialize(
+        env: soroban_sdk::Env,
+        admin: soroban_sdk::Address,
+        quorum: u32,
+        threshold_percentage: u32,
+    ) -> Result<(), DaoError> {
+        DaoContract::initialize(env, admin, quorum, threshold_percentage)
+    }
+
     /// Create a new governance proposal.
     ///
     /// # Arguments
@@ -52,6 +73,21 @@ impl Dao {
         DaoContract::vote(env, proposal_id, voter, choice)
     }
 
+    /// Resolve a proposal once its voting period has ended.
+    ///
+    /// # Arguments
+    /// * `proposal_id` – ID of the proposal to finalize
+    ///
+    /// # Returns
+    /// The terminal `ProposalStatus` (`Passed`, `Rejected`, or `Expired` when quorum
+    /// was not reached), or a [`DaoError`] describing the failure.
+    pub fn finalize(
+        env: soroban_sdk::Env,
+        proposal_id: u64,
+    ) -> Result<types::ProposalStatus, DaoError> {
+        DaoContract::finalize(env, proposal_id)
+    }
+
     /// Fetch a proposal by its ID.
     ///
     /// # Returns
@@ -63,6 +99,11 @@ impl Dao {
         DaoContract::get_proposal(env, proposal_id)
     }
 
+    /// Return the quorum and threshold set at initialization.
+    pub fn get_config(env: soroban_sdk::Env) -> Result<types::DaoConfig, DaoError> {
+        DaoContract::get_config(env)
+    }
+
     /// Return the total number of proposals created.
     pub fn proposal_count(env: soroban_sdk::Env) -> u64 {
         DaoContract::proposal_count(env)
@@ -2,6 +2,8 @@ use soroban_sdk::{contracttype, Address};
 
 #[contracttype]
 pub enum DataKey {
+    Admin,
+    Config,
     Proposal(u64),
     ProposalCount,
     Vote(u64, Address), // (proposal_id, voter)
@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, String};
+use types::{ProposalStatus, VoteChoice};
+
+const VOTING_DURATION: u64 = 3_600;
+
+/// Quorum of 3 votes with a 60 % majority threshold.
+fn setup(env: &Env) -> DaoClient<'_> {
+    env.mock_all_auths();
+    let contract_id = env.register(Dao, ());
+    let client = DaoClient::new(env, &contract_id);
+    client.initialize(&Address::generate(env), &3, &60);
+    client
+}
+
+fn create(env: &Env, client: &DaoClient) -> u64 {
+    client.create_proposal(
+        &Address::generate(env),
+        &String::from_str(env, "Raise reserve ratio"),
+        &String::from_str(env, "Increase the pool reserve ratio to 30%"),
+        &VOTING_DURATION,
+    )
+}
+
+fn cast(env: &Env, client: &DaoClient, proposal_id: u64, yes: u32, no: u32) {
+    for _ in 0..yes {
+        client.vote(&proposal_id, &Address::generate(env), &VoteChoice::Yes);
+    }
+    for _ in 0..no {
+        client.vote(&proposal_id, &Address::generate(env), &VoteChoice::No);
+    }
+}
+
+fn end_voting(env: &Env) {
+    env.ledger().with_mut(|li| li.timestamp += VOTING_DURATION + 1);
+}
+
+#[test]
+fn test_finalize_passes() {
+    let env = Env::default();
+    let client = setup(&env);
+    let id = create(&env, &client);
+
+    cast(&env, &client, id, 3, 2);
+    assert_eq!(client.try_finalize(&id), Err(Ok(DaoError::VotingActive)));
+
+    end_voting(&env);
+    assert_eq!(client.finalize(&id), ProposalStatus::Passed);
+    assert_eq!(client.get_proposal(&id).status, ProposalStatus::Passed);
+    assert_eq!(client.try_finalize(&id), Err(Ok(DaoError::AlreadyFinalized)));
+}
+
+#[test]
+fn test_finalize_fails_by_threshold() {
+    let env = Env::default();
+    let client = setup(&env);
+    let id = create(&env, &client);
+
+    // 2 of 4 is 50 %, short of the 60 % threshold
+    cast(&env, &client, id, 2, 2);
+    end_voting(&env);
+
+    assert_eq!(client.finalize(&id), ProposalStatus::Rejected);
+}
+
+#[test]
+fn test_finalize_fails_by_quorum() {
+    let env = Env::default();
+    let client = setup(&env);
+    let id = create(&env, &client);
+
+    // Unanimous, but only 2 of the 3 required votes
+    cast(&env, &client, id, 2, 0);
+    end_voting(&env);
+
+    assert_eq!(client.finalize(&id), ProposalStatus::Expired);
+}
+
+#[test]
+fn test_initialize_rejects_invalid_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let client = DaoClient::new(&env, &env.register(Dao, ()));
+    let admin = Address::generate(&env);
+
+    assert_eq!(client.try_initialize(&admin, &0, &60), Err(Ok(DaoError::InvalidConfig)));
+    assert_eq!(client.try_initialize(&admin, &3, &50), Err(Ok(DaoError::InvalidConfig)));
+
+    client.initialize(&admin, &3, &60);
+    assert_eq!(client.try_initialize(&admin, &3, &60), Err(Ok(DaoError::AlreadyInitialized)));
+}
@@ -7,6 +7,29 @@ pub enum VoteChoice {
     No,
 }
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    /// Voting is open or has closed but the proposal is not yet finalized
+    Active,
+    /// Quorum was reached and yes votes met the majority threshold
+    Passed,
+    /// Quorum was reached but yes votes fell short of the threshold
+    Rejected,
+    /// Too few votes were cast to reach quorum
+    Expired,
+}
+
+/// Resolution rules fixed at initialization.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DaoConfig {
+    /// Minimum number of votes (yes + no) for a proposal to be decided
+    pub quorum: u32,
+    /// Share of yes votes, in percent, required for a proposal to pass
+    pub threshold_percentage: u32,
+}
+
 #[contracttype]
 #[derive(Clone)]
 pub struct Proposal {
@@ -19,4 +42,5 @@ pub struct Proposal {
     pub yes_votes: u32,
     pub no_votes: u32,
     pub executed: bool,
+    pub status: ProposalStatus,
 }
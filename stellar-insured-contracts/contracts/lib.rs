@@ -10,33 +10,30 @@ use soroban_sdk::{contracttype, Address, Env, Symbol, String, BytesN};
 /// Import authorization functions like: use insurance_contracts::authorization::*;
 pub mod authorization {
     pub use authorization::{
-        Role, RoleKey, AuthError,
+        Role, RoleKey, AuthError, RoleChange,
         initialize_admin, get_admin, grant_role, revoke_role, get_role,
-        has_role, require_role, require_admin, has_any_role, require_any_role,
+        add_role, remove_role, get_roles, grant_role_until, get_role_expiry,
+        get_all_roles, delegate_role, revoke_delegated_role, get_delegator, migrate_user_role, has_role, require_role, require_admin, has_any_role, require_any_role,
         require_policy_management, require_claim_processing,
         require_risk_pool_management, require_governance_permission,
         register_trusted_contract, unregister_trusted_contract,
         is_trusted_contract, require_trusted_contract,
         verify_and_require_role, verify_and_check_permission,
+        get_role_change_audit, migrate_admin_to_rbac, is_admin_migrated,
+        get_multisig_audit_trail, Approval, set_approval_ttl, get_approval_ttl,
+        approval_progress, is_action_approved, check_multisig_auth, set_threshold,
+        get_threshold, get_approvals, clear_approvals,
     };
 }
 
 /// Re-export emergency pause module
 pub mod emergency_pause {
-    pub use emergency_pause::{
-        EmergencyPauseConfig, EmergencyPauseEvent,
-        initialize, activate_emergency_pause, deactivate_emergency_pause,
-        pause_functions, unpause_functions, validate_not_paused,
-        get_pause_config, get_pause_history,
-    };
+    pub use shared::emergency_pause::{EmergencyPause, EmergencyPauseConfig, EmergencyPauseEvent};
 }
 
 /// Re-export gas optimization module
 pub mod gas_optimization {
-    pub use gas_optimization::{
-        GasOptimizer, PerformanceMonitor,
-        track_operation, optimize_storage_access,
-    };
+    pub use shared::gas_optimization::{GasOptimizer, OptimizedStructures, PerformanceMonitor};
 }
 
 /// Common contract types shared across all insurance contracts
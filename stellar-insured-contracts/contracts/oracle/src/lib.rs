@@ -1,9 +1,12 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, BytesN,
+    Env, IntoVal, Symbol, Vec,
 };
 
+use shared::versioning::{VersionInfo, VersionManager, VersioningError};
+
 // ============================================================================
 // Constants
 // ============================================================================
@@ -15,12 +18,36 @@ const ORACLE_DATA: Symbol = symbol_short!("ORA_DATA");
 const ORACLE_HISTORY: Symbol = symbol_short!("ORA_HIST");
 const SUBMISSIONS: Symbol = symbol_short!("SUBS");
 const THRESHOLDS: Symbol = symbol_short!("THRESH");
+const RELAYERS: Symbol = symbol_short!("RELAYERS");
+const MIN_CLUSTER: Symbol = symbol_short!("MIN_CLST");
+const CHALLENGE_CONFIG: Symbol = symbol_short!("CHAL_CFG");
+const CHALLENGES: Symbol = symbol_short!("CHALLNGE");
+const ORACLE_WEIGHT: Symbol = symbol_short!("ORA_WGHT");
+const BOND_CONFIG: Symbol = symbol_short!("BOND_CFG");
+const ORACLE_BOND: Symbol = symbol_short!("ORA_BOND");
+const RESOLVED_BY: Symbol = symbol_short!("RSLV_BY");
+const MAX_SUBMISSIONS: Symbol = symbol_short!("MAX_SUBS");
+const ORACLES: Symbol = symbol_short!("ORACLES");
+const VALUE_BOUNDS: Symbol = symbol_short!("VAL_BNDS");
 
 // Default thresholds for oracle validation
 const DEFAULT_MIN_SUBMISSIONS: u32 = 3;
 const DEFAULT_MAJORITY_THRESHOLD: u32 = 66; // 66% (2 out of 3)
 const DEFAULT_OUTLIER_DEVIATION: i128 = 15; // 15% deviation threshold
 const DEFAULT_STALENESS_THRESHOLD_SECONDS: u64 = 3600; // 1 hour
+const DEFAULT_MAX_SUBMISSIONS: u32 = 100;
+const DEFAULT_MIN_DISTINCT_ORACLES: u32 = 1;
+
+// Number of finalized consensus points retained per data id
+const MAX_HISTORY_POINTS: u32 = 50;
+
+// Role and reason reported to the slashing contract for a slashed oracle bond
+// (`SlashingRole::OracleProvider`, `SlashingReason::OracleManipulation`)
+const SLASHING_ROLE_ORACLE: u32 = 0;
+const SLASHING_REASON_MANIPULATION: u32 = 0;
+
+/// Interface version reported by `get_version`
+const CONTRACT_VERSION: u32 = 1;
 
 // ============================================================================
 // Error Handling
@@ -41,6 +68,15 @@ pub enum OracleError {
     ConsensusNotReached = 10,
     InvalidThreshold = 11,
     DuplicateSubmission = 12,
+    IncompatibleVersion = 13,
+    UnderChallenge = 14,
+    ChallengeWindowClosed = 15,
+    NotBonded = 16,
+    BondCooldownActive = 17,
+    DataInvalidated = 18,
+    TooManySubmissions = 19,
+    OracleNotWhitelisted = 20,
+    ValueOutOfBounds = 21,
 }
 
 // ============================================================================
@@ -59,6 +95,8 @@ pub struct ValidationThreshold {
     pub outlier_deviation_percent: i128,
     /// Maximum age of oracle data in seconds
     pub staleness_threshold_seconds: u64,
+    /// Minimum number of different oracle addresses among the counted submissions
+    pub min_distinct_oracles: u32,
 }
 
 impl ValidationThreshold {
@@ -68,6 +106,7 @@ impl ValidationThreshold {
             majority_threshold_percent: DEFAULT_MAJORITY_THRESHOLD,
             outlier_deviation_percent: DEFAULT_OUTLIER_DEVIATION,
             staleness_threshold_seconds: DEFAULT_STALENESS_THRESHOLD_SECONDS,
+            min_distinct_oracles: DEFAULT_MIN_DISTINCT_ORACLES,
         }
     }
 }
@@ -82,7 +121,8 @@ pub struct OracleSubmission {
     pub value: i128,
     /// Timestamp of submission
     pub timestamp: u64,
-    /// Optional metadata/signature from oracle
+    /// Feed the value came from; one oracle may report several independent
+    /// feeds, each submitting at most once per data point
     pub source_id: u32,
 }
 
@@ -104,6 +144,77 @@ pub struct OracleData {
     pub included_submissions: u32,
     /// Submissions that were rejected as outliers
     pub rejected_submissions: u32,
+    /// Mean absolute deviation of included submissions from the consensus value;
+    /// lower means tighter agreement
+    pub mean_abs_deviation: i128,
+    /// Agreement score from 0 to 100 (higher = tighter agreement), relative to
+    /// the outlier deviation threshold
+    pub confidence: u32,
+    /// False once an admin has invalidated the value after finalization
+    pub is_valid: bool,
+    /// True when an admin set the value with `admin_force_resolve`, bypassing consensus
+    pub admin_override: bool,
+    /// Reason recorded with an admin override
+    pub override_justification: Option<Symbol>,
+}
+
+/// A finalized consensus value retained in the per-data-id history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsensusPoint {
+    /// Consensus value at finalization
+    pub value: i128,
+    /// Timestamp when the value was finalized
+    pub finalized_at: u64,
+}
+
+/// Challenge window and bond required to dispute a finalized value
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChallengeConfig {
+    /// Seconds after finalization during which a value can be challenged
+    pub window_seconds: u64,
+    /// Token the challenge bond is posted in
+    pub bond_token: Address,
+    /// Bond a challenger must post
+    pub bond_amount: i128,
+}
+
+/// An open challenge against a finalized value
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Challenge {
+    pub challenger: Address,
+    pub bond_amount: i128,
+    /// Hash of off-chain evidence supporting the challenge
+    pub evidence: BytesN<32>,
+    pub challenged_at: u64,
+}
+
+/// Bond an oracle must post before its submissions count towards consensus
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondConfig {
+    /// Token bonds are posted in
+    pub bond_token: Address,
+    /// Minimum bond for an oracle's submissions to count
+    pub min_bond: i128,
+    /// Share of the bond slashed per offence (in basis points)
+    pub slash_bps: u32,
+    /// Seconds between requesting a withdrawal and being able to withdraw
+    pub cooldown_seconds: u64,
+    /// Slashing contract notified of each slashed bond, if any; this contract
+    /// must be registered there as a slasher
+    pub slashing_contract: Option<Address>,
+}
+
+/// Bond posted by an oracle
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleBond {
+    pub amount: i128,
+    /// Set once the oracle asks to withdraw; its submissions stop counting
+    pub unbond_requested_at: Option<u64>,
 }
 
 /// Configuration for the oracle contract
@@ -146,6 +257,76 @@ fn require_admin(env: &Env) -> Result<Address, OracleError> {
     Ok(admin)
 }
 
+fn is_relayer(env: &Env, relayer: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&(RELAYERS, relayer.clone()))
+        .unwrap_or(false)
+}
+
+fn is_whitelisted_oracle(env: &Env, oracle: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&(ORACLES, oracle.clone()))
+        .unwrap_or(false)
+}
+
+/// Reject `value` when it falls outside the admin-configured `(min, max)` bounds
+fn check_value_bounds(env: &Env, value: i128) -> Result<(), OracleError> {
+    if let Some((min_value, max_value)) =
+        env.storage().persistent().get::<_, (i128, i128)>(&VALUE_BOUNDS)
+    {
+        if value < min_value || value > max_value {
+            return Err(OracleError::ValueOutOfBounds);
+        }
+    }
+    Ok(())
+}
+
+fn get_max_submissions(env: &Env) -> u32 {
+    env.storage().persistent().get(&MAX_SUBMISSIONS).unwrap_or(DEFAULT_MAX_SUBMISSIONS)
+}
+
+/// Append a submission from `oracle`, rejecting a second value from the same
+/// `(oracle, source_id)` pair and any value once `max_submissions` is reached
+fn push_submission(
+    submissions: &mut Vec<OracleSubmission>,
+    oracle: Address,
+    value: i128,
+    timestamp: u64,
+    source_id: u32,
+    max_submissions: u32,
+) -> Result<(), OracleError> {
+    if submissions.len() >= max_submissions {
+        return Err(OracleError::TooManySubmissions);
+    }
+
+    for sub in submissions.iter() {
+        if sub.oracle == oracle && sub.source_id == source_id {
+            return Err(OracleError::DuplicateSubmission);
+        }
+    }
+
+    submissions.push_back(OracleSubmission {
+        oracle,
+        value,
+        timestamp,
+        source_id,
+    });
+    Ok(())
+}
+
+/// Number of different addresses in `oracles`
+fn count_distinct(env: &Env, oracles: &Vec<Address>) -> u32 {
+    let mut seen: Vec<Address> = Vec::new(env);
+    for oracle in oracles.iter() {
+        if !seen.contains(&oracle) {
+            seen.push_back(oracle);
+        }
+    }
+    seen.len()
+}
+
 fn is_paused(env: &Env) -> bool {
     env.storage().persistent().get(&PAUSED).unwrap_or(false)
 }
@@ -161,6 +342,26 @@ fn set_thresholds(env: &Env, thresholds: &ValidationThreshold) {
     env.storage().persistent().set(&THRESHOLDS, thresholds);
 }
 
+/// Return an ascending copy of `values`
+fn sort_values(values: &Vec<i128>) -> Vec<i128> {
+    let len = values.len();
+
+    // Insertion sort: in place, and close to linear for nearly sorted
+    // submissions; `max_submissions` bounds the worst case
+    let mut sorted = values.clone();
+    for i in 1..len {
+        let current = sorted.get(i).unwrap();
+        let mut j = i;
+        while j > 0 && sorted.get(j - 1).unwrap() > current {
+            sorted.set(j, sorted.get(j - 1).unwrap());
+            j -= 1;
+        }
+        sorted.set(j, current);
+    }
+
+    sorted
+}
+
 /// Calculate median of values
 fn calculate_median(values: &Vec<i128>) -> i128 {
     if values.is_empty() {
@@ -168,18 +369,7 @@ fn calculate_median(values: &Vec<i128>) -> i128 {
     }
 
     let len = values.len();
-
-    // Simple bubble sort for small datasets (safe in blockchain context)
-    let mut sorted = values.clone();
-    for i in 0..len {
-        for j in 0..(len - i - 1) {
-            if sorted.get(j).unwrap() > sorted.get(j + 1).unwrap() {
-                let temp = sorted.get(j).unwrap();
-                sorted.set(j, sorted.get(j + 1).unwrap());
-                sorted.set(j + 1, temp);
-            }
-        }
-    }
+    let sorted = sort_values(values);
 
     if len % 2 == 1 {
         sorted.get(len / 2).unwrap()
@@ -188,18 +378,114 @@ fn calculate_median(values: &Vec<i128>) -> i128 {
     }
 }
 
-/// Calculate weighted average (simple equal weighting for all submissions)
-fn calculate_weighted_average(values: &Vec<i128>) -> i128 {
+/// Calculate the average of `values` weighted by the matching entry in `weights`
+fn calculate_weighted_average(values: &Vec<i128>, weights: &Vec<u32>) -> i128 {
+    let mut sum: i128 = 0;
+    let mut total_weight: i128 = 0;
+    for i in 0..values.len() {
+        let weight = weights.get(i).unwrap() as i128;
+        sum = sum.saturating_add(values.get(i).unwrap().saturating_mul(weight));
+        total_weight += weight;
+    }
+
+    if total_weight == 0 {
+        return 0;
+    }
+
+    sum / total_weight
+}
+
+/// Weighted median of `values`; matches `calculate_median` when all weights are
+/// equal. Zero-weight values are ignored; `None` if every weight is zero.
+fn calculate_weighted_median(values: &Vec<i128>, weights: &Vec<u32>) -> Option<i128> {
+    let len = values.len();
+
+    // Insertion sort on the (value, weight) pairs, as in `sort_values`
+    let mut sorted_values = values.clone();
+    let mut sorted_weights = weights.clone();
+    for i in 1..len {
+        let value = sorted_values.get(i).unwrap();
+        let weight = sorted_weights.get(i).unwrap();
+        let mut j = i;
+        while j > 0 && sorted_values.get(j - 1).unwrap() > value {
+            sorted_values.set(j, sorted_values.get(j - 1).unwrap());
+            sorted_weights.set(j, sorted_weights.get(j - 1).unwrap());
+            j -= 1;
+        }
+        sorted_values.set(j, value);
+        sorted_weights.set(j, weight);
+    }
+
+    let mut total: u64 = 0;
+    for weight in sorted_weights.iter() {
+        total += weight as u64;
+    }
+    if total == 0 {
+        return None;
+    }
+
+    let mut cumulative: u64 = 0;
+    for i in 0..len {
+        let weight = sorted_weights.get(i).unwrap() as u64;
+        if weight == 0 {
+            continue;
+        }
+        cumulative += weight;
+        let value = sorted_values.get(i).unwrap();
+
+        if cumulative * 2 > total {
+            return Some(value);
+        }
+        if cumulative * 2 == total {
+            // Exactly half the weight sits at or below this value: average with the next one
+            for j in (i + 1)..len {
+                if sorted_weights.get(j).unwrap() > 0 {
+                    return Some((value + sorted_values.get(j).unwrap()) / 2);
+                }
+            }
+            return Some(value);
+        }
+    }
+
+    None
+}
+
+fn get_oracle_weight(env: &Env, oracle: &Address) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&(ORACLE_WEIGHT, oracle.clone()))
+        .unwrap_or(1)
+}
+
+/// Mean absolute deviation of `values` from `center`
+fn calculate_mean_abs_deviation(values: &Vec<i128>, center: i128) -> i128 {
     if values.is_empty() {
         return 0;
     }
 
-    let mut sum: i128 = 0;
-    for i in 0..values.len() {
-        sum = sum.saturating_add(values.get(i).unwrap());
+    let mut total: i128 = 0;
+    for value in values.iter() {
+        total = total.saturating_add(value.saturating_sub(center).saturating_abs());
+    }
+
+    total / (values.len() as i128)
+}
+
+/// Score agreement from 0 to 100: 100 when every included value equals the
+/// consensus, falling to 0 as the relative deviation reaches the outlier threshold
+fn calculate_confidence(mean_abs_deviation: i128, consensus_value: i128, deviation_percent: i128) -> u32 {
+    if mean_abs_deviation == 0 {
+        return 100;
+    }
+    if consensus_value == 0 || deviation_percent <= 0 {
+        return 0;
     }
 
-    sum / (values.len() as i128)
+    let deviation_bps = mean_abs_deviation.saturating_mul(10_000) / consensus_value.saturating_abs();
+    let threshold_bps = deviation_percent.saturating_mul(100);
+    let penalty = (deviation_bps.saturating_mul(100) / threshold_bps).min(100);
+
+    (100 - penalty) as u32
 }
 
 /// Detect outliers using interquartile range (IQR) method
@@ -238,7 +524,138 @@ fn detect_outliers(values: &Vec<i128>, deviation_percent: i128) -> Vec<bool> {
     outlier_flags
 }
 
+/// Find the largest group of values lying within `deviation_percent` of the
+/// group's lowest value. Returns `None` when two different groups tie for
+/// largest, since neither can claim agreement over the other.
+fn find_largest_cluster(values: &Vec<i128>, deviation_percent: i128) -> Option<Vec<i128>> {
+    let sorted = sort_values(values);
+    let len = sorted.len();
+
+    let mut best_start = 0u32;
+    let mut best_size = 0u32;
+    let mut tied = false;
+
+    for start in 0..len {
+        let low = sorted.get(start).unwrap();
+        let basis = if low > 0 { low } else { 1 };
+        let max_deviation = (basis.abs() * deviation_percent) / 100;
+
+        let mut end = start;
+        while end + 1 < len && sorted.get(end + 1).unwrap() - low <= max_deviation {
+            end += 1;
+        }
+
+        let size = end - start + 1;
+        if size > best_size {
+            best_start = start;
+            best_size = size;
+            tied = false;
+        } else if size == best_size && start >= best_start + best_size {
+            // A disjoint group of the same size
+            tied = true;
+        }
+    }
+
+    if tied || best_size == 0 {
+        return None;
+    }
+
+    Some(sorted.slice(best_start..best_start + best_size))
+}
+
+/// Load the retained consensus history for a data id
+fn get_history(env: &Env, data_id: u64) -> Vec<ConsensusPoint> {
+    env.storage()
+        .persistent()
+        .get(&(ORACLE_HISTORY, data_id))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Append a finalized value to the history, evicting the oldest point when full
+fn record_history(env: &Env, data_id: u64, value: i128, finalized_at: u64) {
+    let mut history = get_history(env, data_id);
+    if history.len() >= MAX_HISTORY_POINTS {
+        history.pop_front();
+    }
+    history.push_back(ConsensusPoint { value, finalized_at });
+    env.storage().persistent().set(&(ORACLE_HISTORY, data_id), &history);
+}
+
+/// Drop the history point recorded for a finalization that was overturned
+fn remove_history_point(env: &Env, data_id: u64, finalized_at: u64) {
+    let mut history = get_history(env, data_id);
+    if let Some(last) = history.last() {
+        if last.finalized_at == finalized_at {
+            history.pop_back();
+            env.storage().persistent().set(&(ORACLE_HISTORY, data_id), &history);
+        }
+    }
+}
+
+fn is_challenged(env: &Env, data_id: u64) -> bool {
+    env.storage().persistent().has(&(CHALLENGES, data_id))
+}
+
 /// Check if oracle data is stale
+fn get_bond_config(env: &Env) -> Option<BondConfig> {
+    env.storage().persistent().get(&BOND_CONFIG)
+}
+
+/// Whether `oracle`'s submissions count under the bond requirement
+fn is_bonded(env: &Env, config: &BondConfig, oracle: &Address) -> bool {
+    match env.storage().persistent().get::<_, OracleBond>(&(ORACLE_BOND, oracle.clone())) {
+        Some(bond) => bond.unbond_requested_at.is_none() && bond.amount >= config.min_bond,
+        None => false,
+    }
+}
+
+/// Slash `slash_bps` of an oracle's bond to the admin and report it to the
+/// configured slashing contract. Reporting is best effort: a failure there
+/// never blocks resolution.
+fn slash_oracle_bond(
+    env: &Env,
+    config: &BondConfig,
+    admin: &Address,
+    oracle: &Address,
+    data_id: u64,
+) {
+    let key = (ORACLE_BOND, oracle.clone());
+    let mut bond: OracleBond = match env.storage().persistent().get(&key) {
+        Some(bond) => bond,
+        None => return,
+    };
+
+    let penalty = bond.amount * config.slash_bps as i128 / 10_000;
+    if penalty <= 0 {
+        return;
+    }
+
+    bond.amount -= penalty;
+    env.storage().persistent().set(&key, &bond);
+    token::Client::new(env, &config.bond_token).transfer(
+        &env.current_contract_address(),
+        admin,
+        &penalty,
+    );
+
+    if let Some(slashing_contract) = &config.slashing_contract {
+        let _ = env.try_invoke_contract::<u64, soroban_sdk::Error>(
+            slashing_contract,
+            &Symbol::new(env, "slash_funds"),
+            vec![
+                env,
+                env.current_contract_address().into_val(env),
+                oracle.into_val(env),
+                SLASHING_ROLE_ORACLE.into_val(env),
+                SLASHING_REASON_MANIPULATION.into_val(env),
+                penalty.into_val(env),
+            ],
+        );
+    }
+
+    env.events().publish((symbol_short!("bond_slsh"), oracle.clone()), (data_id, penalty));
+}
+
 fn is_data_stale(timestamp: u64, current_time: u64, staleness_threshold: u64) -> bool {
     if current_time < timestamp {
         return true; // Future timestamp is invalid
@@ -266,9 +683,25 @@ impl OracleContract {
         let default_thresholds = ValidationThreshold::default();
         set_thresholds(&env, &default_thresholds);
 
+        VersionManager::initialize(&env, CONTRACT_VERSION)
+            .map_err(|_| OracleError::AlreadyInitialized)?;
+
         Ok(())
     }
 
+    /// Get the contract version information
+    pub fn get_version(env: Env) -> Result<VersionInfo, OracleError> {
+        VersionManager::version_info(&env).map_err(|_| OracleError::NotInitialized)
+    }
+
+    /// Fail unless the contract version is at least `min_version`
+    pub fn require_min_version(env: Env, min_version: u32) -> Result<(), OracleError> {
+        VersionManager::require_min_version(&env, min_version).map_err(|e| match e {
+            VersioningError::NotInitialized => OracleError::NotInitialized,
+            _ => OracleError::IncompatibleVersion,
+        })
+    }
+
     /// Pause or unpause the contract
     pub fn set_paused(env: Env, paused: bool) -> Result<(), OracleError> {
         let _admin = require_admin(&env)?;
@@ -295,24 +728,160 @@ impl OracleContract {
             majority_threshold_percent,
             outlier_deviation_percent,
             staleness_threshold_seconds,
+            min_distinct_oracles: get_thresholds(&env).min_distinct_oracles,
         };
 
         set_thresholds(&env, &thresholds);
         Ok(())
     }
 
+    /// Update only the minimum number of submissions required for consensus
+    pub fn set_min_submissions(env: Env, min_submissions: u32) -> Result<(), OracleError> {
+        let _admin = require_admin(&env)?;
+
+        if min_submissions == 0 {
+            return Err(OracleError::InvalidThreshold);
+        }
+
+        let mut thresholds = get_thresholds(&env);
+        thresholds.min_submissions = min_submissions;
+        set_thresholds(&env, &thresholds);
+        Ok(())
+    }
+
+    /// Update only the minimum number of distinct oracles required for consensus.
+    /// One oracle may submit once per feed, so `min_submissions` alone does not
+    /// guarantee provider diversity.
+    pub fn set_min_distinct_oracles(
+        env: Env,
+        min_distinct_oracles: u32,
+    ) -> Result<(), OracleError> {
+        let _admin = require_admin(&env)?;
+
+        if min_distinct_oracles == 0 {
+            return Err(OracleError::InvalidThreshold);
+        }
+
+        let mut thresholds = get_thresholds(&env);
+        thresholds.min_distinct_oracles = min_distinct_oracles;
+        set_thresholds(&env, &thresholds);
+        Ok(())
+    }
+
+    /// Update only the majority threshold percentage (0-100)
+    pub fn set_majority_threshold(
+        env: Env,
+        majority_threshold_percent: u32,
+    ) -> Result<(), OracleError> {
+        let _admin = require_admin(&env)?;
+
+        if majority_threshold_percent > 100 {
+            return Err(OracleError::InvalidThreshold);
+        }
+
+        let mut thresholds = get_thresholds(&env);
+        thresholds.majority_threshold_percent = majority_threshold_percent;
+        set_thresholds(&env, &thresholds);
+        Ok(())
+    }
+
+    /// Update only the outlier deviation percentage
+    pub fn set_outlier_deviation(
+        env: Env,
+        outlier_deviation_percent: i128,
+    ) -> Result<(), OracleError> {
+        let _admin = require_admin(&env)?;
+
+        if outlier_deviation_percent < 0 {
+            return Err(OracleError::InvalidThreshold);
+        }
+
+        let mut thresholds = get_thresholds(&env);
+        thresholds.outlier_deviation_percent = outlier_deviation_percent;
+        set_thresholds(&env, &thresholds);
+        Ok(())
+    }
+
+    /// Update only the staleness threshold in seconds
+    pub fn set_staleness(env: Env, staleness_threshold_seconds: u64) -> Result<(), OracleError> {
+        let _admin = require_admin(&env)?;
+
+        if staleness_threshold_seconds == 0 {
+            return Err(OracleError::InvalidThreshold);
+        }
+
+        let mut thresholds = get_thresholds(&env);
+        thresholds.staleness_threshold_seconds = staleness_threshold_seconds;
+        set_thresholds(&env, &thresholds);
+        Ok(())
+    }
+
+    /// Require the consensus value to come from a cluster of at least
+    /// `min_cluster` submissions within the outlier deviation of each other.
+    /// Zero disables the cluster requirement.
+    pub fn set_outlier_min_cluster(env: Env, min_cluster: u32) -> Result<(), OracleError> {
+        let _admin = require_admin(&env)?;
+
+        env.storage().persistent().set(&MIN_CLUSTER, &min_cluster);
+        Ok(())
+    }
+
+    /// Set the consensus weight of an oracle (default 1, 0 ignores its value)
+    pub fn set_oracle_weight(env: Env, oracle: Address, weight: u32) -> Result<(), OracleError> {
+        let _admin = require_admin(&env)?;
+
+        env.storage().persistent().set(&(ORACLE_WEIGHT, oracle), &weight);
+        Ok(())
+    }
+
+    /// Get the consensus weight of an oracle
+    pub fn get_oracle_weight(env: Env, oracle: Address) -> u32 {
+        get_oracle_weight(&env, &oracle)
+    }
+
+    /// Cap the number of pending submissions per data point; further
+    /// submissions are rejected with `TooManySubmissions`. Keep it at or above
+    /// `min_submissions` or data points can never resolve.
+    pub fn set_max_submissions(env: Env, max_submissions: u32) -> Result<(), OracleError> {
+        let _admin = require_admin(&env)?;
+
+        if max_submissions == 0 {
+            return Err(OracleError::InvalidThreshold);
+        }
+
+        env.storage().persistent().set(&MAX_SUBMISSIONS, &max_submissions);
+        Ok(())
+    }
+
+    /// Get the cap on pending submissions per data point
+    pub fn get_max_submissions(env: Env) -> u32 {
+        get_max_submissions(&env)
+    }
+
+    /// Get the minimum agreeing cluster size (0 when disabled)
+    pub fn get_outlier_min_cluster(env: Env) -> u32 {
+        env.storage().persistent().get(&MIN_CLUSTER).unwrap_or(0)
+    }
+
     /// Get current validation thresholds
     pub fn get_thresholds(env: Env) -> Result<ValidationThreshold, OracleError> {
         Ok(get_thresholds(&env))
     }
 
-    /// Submit oracle data for a specific data point
+    /// Submit oracle data for a specific data point from the feed `source_id`
     /// Returns true if consensus is reached immediately
-    pub fn submit_oracle_data(env: Env, data_id: u64, value: i128) -> Result<bool, OracleError> {
+    pub fn submit_oracle_data(
+        env: Env,
+        data_id: u64,
+        value: i128,
+        source_id: u32,
+    ) -> Result<bool, OracleError> {
         if is_paused(&env) {
             return Err(OracleError::Paused);
         }
 
+        check_value_bounds(&env, value)?;
+
         // See note in `require_admin` about SDK API differences.
         // For now, use current contract address as the submitting oracle.
         let oracle = env.current_contract_address();
@@ -326,23 +895,15 @@ impl OracleContract {
             .get(&submissions_key)
             .unwrap_or_else(|| Vec::new(&env));
 
-        // Check for duplicate submission from same oracle
-        for i in 0..submissions.len() {
-            let sub = submissions.get(i).unwrap();
-            if sub.oracle == oracle {
-                return Err(OracleError::DuplicateSubmission);
-            }
-        }
-
-        // Add new submission
-        let submission = OracleSubmission {
-            oracle: oracle.clone(),
+        // Add new submission, rejecting duplicates from the same oracle and feed
+        push_submission(
+            &mut submissions,
+            oracle,
             value,
-            timestamp: current_time,
-            source_id: 0,
-        };
-
-        submissions.push_back(submission);
+            current_time,
+            source_id,
+            get_max_submissions(&env),
+        )?;
         env.storage().persistent().set(&submissions_key, &submissions);
 
         // Try to reach consensus
@@ -353,52 +914,592 @@ impl OracleContract {
         }
     }
 
-    /// Attempt to resolve oracle data with consensus validation
-    pub fn resolve_oracle_data(env: Env, data_id: u64) -> Result<OracleData, OracleError> {
-        OracleContract.try_resolve_oracle_data(&env, data_id)
-    }
+    /// Authorize a relayer to submit values on behalf of multiple oracles
+    pub fn add_relayer(env: Env, caller: Address, relayer: Address) -> Result<(), OracleError> {
+        let admin = require_admin(&env)?;
+        caller.require_auth();
+        if caller != admin {
+            return Err(OracleError::Unauthorized);
+        }
 
-    /// Internal oracle resolution with validation
-    fn try_resolve_oracle_data(&self, env: &Env, data_id: u64) -> Result<OracleData, OracleError> {
-        let thresholds = get_thresholds(env);
-        let current_time = env.ledger().timestamp();
+        env.storage().persistent().set(&(RELAYERS, relayer), &true);
+        Ok(())
+    }
 
-        let submissions_key = (SUBMISSIONS, data_id);
-        let submissions: Vec<OracleSubmission> =
-            env.storage().persistent().get(&submissions_key).ok_or(OracleError::NotFound)?;
+    /// Revoke a relayer's authorization
+    pub fn remove_relayer(env: Env, caller: Address, relayer: Address) -> Result<(), OracleError> {
+        let admin = require_admin(&env)?;
+        caller.require_auth();
+        if caller != admin {
+            return Err(OracleError::Unauthorized);
+        }
 
-        let submission_count = submissions.len() as u32;
+        env.storage().persistent().remove(&(RELAYERS, relayer));
+        Ok(())
+    }
 
-        // Check minimum submissions
-        if submission_count < thresholds.min_submissions {
-            return Err(OracleError::InsufficientSubmissions);
+    /// Whitelist an oracle whose values relayers may submit
+    pub fn add_oracle(env: Env, caller: Address, oracle: Address) -> Result<(), OracleError> {
+        let admin = require_admin(&env)?;
+        caller.require_auth();
+        if caller != admin {
+            return Err(OracleError::Unauthorized);
         }
 
-        // Extract values and check for staleness
-        let mut values: Vec<i128> = Vec::new(&env);
-        for i in 0..submissions.len() {
-            let sub = submissions.get(i).unwrap();
+        env.storage().persistent().set(&(ORACLES, oracle), &true);
+        Ok(())
+    }
 
-            // Check staleness
-            if is_data_stale(sub.timestamp, current_time, thresholds.staleness_threshold_seconds) {
+    /// Remove an oracle from the whitelist
+    pub fn remove_oracle(env: Env, caller: Address, oracle: Address) -> Result<(), OracleError> {
+        let admin = require_admin(&env)?;
+        caller.require_auth();
+        if caller != admin {
+            return Err(OracleError::Unauthorized);
+        }
+
+        env.storage().persistent().remove(&(ORACLES, oracle));
+        Ok(())
+    }
+
+    /// Whether `oracle` is on the whitelist
+    pub fn is_oracle(env: Env, oracle: Address) -> bool {
+        is_whitelisted_oracle(&env, &oracle)
+    }
+
+    /// Reject submitted values outside `[min_value, max_value]`
+    pub fn set_value_bounds(
+        env: Env,
+        caller: Address,
+        min_value: i128,
+        max_value: i128,
+    ) -> Result<(), OracleError> {
+        let admin = require_admin(&env)?;
+        caller.require_auth();
+        if caller != admin {
+            return Err(OracleError::Unauthorized);
+        }
+
+        if min_value > max_value {
+            return Err(OracleError::InvalidInput);
+        }
+
+        env.storage().persistent().set(&VALUE_BOUNDS, &(min_value, max_value));
+        Ok(())
+    }
+
+    /// Get the configured `(min_value, max_value)` bounds, if any
+    pub fn get_value_bounds(env: Env) -> Option<(i128, i128)> {
+        env.storage().persistent().get(&VALUE_BOUNDS)
+    }
+
+    /// Record one `(oracle, value, source_id)` entry per oracle feed for a data
+    /// point, then resolve it in the same call. Each oracle must be
+    /// whitelisted, each value must be within the configured bounds, and each
+    /// entry goes through the same duplicate check as `submit_oracle_data`;
+    /// any failure (including falling short of the minimum submissions)
+    /// reverts the whole batch.
+    pub fn submit_many_and_resolve(
+        env: Env,
+        relayer: Address,
+        data_id: u64,
+        submissions: Vec<(Address, i128, u32)>,
+    ) -> Result<OracleData, OracleError> {
+        if is_paused(&env) {
+            return Err(OracleError::Paused);
+        }
+
+        relayer.require_auth();
+        if !is_relayer(&env, &relayer) {
+            return Err(OracleError::Unauthorized);
+        }
+
+        if submissions.is_empty() {
+            return Err(OracleError::InvalidInput);
+        }
+
+        let current_time = env.ledger().timestamp();
+        let submissions_key = (SUBMISSIONS, data_id);
+
+        let mut pending: Vec<OracleSubmission> = env
+            .storage()
+            .persistent()
+            .get(&submissions_key)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let max_submissions = get_max_submissions(&env);
+        for (oracle, value, source_id) in submissions.iter() {
+            if !is_whitelisted_oracle(&env, &oracle) {
+                return Err(OracleError::OracleNotWhitelisted);
+            }
+            check_value_bounds(&env, value)?;
+            push_submission(&mut pending, oracle, value, current_time, source_id, max_submissions)?;
+        }
+        env.storage().persistent().set(&submissions_key, &pending);
+
+        OracleContract.try_resolve_oracle_data(&env, data_id)
+    }
+
+    /// Configure the challenge window and the bond a challenger must post
+    pub fn set_challenge_config(
+        env: Env,
+        caller: Address,
+        window_seconds: u64,
+        bond_token: Address,
+        bond_amount: i128,
+    ) -> Result<(), OracleError> {
+        let admin = require_admin(&env)?;
+        caller.require_auth();
+        if caller != admin {
+            return Err(OracleError::Unauthorized);
+        }
+
+        if window_seconds == 0 || bond_amount <= 0 {
+            return Err(OracleError::InvalidInput);
+        }
+
+        let config = ChallengeConfig { window_seconds, bond_token, bond_amount };
+        env.storage().persistent().set(&CHALLENGE_CONFIG, &config);
+        Ok(())
+    }
+
+    /// Challenge a finalized value within the challenge window. The challenger
+    /// posts the configured bond and the value is frozen until resolved.
+    pub fn challenge_resolution(
+        env: Env,
+        challenger: Address,
+        data_id: u64,
+        evidence: BytesN<32>,
+    ) -> Result<(), OracleError> {
+        challenger.require_auth();
+
+        let config: ChallengeConfig = env
+            .storage()
+            .persistent()
+            .get(&CHALLENGE_CONFIG)
+            .ok_or(OracleError::NotInitialized)?;
+
+        if is_challenged(&env, data_id) {
+            return Err(OracleError::UnderChallenge);
+        }
+
+        let data: OracleData = env
+            .storage()
+            .persistent()
+            .get(&(ORACLE_DATA, data_id))
+            .ok_or(OracleError::NotFound)?;
+
+        let now = env.ledger().timestamp();
+        if now > data.finalized_at.saturating_add(config.window_seconds) {
+            return Err(OracleError::ChallengeWindowClosed);
+        }
+
+        token::Client::new(&env, &config.bond_token).transfer(
+            &challenger,
+            &env.current_contract_address(),
+            &config.bond_amount,
+        );
+
+        let challenge = Challenge {
+            challenger: challenger.clone(),
+            bond_amount: config.bond_amount,
+            evidence,
+            challenged_at: now,
+        };
+        env.storage().persistent().set(&(CHALLENGES, data_id), &challenge);
+
+        env.events().publish(
+            (symbol_short!("challenge"), data_id),
+            (challenger, config.bond_amount),
+        );
+
+        Ok(())
+    }
+
+    /// Settle an open challenge (admin only). When `uphold` is true the
+    /// challenger was right: the value is discarded so the data point can be
+    /// re-collected and the bond is returned. Otherwise the value is confirmed
+    /// and the bond is slashed to the admin.
+    pub fn resolve_challenge(
+        env: Env,
+        caller: Address,
+        data_id: u64,
+        uphold: bool,
+    ) -> Result<(), OracleError> {
+        let admin = require_admin(&env)?;
+        caller.require_auth();
+        if caller != admin {
+            return Err(OracleError::Unauthorized);
+        }
+
+        let challenge: Challenge = env
+            .storage()
+            .persistent()
+            .get(&(CHALLENGES, data_id))
+            .ok_or(OracleError::NotFound)?;
+        let config: ChallengeConfig = env
+            .storage()
+            .persistent()
+            .get(&CHALLENGE_CONFIG)
+            .ok_or(OracleError::NotInitialized)?;
+
+        env.storage().persistent().remove(&(CHALLENGES, data_id));
+
+        let bond_client = token::Client::new(&env, &config.bond_token);
+        if uphold {
+            if let Some(data) =
+                env.storage().persistent().get::<_, OracleData>(&(ORACLE_DATA, data_id))
+            {
+                remove_history_point(&env, data_id, data.finalized_at);
+            }
+            env.storage().persistent().remove(&(ORACLE_DATA, data_id));
+
+            // The oracles that produced the overturned value lose part of their bond
+            let resolved_by: Option<Vec<Address>> =
+                env.storage().persistent().get(&(RESOLVED_BY, data_id));
+            if let (Some(bond_config), Some(oracles)) = (get_bond_config(&env), resolved_by) {
+                for oracle in oracles.iter() {
+                    slash_oracle_bond(&env, &bond_config, &admin, &oracle, data_id);
+                }
+            }
+            env.storage().persistent().remove(&(RESOLVED_BY, data_id));
+            bond_client.transfer(
+                &env.current_contract_address(),
+                &challenge.challenger,
+                &challenge.bond_amount,
+            );
+        } else {
+            bond_client.transfer(&env.current_contract_address(), &admin, &challenge.bond_amount);
+        }
+
+        env.events().publish(
+            (symbol_short!("chal_res"), data_id),
+            (challenge.challenger, uphold),
+        );
+
+        Ok(())
+    }
+
+    /// Get the open challenge for a data point, if any
+    pub fn get_challenge(env: Env, data_id: u64) -> Option<Challenge> {
+        env.storage().persistent().get(&(CHALLENGES, data_id))
+    }
+
+    /// Require oracles to post a bond before their submissions count. Oracles
+    /// whose submissions are rejected as outliers, or whose value is
+    /// overturned by an upheld challenge, lose `slash_bps` of their bond.
+    pub fn set_bond_config(
+        env: Env,
+        caller: Address,
+        bond_token: Address,
+        min_bond: i128,
+        slash_bps: u32,
+        cooldown_seconds: u64,
+        slashing_contract: Option<Address>,
+    ) -> Result<(), OracleError> {
+        let admin = require_admin(&env)?;
+        caller.require_auth();
+        if caller != admin {
+            return Err(OracleError::Unauthorized);
+        }
+
+        if min_bond <= 0 || slash_bps > 10_000 {
+            return Err(OracleError::InvalidInput);
+        }
+
+        let config =
+            BondConfig { bond_token, min_bond, slash_bps, cooldown_seconds, slashing_contract };
+        env.storage().persistent().set(&BOND_CONFIG, &config);
+        Ok(())
+    }
+
+    /// Get the bond configuration, if bonding is enabled
+    pub fn get_bond_config(env: Env) -> Option<BondConfig> {
+        get_bond_config(&env)
+    }
+
+    /// Post (or top up) an oracle's bond. Staking cancels a pending withdrawal.
+    pub fn stake_oracle_bond(env: Env, oracle: Address, amount: i128) -> Result<(), OracleError> {
+        oracle.require_auth();
+
+        let config = get_bond_config(&env).ok_or(OracleError::NotInitialized)?;
+        if amount <= 0 {
+            return Err(OracleError::InvalidInput);
+        }
+
+        token::Client::new(&env, &config.bond_token).transfer(
+            &oracle,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        let key = (ORACLE_BOND, oracle.clone());
+        let mut bond: OracleBond = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(OracleBond { amount: 0, unbond_requested_at: None });
+        bond.amount += amount;
+        bond.unbond_requested_at = None;
+        env.storage().persistent().set(&key, &bond);
+
+        env.events().publish((symbol_short!("bond_stk"), oracle), (amount, bond.amount));
+        Ok(())
+    }
+
+    /// Start the withdrawal cooldown. The oracle's submissions stop counting
+    /// immediately so it cannot misreport and leave before being slashed.
+    pub fn request_bond_withdrawal(env: Env, oracle: Address) -> Result<(), OracleError> {
+        oracle.require_auth();
+
+        let key = (ORACLE_BOND, oracle);
+        let mut bond: OracleBond =
+            env.storage().persistent().get(&key).ok_or(OracleError::NotBonded)?;
+        if bond.unbond_requested_at.is_none() {
+            bond.unbond_requested_at = Some(env.ledger().timestamp());
+            env.storage().persistent().set(&key, &bond);
+        }
+        Ok(())
+    }
+
+    /// Withdraw the remaining bond once the cooldown has elapsed
+    pub fn withdraw_oracle_bond(env: Env, oracle: Address) -> Result<i128, OracleError> {
+        oracle.require_auth();
+
+        let config = get_bond_config(&env).ok_or(OracleError::NotInitialized)?;
+        let key = (ORACLE_BOND, oracle.clone());
+        let bond: OracleBond =
+            env.storage().persistent().get(&key).ok_or(OracleError::NotBonded)?;
+        let requested_at = bond.unbond_requested_at.ok_or(OracleError::NotBonded)?;
+
+        if env.ledger().timestamp() < requested_at.saturating_add(config.cooldown_seconds) {
+            return Err(OracleError::BondCooldownActive);
+        }
+
+        env.storage().persistent().remove(&key);
+        if bond.amount > 0 {
+            token::Client::new(&env, &config.bond_token).transfer(
+                &env.current_contract_address(),
+                &oracle,
+                &bond.amount,
+            );
+        }
+
+        env.events().publish((symbol_short!("bond_wdr"), oracle), bond.amount);
+        Ok(bond.amount)
+    }
+
+    /// Get an oracle's bond, if any
+    pub fn get_oracle_bond(env: Env, oracle: Address) -> Option<OracleBond> {
+        env.storage().persistent().get(&(ORACLE_BOND, oracle))
+    }
+
+    /// Mark a finalized value as invalid (admin only), e.g. when manipulation
+    /// is discovered after the challenge window. The value stays readable with
+    /// `is_valid == false`, is dropped from the history, and the id cannot be
+    /// re-finalized until `reset_oracle_data` is called.
+    pub fn invalidate_oracle_data(
+        env: Env,
+        caller: Address,
+        data_id: u64,
+        reason: Symbol,
+    ) -> Result<(), OracleError> {
+        let admin = require_admin(&env)?;
+        caller.require_auth();
+        if caller != admin {
+            return Err(OracleError::Unauthorized);
+        }
+
+        let key = (ORACLE_DATA, data_id);
+        let mut data: OracleData =
+            env.storage().persistent().get(&key).ok_or(OracleError::NotFound)?;
+        if !data.is_valid {
+            return Err(OracleError::DataInvalidated);
+        }
+
+        data.is_valid = false;
+        env.storage().persistent().set(&key, &data);
+        remove_history_point(&env, data_id, data.finalized_at);
+
+        env.events().publish((symbol_short!("invalid"), data_id), (caller, reason));
+        Ok(())
+    }
+
+    /// Clear an invalidated value (admin only) so the id can be collected again
+    pub fn reset_oracle_data(env: Env, caller: Address, data_id: u64) -> Result<(), OracleError> {
+        let admin = require_admin(&env)?;
+        caller.require_auth();
+        if caller != admin {
+            return Err(OracleError::Unauthorized);
+        }
+
+        let key = (ORACLE_DATA, data_id);
+        let data: OracleData = env.storage().persistent().get(&key).ok_or(OracleError::NotFound)?;
+        if data.is_valid {
+            return Err(OracleError::InvalidInput);
+        }
+
+        env.storage().persistent().remove(&key);
+        env.storage().persistent().remove(&(RESOLVED_BY, data_id));
+        Ok(())
+    }
+
+    /// Finalize `value` for a data point without consensus (admin only), for
+    /// emergencies where a value is needed but the oracles cannot agree.
+    /// Outlier, cluster and quorum checks are skipped; pending submissions are
+    /// counted in `submission_count` and then cleared, and no bonds are
+    /// slashed. The result is marked `admin_override` with the justification.
+    pub fn admin_force_resolve(
+        env: Env,
+        caller: Address,
+        data_id: u64,
+        value: i128,
+        justification: Symbol,
+    ) -> Result<OracleData, OracleError> {
+        let admin = require_admin(&env)?;
+        caller.require_auth();
+        if caller != admin {
+            return Err(OracleError::Unauthorized);
+        }
+
+        if is_challenged(&env, data_id) {
+            return Err(OracleError::UnderChallenge);
+        }
+
+        let key = (ORACLE_DATA, data_id);
+        if let Some(existing) = env.storage().persistent().get::<_, OracleData>(&key) {
+            if !existing.is_valid {
+                return Err(OracleError::DataInvalidated);
+            }
+        }
+
+        let submissions_key = (SUBMISSIONS, data_id);
+        let submission_count = env
+            .storage()
+            .persistent()
+            .get::<_, Vec<OracleSubmission>>(&submissions_key)
+            .map(|submissions| submissions.len())
+            .unwrap_or(0);
+
+        let current_time = env.ledger().timestamp();
+        let oracle_data = OracleData {
+            data_id,
+            consensus_value: value,
+            submission_count,
+            consensus_percentage: 0,
+            finalized_at: current_time,
+            included_submissions: 0,
+            rejected_submissions: 0,
+            mean_abs_deviation: 0,
+            confidence: 0,
+            is_valid: true,
+            admin_override: true,
+            override_justification: Some(justification.clone()),
+        };
+
+        env.storage().persistent().set(&key, &oracle_data);
+        record_history(&env, data_id, value, current_time);
+        env.storage().persistent().remove(&(RESOLVED_BY, data_id));
+        env.storage().persistent().remove(&submissions_key);
+
+        env.events().publish(
+            (symbol_short!("override"), data_id),
+            (caller, value, justification, submission_count),
+        );
+
+        Ok(oracle_data)
+    }
+
+    /// Whether the resolved value for a data point was set by an admin override
+    pub fn is_admin_overridden(env: Env, data_id: u64) -> Result<bool, OracleError> {
+        let data: OracleData = env
+            .storage()
+            .persistent()
+            .get(&(ORACLE_DATA, data_id))
+            .ok_or(OracleError::NotFound)?;
+        Ok(data.admin_override)
+    }
+
+    /// Attempt to resolve oracle data with consensus validation
+    pub fn resolve_oracle_data(env: Env, data_id: u64) -> Result<OracleData, OracleError> {
+        OracleContract.try_resolve_oracle_data(&env, data_id)
+    }
+
+    /// Internal oracle resolution with validation
+    fn try_resolve_oracle_data(&self, env: &Env, data_id: u64) -> Result<OracleData, OracleError> {
+        if is_challenged(env, data_id) {
+            return Err(OracleError::UnderChallenge);
+        }
+
+        // An invalidated value stays in place until an admin resets the id
+        if let Some(existing) =
+            env.storage().persistent().get::<_, OracleData>(&(ORACLE_DATA, data_id))
+        {
+            if !existing.is_valid {
+                return Err(OracleError::DataInvalidated);
+            }
+        }
+
+        let thresholds = get_thresholds(env);
+        let current_time = env.ledger().timestamp();
+
+        let submissions_key = (SUBMISSIONS, data_id);
+        let submissions: Vec<OracleSubmission> =
+            env.storage().persistent().get(&submissions_key).ok_or(OracleError::NotFound)?;
+
+        // When bonding is enabled only bonded oracles' submissions count
+        let bond_config = get_bond_config(env);
+
+        // Extract values and weights and check for staleness
+        let mut values: Vec<i128> = Vec::new(&env);
+        let mut weights: Vec<u32> = Vec::new(&env);
+        let mut oracles: Vec<Address> = Vec::new(&env);
+        for i in 0..submissions.len() {
+            let sub = submissions.get(i).unwrap();
+
+            if let Some(config) = &bond_config {
+                if !is_bonded(env, config, &sub.oracle) {
+                    continue;
+                }
+            }
+
+            // Check staleness
+            if is_data_stale(sub.timestamp, current_time, thresholds.staleness_threshold_seconds) {
                 return Err(OracleError::StaleData);
             }
 
             values.push_back(sub.value);
+            weights.push_back(get_oracle_weight(env, &sub.oracle));
+            oracles.push_back(sub.oracle);
+        }
+
+        let submission_count = values.len() as u32;
+
+        // Check minimum submissions, and that they come from enough providers
+        if submission_count < thresholds.min_submissions
+            || count_distinct(env, &oracles) < thresholds.min_distinct_oracles
+        {
+            return Err(OracleError::InsufficientSubmissions);
         }
 
-        // Detect outliers
+        // Detect outliers on raw, unweighted values
         let outlier_flags = detect_outliers(&values, thresholds.outlier_deviation_percent);
 
         // Filter out outliers and calculate consensus
         let mut valid_values: Vec<i128> = Vec::new(&env);
+        let mut valid_weights: Vec<u32> = Vec::new(&env);
+        let mut valid_oracles: Vec<Address> = Vec::new(&env);
+        let mut rejected_oracles: Vec<Address> = Vec::new(&env);
         let mut rejected_count = 0u32;
 
         for i in 0..values.len() {
             if !outlier_flags.get(i).unwrap() {
                 valid_values.push_back(values.get(i).unwrap());
+                valid_weights.push_back(weights.get(i).unwrap());
+                valid_oracles.push_back(oracles.get(i).unwrap());
             } else {
                 rejected_count += 1;
+                rejected_oracles.push_back(oracles.get(i).unwrap());
             }
         }
 
@@ -411,8 +1512,49 @@ impl OracleContract {
             return Err(OracleError::ConsensusNotReached);
         }
 
-        // Calculate final consensus value (using median for robustness)
-        let consensus_value = calculate_median(&valid_values);
+        // Calculate final consensus value (weighted median for robustness)
+        let mut consensus_value = calculate_weighted_median(&valid_values, &valid_weights)
+            .ok_or(OracleError::ConsensusNotReached)?;
+        let mut included_values = valid_values;
+        let mut valid_count = valid_count;
+        let mut rejected_count = rejected_count;
+
+        // Optionally require the value to come from a large enough agreeing cluster
+        let min_cluster: u32 = env.storage().persistent().get(&MIN_CLUSTER).unwrap_or(0);
+        if min_cluster > 0 {
+            let cluster = find_largest_cluster(&values, thresholds.outlier_deviation_percent)
+                .ok_or(OracleError::ConsensusNotReached)?;
+            if cluster.len() < min_cluster {
+                return Err(OracleError::ConsensusNotReached);
+            }
+
+            // The cluster is a contiguous run of sorted values, so its members
+            // are exactly the submissions within its bounds
+            let low = cluster.first().unwrap();
+            let high = cluster.last().unwrap();
+            let mut cluster_weights: Vec<u32> = Vec::new(&env);
+            let mut cluster_values: Vec<i128> = Vec::new(&env);
+            valid_oracles = Vec::new(&env);
+            rejected_oracles = Vec::new(&env);
+            for i in 0..values.len() {
+                let value = values.get(i).unwrap();
+                if value >= low && value <= high {
+                    cluster_values.push_back(value);
+                    cluster_weights.push_back(weights.get(i).unwrap());
+                    valid_oracles.push_back(oracles.get(i).unwrap());
+                } else {
+                    rejected_oracles.push_back(oracles.get(i).unwrap());
+                }
+            }
+
+            consensus_value = calculate_weighted_median(&cluster_values, &cluster_weights)
+                .ok_or(OracleError::ConsensusNotReached)?;
+            valid_count = cluster.len();
+            rejected_count = submission_count - valid_count;
+            included_values = cluster;
+        }
+
+        let mean_abs_deviation = calculate_mean_abs_deviation(&included_values, consensus_value);
 
         // Store the resolved oracle data
         let oracle_data = OracleData {
@@ -423,10 +1565,30 @@ impl OracleContract {
             finalized_at: current_time,
             included_submissions: valid_count,
             rejected_submissions: rejected_count,
+            mean_abs_deviation,
+            confidence: calculate_confidence(
+                mean_abs_deviation,
+                consensus_value,
+                thresholds.outlier_deviation_percent,
+            ),
+            is_valid: true,
+            admin_override: false,
+            override_justification: None,
         };
 
         // Store the finalized data
         env.storage().persistent().set(&(ORACLE_DATA, data_id), &oracle_data);
+        record_history(env, data_id, consensus_value, current_time);
+
+        // Slash rejected outliers and remember who produced the value in case
+        // it is later overturned by a challenge
+        if let Some(config) = &bond_config {
+            let admin = require_admin(env)?;
+            for oracle in rejected_oracles.iter() {
+                slash_oracle_bond(env, config, &admin, &oracle, data_id);
+            }
+            env.storage().persistent().set(&(RESOLVED_BY, data_id), &valid_oracles);
+        }
 
         // Clear submissions after resolution
         env.storage().persistent().remove(&submissions_key);
@@ -435,13 +1597,97 @@ impl OracleContract {
     }
 
     /// Get resolved oracle data
+    /// Fails with `UnderChallenge` while the value is frozen by a challenge.
     pub fn get_oracle_data(env: Env, data_id: u64) -> Result<OracleData, OracleError> {
+        if is_challenged(&env, data_id) {
+            return Err(OracleError::UnderChallenge);
+        }
+
         env.storage()
             .persistent()
             .get(&(ORACLE_DATA, data_id))
             .ok_or(OracleError::NotFound)
     }
 
+    /// Get the spread (mean absolute deviation) behind the resolved consensus value.
+    /// A low spread signals high confidence in the value.
+    pub fn get_consensus_confidence(env: Env, data_id: u64) -> Result<i128, OracleError> {
+        Ok(Self::get_oracle_data(env, data_id)?.mean_abs_deviation)
+    }
+
+    /// Get the minimum and maximum finalized consensus values within the window
+    pub fn get_consensus_range(
+        env: Env,
+        data_id: u64,
+        window_seconds: u64,
+    ) -> Result<(i128, i128), OracleError> {
+        let history = get_history(&env, data_id);
+        let window_start = env.ledger().timestamp().saturating_sub(window_seconds);
+
+        let mut range: Option<(i128, i128)> = None;
+        for point in history.iter() {
+            if point.finalized_at < window_start {
+                continue;
+            }
+            range = Some(match range {
+                Some((min, max)) => (min.min(point.value), max.max(point.value)),
+                None => (point.value, point.value),
+            });
+        }
+
+        range.ok_or(OracleError::NotFound)
+    }
+
+    /// Get the time-weighted average of finalized consensus values within the window.
+    ///
+    /// Each value is weighted by how long it stood before the next finalization
+    /// (or now). A value finalized before the window contributes for the part of
+    /// the window it was still current. Fails with `NotFound` when nothing was
+    /// finalized inside the window.
+    pub fn get_twap(env: Env, feed_id: u64, window_seconds: u64) -> Result<i128, OracleError> {
+        let history = get_history(&env, feed_id);
+        let now = env.ledger().timestamp();
+        let window_start = now.saturating_sub(window_seconds);
+
+        let mut current: Option<i128> = None;
+        let mut cursor = window_start;
+        let mut weighted_sum: i128 = 0;
+        let mut total_weight: i128 = 0;
+        let mut in_window = false;
+
+        for point in history.iter() {
+            if point.finalized_at < window_start {
+                current = Some(point.value);
+                continue;
+            }
+            in_window = true;
+
+            if let Some(value) = current {
+                let held = (point.finalized_at - cursor) as i128;
+                weighted_sum = weighted_sum.saturating_add(value.saturating_mul(held));
+                total_weight += held;
+            }
+            cursor = point.finalized_at;
+            current = Some(point.value);
+        }
+
+        if !in_window {
+            return Err(OracleError::NotFound);
+        }
+
+        let last = current.ok_or(OracleError::NotFound)?;
+        let held = now.saturating_sub(cursor) as i128;
+        weighted_sum = weighted_sum.saturating_add(last.saturating_mul(held));
+        total_weight += held;
+
+        // Only a point finalized this instant: nothing has been held yet
+        if total_weight == 0 {
+            return Ok(last);
+        }
+
+        Ok(weighted_sum / total_weight)
+    }
+
     /// Get pending submissions for a data point
     pub fn get_pending_submissions(
         env: Env,
@@ -534,8 +1780,17 @@ mod tests {
         values.push_back(20i128);
         values.push_back(30i128);
 
-        let avg = calculate_weighted_average(&values);
+        let mut weights = Vec::new(&env);
+        weights.push_back(1u32);
+        weights.push_back(1u32);
+        weights.push_back(1u32);
+
+        let avg = calculate_weighted_average(&values, &weights);
         assert_eq!(avg, 20i128);
+
+        // Weight 2 on 30: (10 + 20 + 60) / 4
+        weights.set(2, 2);
+        assert_eq!(calculate_weighted_average(&values, &weights), 22i128);
     }
 
     #[test]
@@ -579,15 +1834,15 @@ mod oracle_consensus_tests {
         let data_id = 1u64;
 
         // Oracle 1 submits value 100
-        let result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128);
+        let result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128, 1);
         assert!(result1.is_ok());
 
         // Oracle 2 submits value 102 (within 15% deviation)
-        let result2 = contract.submit_oracle_data(env.clone(), data_id, 102i128);
+        let result2 = contract.submit_oracle_data(env.clone(), data_id, 102i128, 2);
         assert!(result2.is_ok());
 
         // Oracle 3 submits value 101 (within 15% deviation)
-        let result3 = contract.submit_oracle_data(env.clone(), data_id, 101i128);
+        let result3 = contract.submit_oracle_data(env.clone(), data_id, 101i128, 3);
         assert!(result3.is_ok());
 
         // Consensus should be reached with median of 101
@@ -614,12 +1869,12 @@ mod oracle_consensus_tests {
         let data_id = 2u64;
 
         // Valid submissions
-        let _result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128);
-        let _result2 = contract.submit_oracle_data(env.clone(), data_id, 101i128);
-        let _result3 = contract.submit_oracle_data(env.clone(), data_id, 102i128);
+        let _result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128, 1);
+        let _result2 = contract.submit_oracle_data(env.clone(), data_id, 101i128, 2);
+        let _result3 = contract.submit_oracle_data(env.clone(), data_id, 102i128, 3);
 
         // Outlier submission (far outside 15% deviation range)
-        let _result4 = contract.submit_oracle_data(env.clone(), data_id, 500i128);
+        let _result4 = contract.submit_oracle_data(env.clone(), data_id, 500i128, 4);
 
         let resolved = contract.resolve_oracle_data(env.clone(), data_id);
         assert!(resolved.is_ok());
@@ -644,7 +1899,7 @@ mod oracle_consensus_tests {
         let data_id = 3u64;
 
         // Submit only one oracle value
-        let _result = contract.submit_oracle_data(env.clone(), data_id, 100i128);
+        let _result = contract.submit_oracle_data(env.clone(), data_id, 100i128, 1);
 
         // Try to resolve - should fail with insufficient submissions
         let resolved = contract.resolve_oracle_data(env.clone(), data_id);
@@ -673,9 +1928,9 @@ mod oracle_consensus_tests {
 
         // Simulate oracle data submission at time 100
         env.ledger().with_timestamp(100);
-        let _result = contract.submit_oracle_data(env.clone(), data_id, 100i128);
-        let _result = contract.submit_oracle_data(env.clone(), data_id, 101i128);
-        let _result = contract.submit_oracle_data(env.clone(), data_id, 102i128);
+        let _result = contract.submit_oracle_data(env.clone(), data_id, 100i128, 1);
+        let _result = contract.submit_oracle_data(env.clone(), data_id, 101i128, 2);
+        let _result = contract.submit_oracle_data(env.clone(), data_id, 102i128, 3);
 
         // Try to resolve - should succeed at current time
         let resolved1 = contract.resolve_oracle_data(env.clone(), data_id);
@@ -686,9 +1941,9 @@ mod oracle_consensus_tests {
 
         // Create new data point at future timestamp
         let data_id2 = 5u64;
-        let _result = contract.submit_oracle_data(env.clone(), data_id2, 100i128);
-        let _result = contract.submit_oracle_data(env.clone(), data_id2, 101i128);
-        let _result = contract.submit_oracle_data(env.clone(), data_id2, 102i128);
+        let _result = contract.submit_oracle_data(env.clone(), data_id2, 100i128, 1);
+        let _result = contract.submit_oracle_data(env.clone(), data_id2, 101i128, 2);
+        let _result = contract.submit_oracle_data(env.clone(), data_id2, 102i128, 3);
 
         // This should fail because all submissions are stale
         let resolved2 = contract.resolve_oracle_data(env.clone(), data_id2);
@@ -708,11 +1963,11 @@ mod oracle_consensus_tests {
         let data_id = 6u64;
 
         // First submission should succeed
-        let result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128);
+        let result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128, 0);
         assert!(result1.is_ok());
 
         // Second submission from same oracle should fail
-        let result2 = contract.submit_oracle_data(env.clone(), data_id, 102i128);
+        let result2 = contract.submit_oracle_data(env.clone(), data_id, 102i128, 0);
         assert_eq!(result2, Err(OracleError::DuplicateSubmission));
     }
 
@@ -737,9 +1992,9 @@ mod oracle_consensus_tests {
         let data_id = 7u64;
 
         // Submit 3 values where 2 match and 1 is outlier
-        let _result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128);
-        let _result2 = contract.submit_oracle_data(env.clone(), data_id, 101i128);
-        let _result3 = contract.submit_oracle_data(env.clone(), data_id, 500i128); // Outlier
+        let _result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128, 1);
+        let _result2 = contract.submit_oracle_data(env.clone(), data_id, 101i128, 2);
+        let _result3 = contract.submit_oracle_data(env.clone(), data_id, 500i128, 3); // Outlier
 
         // With only 2 valid submissions out of 3 (66%), below 80% threshold
         let resolved = contract.resolve_oracle_data(env.clone(), data_id);
@@ -779,7 +2034,12 @@ mod oracle_consensus_tests {
         values.push_back(200i128);
         values.push_back(300i128);
 
-        let avg = calculate_weighted_average(&values);
+        let mut weights = Vec::new(&env);
+        weights.push_back(1u32);
+        weights.push_back(1u32);
+        weights.push_back(1u32);
+
+        let avg = calculate_weighted_average(&values, &weights);
         assert_eq!(avg, 200i128);
     }
 
@@ -836,9 +2096,9 @@ mod oracle_consensus_tests {
         let data_id = 10u64;
 
         // Submit and resolve
-        let _result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128);
-        let _result2 = contract.submit_oracle_data(env.clone(), data_id, 101i128);
-        let _result3 = contract.submit_oracle_data(env.clone(), data_id, 102i128);
+        let _result1 = contract.submit_oracle_data(env.clone(), data_id, 100i128, 1);
+        let _result2 = contract.submit_oracle_data(env.clone(), data_id, 101i128, 2);
+        let _result3 = contract.submit_oracle_data(env.clone(), data_id, 102i128, 3);
 
         let resolved = contract.resolve_oracle_data(env.clone(), data_id).unwrap();
 
@@ -860,14 +2120,14 @@ mod oracle_consensus_tests {
         contract.initialize(env.clone(), admin.clone()).unwrap();
 
         // Data point 1
-        let _r1_1 = contract.submit_oracle_data(env.clone(), 1u64, 100i128);
-        let _r1_2 = contract.submit_oracle_data(env.clone(), 1u64, 101i128);
-        let _r1_3 = contract.submit_oracle_data(env.clone(), 1u64, 102i128);
+        let _r1_1 = contract.submit_oracle_data(env.clone(), 1u64, 100i128, 1);
+        let _r1_2 = contract.submit_oracle_data(env.clone(), 1u64, 101i128, 2);
+        let _r1_3 = contract.submit_oracle_data(env.clone(), 1u64, 102i128, 3);
 
         // Data point 2
-        let _r2_1 = contract.submit_oracle_data(env.clone(), 2u64, 200i128);
-        let _r2_2 = contract.submit_oracle_data(env.clone(), 2u64, 201i128);
-        let _r2_3 = contract.submit_oracle_data(env.clone(), 2u64, 202i128);
+        let _r2_1 = contract.submit_oracle_data(env.clone(), 2u64, 200i128, 1);
+        let _r2_2 = contract.submit_oracle_data(env.clone(), 2u64, 201i128, 2);
+        let _r2_3 = contract.submit_oracle_data(env.clone(), 2u64, 202i128, 3);
 
         // Resolve both independently
         let resolved1 = contract.resolve_oracle_data(env.clone(), 1u64).unwrap();
@@ -891,14 +2151,918 @@ mod oracle_consensus_tests {
         let _result = contract.set_paused(env.clone(), true);
 
         // Attempts to submit should fail
-        let submit_result = contract.submit_oracle_data(env.clone(), 1u64, 100i128);
+        let submit_result = contract.submit_oracle_data(env.clone(), 1u64, 100i128, 0);
         assert_eq!(submit_result, Err(OracleError::Paused));
 
         // Unpause
         let _result = contract.set_paused(env.clone(), false);
 
         // Should work again
-        let submit_result2 = contract.submit_oracle_data(env.clone(), 1u64, 100i128);
+        let submit_result2 = contract.submit_oracle_data(env.clone(), 1u64, 100i128, 0);
         assert!(submit_result2.is_ok());
     }
 }
+
+// ============================================================================
+// Contract Client Tests
+// ============================================================================
+
+#[cfg(test)]
+mod contract_tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Events, Ledger};
+    use soroban_sdk::{xdr, TryFromVal, Val};
+
+    /// Last emitted event as `(contract, topics, data)`
+    fn last_event(env: &Env) -> (Address, Vec<Val>, Val) {
+        let event = env.events().all().events().last().unwrap().clone();
+        let xdr::ContractEventBody::V0(body) = event.body;
+        let contract =
+            Address::try_from_val(env, &xdr::ScAddress::Contract(event.contract_id.unwrap()))
+                .unwrap();
+        let mut topics = Vec::new(env);
+        for topic in body.topics.iter() {
+            topics.push_back(Val::try_from_val(env, topic).unwrap());
+        }
+        (contract, topics, Val::try_from_val(env, &body.data).unwrap())
+    }
+
+    fn setup(env: &Env) -> OracleContractClient<'_> {
+        env.mock_all_auths();
+        let contract_id = env.register(OracleContract, ());
+        let client = OracleContractClient::new(env, &contract_id);
+        client.initialize(&Address::generate(env));
+        client
+    }
+
+    /// Resolve a single-submission data point at the given timestamp
+    fn resolve_at(env: &Env, client: &OracleContractClient, data_id: u64, value: i128, at: u64) {
+        env.ledger().with_mut(|l| l.timestamp = at);
+        assert!(client.submit_oracle_data(&data_id, &value, &0));
+    }
+
+    #[test]
+    fn test_consensus_range_within_window() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_thresholds(&1, &66, &15, &3600);
+
+        resolve_at(&env, &client, 1, 500, 1_000);
+        resolve_at(&env, &client, 1, 120, 5_000);
+        resolve_at(&env, &client, 1, 180, 6_000);
+        resolve_at(&env, &client, 1, 150, 7_000);
+
+        // Window [4_000, 7_000] excludes the 500 point finalized at 1_000
+        assert_eq!(client.get_consensus_range(&1, &3_000), (120, 180));
+        // A wide enough window includes it
+        assert_eq!(client.get_consensus_range(&1, &10_000), (120, 500));
+    }
+
+    #[test]
+    fn test_consensus_range_not_found() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_thresholds(&1, &66, &15, &3600);
+
+        assert_eq!(client.try_get_consensus_range(&1, &3_600), Err(Ok(OracleError::NotFound)));
+
+        resolve_at(&env, &client, 1, 100, 1_000);
+        env.ledger().with_mut(|l| l.timestamp = 10_000);
+        assert_eq!(client.try_get_consensus_range(&1, &60), Err(Ok(OracleError::NotFound)));
+    }
+
+    #[test]
+    fn test_twap_weights_values_by_time_held() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_thresholds(&1, &66, &15, &3600);
+
+        resolve_at(&env, &client, 1, 100, 1_000);
+        resolve_at(&env, &client, 1, 200, 2_000);
+        env.ledger().with_mut(|l| l.timestamp = 4_000);
+
+        // Window [1_000, 4_000]: 100 held 1_000s, 200 held 2_000s
+        assert_eq!(client.get_twap(&1, &3_000), (100 * 1_000 + 200 * 2_000) / 3_000);
+
+        // Window [1_500, 4_000]: 100 carried in for 500s before 200 took over
+        assert_eq!(client.get_twap(&1, &2_500), (100 * 500 + 200 * 2_000) / 2_500);
+    }
+
+    #[test]
+    fn test_twap_edge_cases() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_thresholds(&1, &66, &15, &3600);
+
+        assert_eq!(client.try_get_twap(&1, &3_600), Err(Ok(OracleError::NotFound)));
+
+        // Single point returns that value, even before any time has passed
+        resolve_at(&env, &client, 1, 300, 5_000);
+        assert_eq!(client.get_twap(&1, &3_600), 300);
+        env.ledger().with_mut(|l| l.timestamp = 6_000);
+        assert_eq!(client.get_twap(&1, &3_600), 300);
+
+        // Nothing finalized inside the window
+        assert_eq!(client.try_get_twap(&1, &500), Err(Ok(OracleError::NotFound)));
+    }
+
+    #[test]
+    fn test_individual_threshold_setters() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_thresholds(&3, &66, &15, &3600);
+
+        client.set_min_submissions(&5);
+        assert_eq!(
+            client.get_thresholds(),
+            ValidationThreshold {
+                min_submissions: 5,
+                majority_threshold_percent: 66,
+                outlier_deviation_percent: 15,
+                staleness_threshold_seconds: 3600,
+                min_distinct_oracles: 1,
+            }
+        );
+
+        client.set_majority_threshold(&75);
+        client.set_outlier_deviation(&20);
+        client.set_staleness(&600);
+        assert_eq!(
+            client.get_thresholds(),
+            ValidationThreshold {
+                min_submissions: 5,
+                majority_threshold_percent: 75,
+                outlier_deviation_percent: 20,
+                staleness_threshold_seconds: 600,
+                min_distinct_oracles: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_individual_threshold_setters_validate() {
+        let env = Env::default();
+        let client = setup(&env);
+        let before = client.get_thresholds();
+
+        assert_eq!(client.try_set_min_submissions(&0), Err(Ok(OracleError::InvalidThreshold)));
+        assert_eq!(client.try_set_majority_threshold(&101), Err(Ok(OracleError::InvalidThreshold)));
+        assert_eq!(client.try_set_outlier_deviation(&-1), Err(Ok(OracleError::InvalidThreshold)));
+        assert_eq!(client.try_set_staleness(&0), Err(Ok(OracleError::InvalidThreshold)));
+        assert_eq!(
+            client.try_set_min_distinct_oracles(&0),
+            Err(Ok(OracleError::InvalidThreshold))
+        );
+
+        assert_eq!(client.get_thresholds(), before);
+    }
+
+    #[test]
+    fn test_get_version() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        let info = client.get_version();
+        assert_eq!(info.current_version, CONTRACT_VERSION);
+        assert_eq!(info.migration_count, 0);
+
+        client.require_min_version(&CONTRACT_VERSION);
+        assert_eq!(
+            client.try_require_min_version(&(CONTRACT_VERSION + 1)),
+            Err(Ok(OracleError::IncompatibleVersion))
+        );
+    }
+
+    #[test]
+    fn test_same_oracle_can_submit_distinct_sources() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        assert!(!client.submit_oracle_data(&1, &100, &1));
+        assert!(!client.submit_oracle_data(&1, &101, &2));
+
+        let pending = client.get_pending_submissions(&1);
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending.get(0).unwrap().source_id, 1);
+        assert_eq!(pending.get(1).unwrap().source_id, 2);
+    }
+
+    #[test]
+    fn test_same_oracle_and_source_rejected_twice() {
+        let env = Env::default();
+        let client = setup(&env);
+
+        assert!(!client.submit_oracle_data(&1, &100, &7));
+        assert_eq!(
+            client.try_submit_oracle_data(&1, &102, &7),
+            Err(Ok(OracleError::DuplicateSubmission))
+        );
+        assert_eq!(client.get_submission_count(&1), 1);
+    }
+
+    fn setup_with_admin(env: &Env) -> (OracleContractClient<'_>, Address) {
+        env.mock_all_auths();
+        let contract_id = env.register(OracleContract, ());
+        let client = OracleContractClient::new(env, &contract_id);
+        let admin = Address::generate(env);
+        client.initialize(&admin);
+        client.set_thresholds(&1, &66, &15, &3600);
+        (client, admin)
+    }
+
+    #[test]
+    fn test_invalidated_data_is_flagged_and_cannot_refinalize() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let reason = symbol_short!("manip");
+
+        resolve_at(&env, &client, 1, 500, 1_000);
+        assert!(client.get_oracle_data(&1).is_valid);
+
+        client.invalidate_oracle_data(&admin, &1, &reason);
+        let data = client.get_oracle_data(&1);
+        assert!(!data.is_valid);
+        assert_eq!(data.consensus_value, 500);
+        assert_eq!(client.try_get_consensus_range(&1, &3_600), Err(Ok(OracleError::NotFound)));
+
+        assert_eq!(
+            client.try_submit_oracle_data(&1, &510, &0),
+            Err(Ok(OracleError::DataInvalidated))
+        );
+        assert_eq!(
+            client.try_invalidate_oracle_data(&admin, &1, &reason),
+            Err(Ok(OracleError::DataInvalidated))
+        );
+
+        client.reset_oracle_data(&admin, &1);
+        assert_eq!(client.try_get_oracle_data(&1), Err(Ok(OracleError::NotFound)));
+        resolve_at(&env, &client, 1, 510, 1_100);
+        assert!(client.get_oracle_data(&1).is_valid);
+    }
+
+    #[test]
+    fn test_invalidate_requires_admin_and_existing_data() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let reason = symbol_short!("manip");
+
+        assert_eq!(
+            client.try_invalidate_oracle_data(&admin, &1, &reason),
+            Err(Ok(OracleError::NotFound))
+        );
+
+        resolve_at(&env, &client, 1, 500, 1_000);
+        assert_eq!(
+            client.try_invalidate_oracle_data(&Address::generate(&env), &1, &reason),
+            Err(Ok(OracleError::Unauthorized))
+        );
+        assert_eq!(client.try_reset_oracle_data(&admin, &1), Err(Ok(OracleError::InvalidInput)));
+        assert!(client.get_oracle_data(&1).is_valid);
+    }
+
+    #[test]
+    fn test_admin_force_resolve_when_consensus_fails() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        client.set_thresholds(&3, &66, &15, &3600);
+        let justification = symbol_short!("flood");
+
+        assert!(!client.submit_oracle_data(&1, &100, &0));
+        assert!(!client.submit_oracle_data(&1, &400, &1));
+        assert_eq!(
+            client.try_resolve_oracle_data(&1),
+            Err(Ok(OracleError::InsufficientSubmissions))
+        );
+
+        env.ledger().with_mut(|l| l.timestamp = 2_000);
+        let data = client.admin_force_resolve(&admin, &1, &250, &justification);
+        let (_, topics, _) = last_event(&env);
+        let name: Symbol = topics.get(0).unwrap().into_val(&env);
+        assert_eq!(name, symbol_short!("override"));
+
+        assert_eq!(data.consensus_value, 250);
+        assert_eq!(data.submission_count, 2);
+        assert_eq!(data.finalized_at, 2_000);
+        assert!(data.admin_override);
+        assert_eq!(data.override_justification, Some(justification.clone()));
+
+        assert_eq!(client.get_oracle_data(&1), data);
+        assert!(client.is_admin_overridden(&1));
+        assert_eq!(client.try_get_submission_count(&1), Err(Ok(OracleError::NotFound)));
+    }
+
+    #[test]
+    fn test_admin_force_resolve_requires_admin() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let justification = symbol_short!("flood");
+
+        assert_eq!(
+            client.try_admin_force_resolve(&Address::generate(&env), &1, &250, &justification),
+            Err(Ok(OracleError::Unauthorized))
+        );
+        assert_eq!(client.try_is_admin_overridden(&1), Err(Ok(OracleError::NotFound)));
+
+        // Normally resolved values are not flagged
+        resolve_at(&env, &client, 1, 500, 1_000);
+        assert!(!client.is_admin_overridden(&1));
+        assert_eq!(client.get_oracle_data(&1).override_justification, None);
+
+        // Invalidated values must be reset before they can be overridden
+        client.invalidate_oracle_data(&admin, &1, &symbol_short!("manip"));
+        assert_eq!(
+            client.try_admin_force_resolve(&admin, &1, &250, &justification),
+            Err(Ok(OracleError::DataInvalidated))
+        );
+    }
+
+    #[test]
+    fn test_sort_values_and_median() {
+        let env = Env::default();
+
+        let values = soroban_sdk::vec![&env, 30i128, -5, 20, 20, 10, 100, 0];
+        assert_eq!(sort_values(&values), soroban_sdk::vec![&env, -5i128, 0, 10, 20, 20, 30, 100]);
+        assert_eq!(sort_values(&Vec::new(&env)), Vec::<i128>::new(&env));
+
+        assert_eq!(calculate_median(&soroban_sdk::vec![&env, 10i128, 30, 20]), 20);
+        assert_eq!(calculate_median(&soroban_sdk::vec![&env, 102i128, 100, 101]), 101);
+        assert_eq!(calculate_median(&soroban_sdk::vec![&env, 40i128, 10, 30, 20]), 25);
+    }
+
+    #[test]
+    fn test_max_submissions_rejects_extra_submissions() {
+        let env = Env::default();
+        let client = setup(&env);
+        assert_eq!(client.get_max_submissions(), 100);
+        assert_eq!(client.try_set_max_submissions(&0), Err(Ok(OracleError::InvalidThreshold)));
+
+        // Below the default minimum of 3 so the pending set fills without resolving
+        client.set_max_submissions(&2);
+        assert!(!client.submit_oracle_data(&1, &100, &1));
+        assert!(!client.submit_oracle_data(&1, &101, &2));
+        assert_eq!(
+            client.try_submit_oracle_data(&1, &102, &3),
+            Err(Ok(OracleError::TooManySubmissions))
+        );
+        assert_eq!(client.get_submission_count(&1), 2);
+    }
+
+    fn setup_relayer(env: &Env, client: &OracleContractClient, admin: &Address) -> Address {
+        let relayer = Address::generate(env);
+        client.add_relayer(admin, &relayer);
+        client.set_thresholds(&3, &66, &15, &3600);
+        relayer
+    }
+
+    /// Generate a whitelisted oracle address
+    fn new_oracle(env: &Env, client: &OracleContractClient, admin: &Address) -> Address {
+        let oracle = Address::generate(env);
+        client.add_oracle(admin, &oracle);
+        oracle
+    }
+
+    #[test]
+    fn test_submit_many_and_resolve() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+
+        let mut batch = Vec::new(&env);
+        batch.push_back((new_oracle(&env, &client, &admin), 100, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 102, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 98, 0));
+
+        let data = client.submit_many_and_resolve(&relayer, &1, &batch);
+        assert_eq!(data.consensus_value, 100);
+        assert_eq!(data.submission_count, 3);
+        assert_eq!(client.get_oracle_data(&1), data);
+    }
+
+    #[test]
+    fn test_consensus_confidence_reflects_spread() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+
+        let mut tight = Vec::new(&env);
+        tight.push_back((new_oracle(&env, &client, &admin), 100, 0));
+        tight.push_back((new_oracle(&env, &client, &admin), 101, 0));
+        tight.push_back((new_oracle(&env, &client, &admin), 99, 0));
+        client.submit_many_and_resolve(&relayer, &1, &tight);
+
+        let mut wide = Vec::new(&env);
+        wide.push_back((new_oracle(&env, &client, &admin), 100, 0));
+        wide.push_back((new_oracle(&env, &client, &admin), 110, 0));
+        wide.push_back((new_oracle(&env, &client, &admin), 90, 0));
+        client.submit_many_and_resolve(&relayer, &2, &wide);
+
+        // |0| + |1| + |-1| = 2 over 3 submissions
+        assert_eq!(client.get_consensus_confidence(&1), 0);
+        // |0| + |10| + |-10| = 20 over 3 submissions
+        assert_eq!(client.get_consensus_confidence(&2), 6);
+        assert_eq!(client.get_oracle_data(&2).mean_abs_deviation, 6);
+
+        assert_eq!(client.try_get_consensus_confidence(&3), Err(Ok(OracleError::NotFound)));
+    }
+
+    #[test]
+    fn test_confidence_score_tight_vs_loose_agreement() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+
+        let mut tight = Vec::new(&env);
+        tight.push_back((new_oracle(&env, &client, &admin), 1_000, 0));
+        tight.push_back((new_oracle(&env, &client, &admin), 1_000, 0));
+        tight.push_back((new_oracle(&env, &client, &admin), 1_000, 0));
+        let tight = client.submit_many_and_resolve(&relayer, &1, &tight);
+
+        let mut loose = Vec::new(&env);
+        loose.push_back((new_oracle(&env, &client, &admin), 100, 0));
+        loose.push_back((new_oracle(&env, &client, &admin), 110, 0));
+        loose.push_back((new_oracle(&env, &client, &admin), 90, 0));
+        let loose = client.submit_many_and_resolve(&relayer, &2, &loose);
+
+        assert_eq!(tight.confidence, 100);
+        // 6% mean deviation against a 15% outlier threshold
+        assert_eq!(loose.confidence, 60);
+        assert!(loose.confidence < tight.confidence);
+        assert_eq!(client.get_oracle_data(&2).confidence, 60);
+    }
+
+    #[test]
+    fn test_high_weight_oracle_pulls_consensus() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+
+        let trusted = new_oracle(&env, &client, &admin);
+        client.set_oracle_weight(&trusted, &5);
+        assert_eq!(client.get_oracle_weight(&trusted), 5);
+
+        let mut batch = Vec::new(&env);
+        batch.push_back((new_oracle(&env, &client, &admin), 100, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 104, 0));
+        batch.push_back((trusted, 110, 0));
+
+        // Unweighted median would be 104
+        let data = client.submit_many_and_resolve(&relayer, &1, &batch);
+        assert_eq!(data.consensus_value, 110);
+        assert_eq!(data.included_submissions, 3);
+    }
+
+    #[test]
+    fn test_zero_weight_oracle_is_ignored() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+
+        let flaky = new_oracle(&env, &client, &admin);
+        client.set_oracle_weight(&flaky, &0);
+
+        let mut batch = Vec::new(&env);
+        batch.push_back((flaky, 100, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 102, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 110, 0));
+
+        // Median of the remaining two values; unweighted it would be 102
+        let data = client.submit_many_and_resolve(&relayer, &1, &batch);
+        assert_eq!(data.consensus_value, 106);
+    }
+
+    #[test]
+    fn test_submit_many_and_resolve_insufficient() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+
+        let mut batch = Vec::new(&env);
+        batch.push_back((new_oracle(&env, &client, &admin), 100, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 101, 0));
+
+        assert_eq!(
+            client.try_submit_many_and_resolve(&relayer, &1, &batch),
+            Err(Ok(OracleError::InsufficientSubmissions))
+        );
+        // The failed batch is not recorded
+        assert_eq!(client.try_get_submission_count(&1), Err(Ok(OracleError::NotFound)));
+    }
+
+    #[test]
+    fn test_submit_many_and_resolve_rejects_bad_entries() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+
+        let oracle = new_oracle(&env, &client, &admin);
+        let mut batch = Vec::new(&env);
+        batch.push_back((oracle.clone(), 100, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 101, 0));
+        batch.push_back((oracle.clone(), 99, 0));
+        assert_eq!(
+            client.try_submit_many_and_resolve(&relayer, &1, &batch),
+            Err(Ok(OracleError::DuplicateSubmission))
+        );
+
+        // A second feed from the same oracle is a distinct submission
+        let mut feeds = Vec::new(&env);
+        feeds.push_back((oracle.clone(), 100, 0));
+        feeds.push_back((new_oracle(&env, &client, &admin), 101, 0));
+        feeds.push_back((oracle.clone(), 99, 1));
+        assert_eq!(client.submit_many_and_resolve(&relayer, &2, &feeds).submission_count, 3);
+
+        let mut unlisted = Vec::new(&env);
+        unlisted.push_back((Address::generate(&env), 100, 0));
+        assert_eq!(
+            client.try_submit_many_and_resolve(&relayer, &1, &unlisted),
+            Err(Ok(OracleError::OracleNotWhitelisted))
+        );
+
+        client.remove_oracle(&admin, &oracle);
+        assert!(!client.is_oracle(&oracle));
+        let mut removed = Vec::new(&env);
+        removed.push_back((oracle, 100, 0));
+        assert_eq!(
+            client.try_submit_many_and_resolve(&relayer, &1, &removed),
+            Err(Ok(OracleError::OracleNotWhitelisted))
+        );
+
+        let outsider = Address::generate(&env);
+        assert_eq!(
+            client.try_submit_many_and_resolve(&outsider, &1, &batch),
+            Err(Ok(OracleError::Unauthorized))
+        );
+
+        assert_eq!(
+            client.try_remove_relayer(&outsider, &relayer),
+            Err(Ok(OracleError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_add_relayer(&outsider, &outsider),
+            Err(Ok(OracleError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_add_oracle(&outsider, &outsider),
+            Err(Ok(OracleError::Unauthorized))
+        );
+
+        client.remove_relayer(&admin, &relayer);
+        assert_eq!(
+            client.try_submit_many_and_resolve(&relayer, &1, &batch),
+            Err(Ok(OracleError::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_value_bounds_apply_to_every_entry() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+
+        assert_eq!(
+            client.try_set_value_bounds(&admin, &200, &100),
+            Err(Ok(OracleError::InvalidInput))
+        );
+        assert_eq!(
+            client.try_set_value_bounds(&relayer, &50, &150),
+            Err(Ok(OracleError::Unauthorized))
+        );
+        client.set_value_bounds(&admin, &50, &102);
+        assert_eq!(client.get_value_bounds(), Some((50, 102)));
+
+        let mut batch = Vec::new(&env);
+        batch.push_back((new_oracle(&env, &client, &admin), 100, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 101, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 103, 0));
+        assert_eq!(
+            client.try_submit_many_and_resolve(&relayer, &1, &batch),
+            Err(Ok(OracleError::ValueOutOfBounds))
+        );
+        assert_eq!(
+            client.try_submit_oracle_data(&1, &49, &0),
+            Err(Ok(OracleError::ValueOutOfBounds))
+        );
+
+        batch.set(2, (new_oracle(&env, &client, &admin), 102, 0));
+        assert_eq!(client.submit_many_and_resolve(&relayer, &1, &batch).consensus_value, 101);
+    }
+
+    #[test]
+    fn test_min_distinct_oracles_rejects_single_provider() {
+        let env = Env::default();
+        let client = setup(&env);
+        client.set_thresholds(&3, &66, &15, &3600);
+        client.set_min_distinct_oracles(&2);
+        assert_eq!(client.get_thresholds().min_distinct_oracles, 2);
+
+        // Three submissions on different feeds, all from the same oracle
+        assert!(!client.submit_oracle_data(&1, &100, &0));
+        assert!(!client.submit_oracle_data(&1, &101, &1));
+        assert!(!client.submit_oracle_data(&1, &102, &2));
+        assert_eq!(client.get_submission_count(&1), 3);
+        assert_eq!(
+            client.try_resolve_oracle_data(&1),
+            Err(Ok(OracleError::InsufficientSubmissions))
+        );
+
+        client.set_min_distinct_oracles(&1);
+        assert_eq!(client.resolve_oracle_data(&1).consensus_value, 101);
+    }
+
+    #[test]
+    fn test_min_distinct_oracles_in_batch() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+        client.set_min_distinct_oracles(&4);
+
+        let mut batch = Vec::new(&env);
+        batch.push_back((new_oracle(&env, &client, &admin), 100, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 101, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 102, 0));
+        assert_eq!(
+            client.try_submit_many_and_resolve(&relayer, &1, &batch),
+            Err(Ok(OracleError::InsufficientSubmissions))
+        );
+
+        client.set_min_distinct_oracles(&3);
+        let data = client.submit_many_and_resolve(&relayer, &1, &batch);
+        assert_eq!(data.consensus_value, 101);
+    }
+
+    #[test]
+    fn test_min_cluster_accepts_honest_cluster() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+        client.set_thresholds(&4, &66, &15, &3600);
+        client.set_outlier_min_cluster(&3);
+        assert_eq!(client.get_outlier_min_cluster(), 3);
+
+        let mut batch = Vec::new(&env);
+        batch.push_back((new_oracle(&env, &client, &admin), 100, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 150, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 101, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 102, 0));
+
+        let data = client.submit_many_and_resolve(&relayer, &1, &batch);
+        assert_eq!(data.consensus_value, 101);
+        assert_eq!(data.included_submissions, 3);
+        assert_eq!(data.rejected_submissions, 1);
+    }
+
+    #[test]
+    fn test_min_cluster_rejects_split_brain() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+        // Wide enough deviation that no submission is flagged against the median
+        client.set_thresholds(&4, &66, &40, &3600);
+
+        let mut batch = Vec::new(&env);
+        batch.push_back((new_oracle(&env, &client, &admin), 100, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 101, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 200, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 201, 0));
+
+        client.set_outlier_min_cluster(&3);
+        assert_eq!(
+            client.try_submit_many_and_resolve(&relayer, &1, &batch),
+            Err(Ok(OracleError::ConsensusNotReached))
+        );
+
+        // Two equal clusters never agree, even when each meets the minimum
+        client.set_outlier_min_cluster(&2);
+        assert_eq!(
+            client.try_submit_many_and_resolve(&relayer, &1, &batch),
+            Err(Ok(OracleError::ConsensusNotReached))
+        );
+
+        // Without the cluster requirement the median of both groups is accepted
+        client.set_outlier_min_cluster(&0);
+        assert_eq!(client.submit_many_and_resolve(&relayer, &1, &batch).consensus_value, 150);
+    }
+
+    fn setup_challenge(
+        env: &Env,
+    ) -> (OracleContractClient<'_>, token::Client<'_>, Address, Address) {
+        let (client, admin) = setup_with_admin(env);
+
+        let token_admin = Address::generate(env);
+        let bond_token = env.register_stellar_asset_contract_v2(token_admin).address();
+        let challenger = Address::generate(env);
+        token::StellarAssetClient::new(env, &bond_token).mint(&challenger, &1_000);
+
+        client.set_challenge_config(&admin, &600, &bond_token, &100);
+        (client, token::Client::new(env, &bond_token), challenger, admin)
+    }
+
+    #[test]
+    fn test_challenge_freezes_value_and_upheld_challenge_reopens_collection() {
+        let env = Env::default();
+        let (client, bond, challenger, admin) = setup_challenge(&env);
+        let evidence = BytesN::from_array(&env, &[7u8; 32]);
+
+        resolve_at(&env, &client, 1, 500, 1_000);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_300);
+        client.challenge_resolution(&challenger, &1, &evidence);
+        assert_eq!(bond.balance(&challenger), 900);
+        assert_eq!(client.get_challenge(&1).unwrap().evidence, evidence);
+
+        // Frozen: neither readable nor re-resolvable, and only one challenge at a time
+        assert_eq!(client.try_get_oracle_data(&1), Err(Ok(OracleError::UnderChallenge)));
+        assert_eq!(
+            client.try_submit_oracle_data(&1, &510, &0),
+            Err(Ok(OracleError::UnderChallenge))
+        );
+        assert_eq!(
+            client.try_challenge_resolution(&challenger, &1, &evidence),
+            Err(Ok(OracleError::UnderChallenge))
+        );
+
+        client.resolve_challenge(&admin, &1, &true);
+        assert_eq!(bond.balance(&challenger), 1_000);
+        assert_eq!(client.get_challenge(&1), None);
+        assert_eq!(client.try_get_oracle_data(&1), Err(Ok(OracleError::NotFound)));
+        assert_eq!(client.try_get_consensus_range(&1, &3_600), Err(Ok(OracleError::NotFound)));
+
+        // The data point can be collected again
+        resolve_at(&env, &client, 1, 100, 1_400);
+        assert_eq!(client.get_oracle_data(&1).consensus_value, 100);
+    }
+
+    #[test]
+    fn test_rejected_challenge_confirms_value_and_slashes_bond() {
+        let env = Env::default();
+        let (client, bond, challenger, admin) = setup_challenge(&env);
+        let evidence = BytesN::from_array(&env, &[1u8; 32]);
+
+        resolve_at(&env, &client, 1, 500, 1_000);
+        client.challenge_resolution(&challenger, &1, &evidence);
+
+        client.resolve_challenge(&admin, &1, &false);
+        assert_eq!(bond.balance(&challenger), 900);
+        assert_eq!(bond.balance(&client.address), 0);
+        assert_eq!(client.get_oracle_data(&1).consensus_value, 500);
+        assert_eq!(
+            client.try_resolve_challenge(&admin, &1, &false),
+            Err(Ok(OracleError::NotFound))
+        );
+    }
+
+    #[test]
+    fn test_challenge_admin_calls_require_admin() {
+        let env = Env::default();
+        let (client, bond, challenger, _) = setup_challenge(&env);
+        let outsider = Address::generate(&env);
+        let evidence = BytesN::from_array(&env, &[1u8; 32]);
+
+        assert_eq!(
+            client.try_set_challenge_config(&outsider, &1, &bond.address, &1),
+            Err(Ok(OracleError::Unauthorized))
+        );
+
+        resolve_at(&env, &client, 1, 500, 1_000);
+        client.challenge_resolution(&challenger, &1, &evidence);
+        assert_eq!(
+            client.try_resolve_challenge(&outsider, &1, &true),
+            Err(Ok(OracleError::Unauthorized))
+        );
+        assert_eq!(client.get_challenge(&1).unwrap().challenger, challenger);
+    }
+
+    #[test]
+    fn test_challenge_window_closes() {
+        let env = Env::default();
+        let (client, bond, challenger, _) = setup_challenge(&env);
+        let evidence = BytesN::from_array(&env, &[1u8; 32]);
+
+        resolve_at(&env, &client, 1, 500, 1_000);
+
+        env.ledger().with_mut(|l| l.timestamp = 1_601);
+        assert_eq!(
+            client.try_challenge_resolution(&challenger, &1, &evidence),
+            Err(Ok(OracleError::ChallengeWindowClosed))
+        );
+        assert_eq!(
+            client.try_challenge_resolution(&challenger, &2, &evidence),
+            Err(Ok(OracleError::NotFound))
+        );
+        assert_eq!(bond.balance(&challenger), 1_000);
+    }
+
+    /// Enable bonding (min 100, 10% slash, 600s cooldown) and stake `count`
+    /// oracles with 1_000 each
+    fn setup_bonding<'a>(
+        env: &'a Env,
+        client: &OracleContractClient,
+        admin: &Address,
+        count: u32,
+    ) -> (token::Client<'a>, Vec<Address>) {
+        let token_admin = Address::generate(env);
+        let bond_token = env.register_stellar_asset_contract_v2(token_admin).address();
+        client.set_bond_config(admin, &bond_token, &100, &1_000, &600, &None);
+
+        let mut oracles = Vec::new(env);
+        for _ in 0..count {
+            let oracle = Address::generate(env);
+            token::StellarAssetClient::new(env, &bond_token).mint(&oracle, &1_000);
+            client.stake_oracle_bond(&oracle, &1_000);
+            client.add_oracle(admin, &oracle);
+            oracles.push_back(oracle);
+        }
+        (token::Client::new(env, &bond_token), oracles)
+    }
+
+    #[test]
+    fn test_unbonded_oracle_submission_does_not_count() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+        let (_, oracles) = setup_bonding(&env, &client, &admin, 2);
+
+        let mut batch = Vec::new(&env);
+        batch.push_back((oracles.get(0).unwrap(), 100, 0));
+        batch.push_back((oracles.get(1).unwrap(), 102, 0));
+        batch.push_back((new_oracle(&env, &client, &admin), 98, 0));
+        assert_eq!(
+            client.try_submit_many_and_resolve(&relayer, &1, &batch),
+            Err(Ok(OracleError::InsufficientSubmissions))
+        );
+    }
+
+    #[test]
+    fn test_bonded_oracle_submission_counts_and_outlier_is_slashed() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+        let (bond, oracles) = setup_bonding(&env, &client, &admin, 4);
+
+        let mut batch = Vec::new(&env);
+        batch.push_back((oracles.get(0).unwrap(), 100, 0));
+        batch.push_back((oracles.get(1).unwrap(), 102, 0));
+        batch.push_back((oracles.get(2).unwrap(), 98, 0));
+        batch.push_back((oracles.get(3).unwrap(), 200, 0));
+        // An unbonded submission is ignored rather than rejected
+        batch.push_back((new_oracle(&env, &client, &admin), 5_000, 0));
+
+        let data = client.submit_many_and_resolve(&relayer, &1, &batch);
+        assert_eq!(data.consensus_value, 100);
+        assert_eq!(data.submission_count, 4);
+        assert_eq!(data.rejected_submissions, 1);
+
+        assert_eq!(client.get_oracle_bond(&oracles.get(0).unwrap()).unwrap().amount, 1_000);
+        assert_eq!(client.get_oracle_bond(&oracles.get(3).unwrap()).unwrap().amount, 900);
+        assert_eq!(bond.balance(&client.address), 3_900);
+    }
+
+    #[test]
+    fn test_upheld_challenge_slashes_bonded_submitters() {
+        let env = Env::default();
+        let (client, _, challenger, admin) = setup_challenge(&env);
+        let relayer = setup_relayer(&env, &client, &admin);
+        let (bond, oracles) = setup_bonding(&env, &client, &admin, 3);
+        let evidence = BytesN::from_array(&env, &[9u8; 32]);
+
+        let mut batch = Vec::new(&env);
+        for oracle in oracles.iter() {
+            batch.push_back((oracle, 500, 0));
+        }
+        client.submit_many_and_resolve(&relayer, &1, &batch);
+
+        client.challenge_resolution(&challenger, &1, &evidence);
+        client.resolve_challenge(&admin, &1, &true);
+
+        for oracle in oracles.iter() {
+            assert_eq!(client.get_oracle_bond(&oracle).unwrap().amount, 900);
+        }
+        assert_eq!(bond.balance(&client.address), 2_700);
+    }
+
+    #[test]
+    fn test_bond_withdrawal_respects_cooldown() {
+        let env = Env::default();
+        let (client, admin) = setup_with_admin(&env);
+        let (bond, oracles) = setup_bonding(&env, &client, &admin, 1);
+        let oracle = oracles.get(0).unwrap();
+
+        assert_eq!(client.try_withdraw_oracle_bond(&oracle), Err(Ok(OracleError::NotBonded)));
+        assert_eq!(
+            client.try_set_bond_config(&oracle, &bond.address, &1, &0, &0, &None),
+            Err(Ok(OracleError::Unauthorized))
+        );
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        client.request_bond_withdrawal(&oracle);
+        assert_eq!(
+            client.try_withdraw_oracle_bond(&oracle),
+            Err(Ok(OracleError::BondCooldownActive))
+        );
+
+        env.ledger().with_mut(|l| l.timestamp = 1_600);
+        assert_eq!(client.withdraw_oracle_bond(&oracle), 1_000);
+        assert_eq!(bond.balance(&oracle), 1_000);
+        assert_eq!(client.get_oracle_bond(&oracle), None);
+    }
+}
@@ -23,6 +23,21 @@ const CONFIDENTIAL_CLAIM: Symbol = symbol_short!("CONF_CLM");
 const PRIVATE_POLICY: Symbol = symbol_short!("PRIV_POL");
 const ZK_PROOF: Symbol = symbol_short!("ZK_PROOF");
 const COMPLIANCE_RECORD: Symbol = symbol_short!("COMP_REC");
+const USER_CLAIMS: Symbol = symbol_short!("USR_CLM");
+const USER_POLICIES: Symbol = symbol_short!("USR_POL");
+const ENTITY_COMPLIANCE: Symbol = symbol_short!("ENT_COMP");
+const COMPLIANCE_AUDITOR: Symbol = symbol_short!("AUDITOR");
+const CIRCUIT: Symbol = symbol_short!("CIRCUIT");
+
+/// Circuits recognized before the registry existed; seeded on initialization
+const DEFAULT_CIRCUITS: [&str; 4] = [
+    "claim_validity",
+    "policy_coverage",
+    "amount_range",
+    "identity_verification",
+];
+/// Key hash of a seeded circuit whose verification key is not pinned yet
+const UNPINNED_KEY: [u8; 32] = [0; 32];
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -40,6 +55,8 @@ pub enum ContractError {
     ProofExpired = 11,
     CircuitNotRecognized = 12,
     ComplianceCheckFailed = 13,
+    RetentionPeriodActive = 14,
+    ActiveProofExists = 15,
 }
 
 fn is_paused(env: &Env) -> bool {
@@ -50,6 +67,18 @@ fn set_paused(env: &Env, paused: bool) {
     env.storage().persistent().set(&PAUSED, &paused);
 }
 
+/// Authenticate `admin` and require it to be the stored admin
+fn require_admin(env: &Env, admin: &Address) -> Result<(), ContractError> {
+    admin.require_auth();
+
+    let stored_admin: Address =
+        env.storage().persistent().get(&ADMIN).ok_or(ContractError::NotInitialized)?;
+    if *admin != stored_admin {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
 fn get_next_proof_id(env: &Env) -> u64 {
     let current: u64 = env.storage().persistent().get(&PROOF_COUNTER).unwrap_or(0);
     env.storage().persistent().set(&PROOF_COUNTER, &(current + 1));
@@ -62,6 +91,40 @@ fn get_next_compliance_id(env: &Env) -> u64 {
     current + 1
 }
 
+fn get_user_entities(env: &Env, prefix: Symbol, user: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(prefix, user.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Index a confidential claim or private policy under its owner
+fn add_user_entity(env: &Env, prefix: Symbol, user: &Address, entity_id: u64) {
+    let mut ids = get_user_entities(env, prefix.clone(), user);
+    ids.push_back(entity_id);
+    env.storage().persistent().set(&(prefix, user.clone()), &ids);
+}
+
+/// Remove a confidential claim or private policy from its owner's index
+fn remove_user_entity(env: &Env, prefix: Symbol, user: &Address, entity_id: u64) {
+    let mut ids = get_user_entities(env, prefix.clone(), user);
+    if let Some(index) = ids.first_index_of(entity_id) {
+        ids.remove(index);
+        env.storage().persistent().set(&(prefix, user.clone()), &ids);
+    }
+}
+
+/// Register any default circuit that is missing, leaving its key unpinned
+fn seed_default_circuits(env: &Env) {
+    let unpinned = BytesN::from_array(env, &UNPINNED_KEY);
+    for name in DEFAULT_CIRCUITS.iter() {
+        let key = (CIRCUIT, Symbol::new(env, name));
+        if !env.storage().persistent().has(&key) {
+            env.storage().persistent().set(&key, &unpinned);
+        }
+    }
+}
+
 /// Verify a ZK proof (simulated - in production this would use actual ZKP verification)
 fn verify_zk_proof(env: &Env, proof: &ZkProof) -> ZkVerificationResult {
     // Check if proof has expired
@@ -82,20 +145,18 @@ fn verify_zk_proof(env: &Env, proof: &ZkProof) -> ZkVerificationResult {
         return ZkVerificationResult::Invalid;
     }
 
-    // Check circuit ID is recognized (simulated)
-    let circuit_id = proof.circuit_id;
-    let valid_circuits = [
-        Symbol::new(env, "claim_validity"),
-        Symbol::new(env, "policy_coverage"),
-        Symbol::new(env, "amount_range"),
-        Symbol::new(env, "identity_verification"),
-    ];
-    
-    if !valid_circuits.contains(&circuit_id) {
-        return ZkVerificationResult::UnknownCircuit;
+    // Check circuit ID is registered and the proof targets its verification key;
+    // an unpinned default circuit accepts any key, as before the registry
+    let verification_key: Option<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&(CIRCUIT, proof.circuit_id.clone()));
+    match verification_key {
+        None => ZkVerificationResult::UnknownCircuit,
+        Some(key) if key.to_array() == UNPINNED_KEY => ZkVerificationResult::Valid,
+        Some(key) if key != proof.vk_hash => ZkVerificationResult::Invalid,
+        Some(_) => ZkVerificationResult::Valid,
     }
-
-    ZkVerificationResult::Valid
 }
 
 #[contractimpl]
@@ -110,6 +171,7 @@ impl PrivacyContract {
         env.storage().persistent().set(&ADMIN, &admin);
         env.storage().persistent().set(&PROOF_COUNTER, &0u64);
         env.storage().persistent().set(&COMPLIANCE_COUNTER, &0u64);
+        seed_default_circuits(&env);
 
         env.events().publish((symbol_short!("init"), ()), admin);
 
@@ -159,6 +221,49 @@ impl PrivacyContract {
         Ok(())
     }
 
+    /// Rotate a user's encryption key.
+    ///
+    /// Existing confidential claims and private policies stay encrypted under
+    /// the old key; the `key_rotated` event lists them so the client can
+    /// re-wrap and resubmit their commitments.
+    pub fn rotate_encryption_key(
+        env: Env,
+        user: Address,
+        new_key: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        user.require_auth();
+
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        let mut settings: PrivacySettings = env
+            .storage()
+            .persistent()
+            .get(&(USER_PRIVACY, user.clone()))
+            .ok_or(ContractError::NotFound)?;
+
+        if settings.encryption_key.as_ref() == Some(&new_key) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        settings.encryption_key = Some(new_key.clone());
+        settings.updated_at = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&(USER_PRIVACY, user.clone()), &settings);
+
+        let claim_ids = get_user_entities(&env, USER_CLAIMS, &user);
+        let policy_ids = get_user_entities(&env, USER_POLICIES, &user);
+
+        env.events().publish(
+            (Symbol::new(&env, "key_rotated"), user),
+            (new_key, claim_ids, policy_ids),
+        );
+
+        Ok(())
+    }
+
     /// Submit a confidential claim
     pub fn submit_confidential_claim(
         env: Env,
@@ -205,9 +310,10 @@ impl PrivacyContract {
             &(CONFIDENTIAL_CLAIM, claim_id),
             &confidential_claim,
         );
+        add_user_entity(&env, USER_CLAIMS, &claimant, claim_id);
 
         env.events().publish(
-            (symbol_short!("conf_claim"), claim_id),
+            (Symbol::new(&env, "conf_claim"), claim_id),
             (claimant, policy_id, privacy_level),
         );
 
@@ -257,7 +363,7 @@ impl PrivacyContract {
             .set(&(ZK_PROOF, proof_id.clone()), &privacy_proof);
 
         // Update claim with proof ID
-        claim.validity_proof_id = proof_id;
+        claim.validity_proof_id = proof_id.clone();
         env.storage().persistent().set(
             &(CONFIDENTIAL_CLAIM, claim_id),
             &claim,
@@ -271,6 +377,64 @@ impl PrivacyContract {
         Ok(())
     }
 
+    /// Delete a confidential claim once the claimant's `retention_days` have
+    /// passed since submission. Callable by the claimant or admin; claims whose
+    /// attached proof still verifies are kept.
+    pub fn purge_expired_claim(
+        env: Env,
+        caller: Address,
+        claim_id: u64,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        let claim: ConfidentialClaim = env
+            .storage()
+            .persistent()
+            .get(&(CONFIDENTIAL_CLAIM, claim_id))
+            .ok_or(ContractError::NotFound)?;
+
+        if caller != claim.claimant {
+            let admin: Address =
+                env.storage().persistent().get(&ADMIN).ok_or(ContractError::NotInitialized)?;
+            if caller != admin {
+                return Err(ContractError::Unauthorized);
+            }
+        }
+
+        // Without settings there is no retention period to enforce
+        let settings: PrivacySettings = env
+            .storage()
+            .persistent()
+            .get(&(USER_PRIVACY, claim.claimant.clone()))
+            .ok_or(ContractError::InvalidState)?;
+
+        let retention_ends = claim
+            .submitted_at
+            .saturating_add(settings.retention_days as u64 * 86400);
+        if retention_ends >= env.ledger().timestamp() {
+            return Err(ContractError::RetentionPeriodActive);
+        }
+
+        let proof_key = (ZK_PROOF, claim.validity_proof_id.clone());
+        let proof: Option<PrivacyProof> = env.storage().persistent().get(&proof_key);
+        if let Some(proof) = proof {
+            if verify_zk_proof(&env, &proof.zk_proof) == ZkVerificationResult::Valid {
+                return Err(ContractError::ActiveProofExists);
+            }
+            env.storage().persistent().remove(&proof_key);
+        }
+
+        env.storage().persistent().remove(&(CONFIDENTIAL_CLAIM, claim_id));
+        remove_user_entity(&env, USER_CLAIMS, &claim.claimant, claim_id);
+
+        env.events().publish(
+            (Symbol::new(&env, "claim_purged"), claim_id),
+            (claim.claimant, caller),
+        );
+
+        Ok(())
+    }
+
     /// Create a private policy
     pub fn create_private_policy(
         env: Env,
@@ -301,6 +465,7 @@ impl PrivacyContract {
             &(PRIVATE_POLICY, policy_id),
             &private_policy,
         );
+        add_user_entity(&env, USER_POLICIES, &holder, policy_id);
 
         env.events().publish(
             (symbol_short!("priv_pol"), policy_id),
@@ -353,7 +518,7 @@ impl PrivacyContract {
             .set(&(ZK_PROOF, proof_id.clone()), &privacy_proof);
 
         // Update policy with proof ID
-        policy.policy_proof_id = proof_id;
+        policy.policy_proof_id = proof_id.clone();
         env.storage().persistent().set(
             &(PRIVATE_POLICY, policy_id),
             &policy,
@@ -381,11 +546,8 @@ impl PrivacyContract {
 
         // Verify auditor is authorized (admin or designated auditor)
         let admin: Address = env.storage().persistent().get(&ADMIN).ok_or(ContractError::NotInitialized)?;
-        
-        // In production, check against a list of authorized auditors
-        if auditor != admin {
-            // For now, only admin can record compliance
-            // return Err(ContractError::Unauthorized);
+        if auditor != admin && !Self::is_compliance_auditor(env.clone(), auditor.clone()) {
+            return Err(ContractError::Unauthorized);
         }
 
         let record_id = get_next_compliance_id(&env);
@@ -406,20 +568,112 @@ impl PrivacyContract {
             &compliance_record,
         );
 
+        let index_key = (ENTITY_COMPLIANCE, compliance_record.entity_type.clone(), entity_id);
+        let mut record_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&index_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        record_ids.push_back(record_id);
+        env.storage().persistent().set(&index_key, &record_ids);
+
         env.events().publish(
-            (symbol_short!("compliance"), record_id),
+            (Symbol::new(&env, "compliance"), record_id),
             (entity_id, is_compliant),
         );
 
         Ok(record_id)
     }
 
+    /// Allow an address to record compliance checks (admin only)
+    pub fn add_compliance_auditor(
+        env: Env,
+        admin: Address,
+        auditor: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &admin)?;
+
+        env.storage()
+            .persistent()
+            .set(&(COMPLIANCE_AUDITOR, auditor.clone()), &true);
+
+        env.events().publish((symbol_short!("aud_add"), auditor), admin);
+
+        Ok(())
+    }
+
+    /// Stop an address from recording compliance checks (admin only)
+    pub fn remove_compliance_auditor(
+        env: Env,
+        admin: Address,
+        auditor: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &admin)?;
+
+        env.storage()
+            .persistent()
+            .remove(&(COMPLIANCE_AUDITOR, auditor.clone()));
+
+        env.events().publish((symbol_short!("aud_rm"), auditor), admin);
+
+        Ok(())
+    }
+
+    /// Register a verification circuit and the hash of its verification key
+    /// (admin only). Re-registering replaces the key.
+    pub fn register_circuit(
+        env: Env,
+        admin: Address,
+        circuit_id: Symbol,
+        verification_key: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &admin)?;
+
+        env.storage()
+            .persistent()
+            .set(&(CIRCUIT, circuit_id.clone()), &verification_key);
+
+        env.events().publish(
+            (symbol_short!("circ_reg"), circuit_id),
+            verification_key,
+        );
+
+        Ok(())
+    }
+
+    /// Remove a verification circuit (admin only); its proofs stop verifying
+    pub fn deregister_circuit(
+        env: Env,
+        admin: Address,
+        circuit_id: Symbol,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &admin)?;
+
+        if !env.storage().persistent().has(&(CIRCUIT, circuit_id.clone())) {
+            return Err(ContractError::CircuitNotRecognized);
+        }
+        env.storage().persistent().remove(&(CIRCUIT, circuit_id.clone()));
+
+        env.events().publish((symbol_short!("circ_drg"), circuit_id), admin);
+
+        Ok(())
+    }
+
+    /// Seed the default circuits on a contract initialized before the circuit
+    /// registry existed (admin only). Circuits already registered or pinned
+    /// are left alone.
+    pub fn migrate_default_circuits(env: Env, admin: Address) -> Result<(), ContractError> {
+        require_admin(&env, &admin)?;
+        seed_default_circuits(&env);
+        Ok(())
+    }
+
     /// Verify a ZK proof (public verification)
     pub fn verify_proof(env: Env, proof_id: BytesN<32>) -> Result<ZkVerificationResult, ContractError> {
         let privacy_proof: PrivacyProof = env
             .storage()
             .persistent()
-            .get(&(ZK_PROOF, proof_id))
+            .get(&(ZK_PROOF, proof_id.clone()))
             .ok_or(ContractError::NotFound)?;
 
         // Re-verify the proof
@@ -435,12 +689,7 @@ impl PrivacyContract {
 
     /// Pause/unpause contract (admin only)
     pub fn set_paused(env: Env, admin: Address, paused: bool) -> Result<(), ContractError> {
-        admin.require_auth();
-
-        let stored_admin: Address = env.storage().persistent().get(&ADMIN).ok_or(ContractError::NotInitialized)?;
-        if admin != stored_admin {
-            return Err(ContractError::Unauthorized);
-        }
+        require_admin(&env, &admin)?;
 
         set_paused(&env, paused);
 
@@ -464,6 +713,16 @@ impl PrivacyContract {
         env.storage().persistent().get(&(CONFIDENTIAL_CLAIM, claim_id))
     }
 
+    /// Get IDs of confidential claims submitted by a user
+    pub fn get_user_claims(env: Env, user: Address) -> Vec<u64> {
+        get_user_entities(&env, USER_CLAIMS, &user)
+    }
+
+    /// Get IDs of private policies held by a user
+    pub fn get_user_policies(env: Env, user: Address) -> Vec<u64> {
+        get_user_entities(&env, USER_POLICIES, &user)
+    }
+
     /// Get private policy
     pub fn get_private_policy(env: Env, policy_id: u64) -> Option<PrivatePolicyData> {
         env.storage().persistent().get(&(PRIVATE_POLICY, policy_id))
@@ -479,6 +738,19 @@ impl PrivacyContract {
         env.storage().persistent().get(&(COMPLIANCE_RECORD, record_id))
     }
 
+    /// Get the verification key hash of a registered circuit
+    pub fn get_circuit(env: Env, circuit_id: Symbol) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&(CIRCUIT, circuit_id))
+    }
+
+    /// Check if an address is on the compliance auditor allowlist
+    pub fn is_compliance_auditor(env: Env, auditor: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(COMPLIANCE_AUDITOR, auditor))
+            .unwrap_or(false)
+    }
+
     /// Check if privacy is enabled for a user
     pub fn is_privacy_enabled(env: Env, user: Address) -> bool {
         if let Some(settings) = Self::get_privacy_settings(env, user) {
@@ -488,14 +760,26 @@ impl PrivacyContract {
         }
     }
 
-    /// Get all compliance records for an entity
+    /// Get all compliance records for an entity, oldest first
     pub fn get_entity_compliance(
         env: Env,
         entity_type: Symbol,
         entity_id: u64,
     ) -> Vec<ComplianceRecord> {
-        // In production, maintain an index for efficient querying
-        // For now, return empty vector
-        Vec::new(&env)
+        let record_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&(ENTITY_COMPLIANCE, entity_type, entity_id))
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut records = Vec::new(&env);
+        for record_id in record_ids.iter() {
+            if let Some(record) = env.storage().persistent().get(&(COMPLIANCE_RECORD, record_id)) {
+                records.push_back(record);
+            }
+        }
+        records
     }
 }
+
+mod test;
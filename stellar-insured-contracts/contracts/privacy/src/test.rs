@@ -0,0 +1,320 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{vec, xdr, IntoVal, TryFromVal, Val};
+
+/// Last emitted event as `(contract, topics, data)`
+fn last_event(env: &Env) -> (Address, Vec<Val>, Val) {
+    let event = env.events().all().events().last().unwrap().clone();
+    let xdr::ContractEventBody::V0(body) = event.body;
+    let contract =
+        Address::try_from_val(env, &xdr::ScAddress::Contract(event.contract_id.unwrap())).unwrap();
+    let mut topics = Vec::new(env);
+    for topic in body.topics.iter() {
+        topics.push_back(Val::try_from_val(env, topic).unwrap());
+    }
+    (contract, topics, Val::try_from_val(env, &body.data).unwrap())
+}
+
+fn setup_with_admin(env: &Env) -> (PrivacyContractClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let admin = Address::generate(env);
+
+    let contract_id = env.register_contract(None, PrivacyContract);
+    let client = PrivacyContractClient::new(env, &contract_id);
+    client.initialize(&admin);
+
+    let user = Address::generate(env);
+    client.set_privacy_settings(
+        &user,
+        &true,
+        &2,
+        &Some(BytesN::from_array(env, &[1; 32])),
+        &365,
+        &true,
+    );
+
+    (client, admin, user)
+}
+
+fn setup(env: &Env) -> (PrivacyContractClient<'_>, Address) {
+    let (client, _, user) = setup_with_admin(env);
+    (client, user)
+}
+
+#[test]
+fn test_rotate_encryption_key() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    let blob = BytesN::from_array(&env, &[7; 32]);
+    let claim_id = client.submit_confidential_claim(&user, &1, &blob, &blob, &blob, &2);
+    let policy_id = client.create_private_policy(&user, &blob, &blob, &blob);
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let new_key = BytesN::from_array(&env, &[2; 32]);
+    client.rotate_encryption_key(&user, &new_key);
+    let (_, topics, data) = last_event(&env);
+
+    let settings = client.get_privacy_settings(&user).unwrap();
+    assert_eq!(settings.encryption_key, Some(new_key.clone()));
+    assert_eq!(settings.updated_at, 10_000);
+
+    assert_eq!(
+        topics,
+        vec![&env, Symbol::new(&env, "key_rotated").into_val(&env), user.into_val(&env)]
+    );
+    let (key, claim_ids, policy_ids): (BytesN<32>, Vec<u64>, Vec<u64>) = data.into_val(&env);
+    assert_eq!(key, new_key);
+    assert_eq!(claim_ids, vec![&env, claim_id]);
+    assert_eq!(policy_ids, vec![&env, policy_id]);
+}
+
+#[test]
+fn test_rotate_encryption_key_rejects_same_key_and_unknown_user() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+
+    let current = BytesN::from_array(&env, &[1; 32]);
+    assert_eq!(
+        client.try_rotate_encryption_key(&user, &current),
+        Err(Ok(ContractError::InvalidInput))
+    );
+
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_rotate_encryption_key(&stranger, &current),
+        Err(Ok(ContractError::NotFound))
+    );
+}
+
+const DAY: u64 = 86400;
+
+fn short_retention_claim(env: &Env, client: &PrivacyContractClient, user: &Address) -> u64 {
+    client.set_privacy_settings(user, &true, &2, &None, &1, &true);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let blob = BytesN::from_array(env, &[7; 32]);
+    client.submit_confidential_claim(user, &1, &blob, &blob, &blob, &2)
+}
+
+#[test]
+fn test_purge_expired_claim_after_retention() {
+    let env = Env::default();
+    let (client, user) = setup(&env);
+    let claim_id = short_retention_claim(&env, &client, &user);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + DAY);
+    assert_eq!(
+        client.try_purge_expired_claim(&user, &claim_id),
+        Err(Ok(ContractError::RetentionPeriodActive))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + DAY + 1);
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_purge_expired_claim(&stranger, &claim_id),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    client.purge_expired_claim(&user, &claim_id);
+    let (_, topics, _) = last_event(&env);
+    assert_eq!(client.get_confidential_claim(&claim_id), None);
+    assert_eq!(client.get_user_claims(&user).len(), 0);
+
+    assert_eq!(
+        topics,
+        vec![&env, Symbol::new(&env, "claim_purged").into_val(&env), claim_id.into_val(&env)]
+    );
+    assert_eq!(
+        client.try_purge_expired_claim(&user, &claim_id),
+        Err(Ok(ContractError::NotFound))
+    );
+}
+
+#[test]
+fn test_purge_expired_claim_keeps_claims_with_active_proofs() {
+    let env = Env::default();
+    let (client, admin, user) = setup_with_admin(&env);
+    let claim_id = short_retention_claim(&env, &client, &user);
+    let circuit_id = Symbol::new(&env, "claim_validity");
+    client.register_circuit(&admin, &circuit_id, &BytesN::from_array(&env, &[3; 32]));
+
+    let proof_id = BytesN::from_array(&env, &[9; 32]);
+    client.attach_claim_proof(
+        &user,
+        &claim_id,
+        &ZkProof {
+            proof_id: proof_id.clone(),
+            circuit_id,
+            public_inputs: vec![&env, 1],
+            vk_hash: BytesN::from_array(&env, &[3; 32]),
+            created_at: 1_000,
+            expires_at: Some(1_000 + 2 * DAY),
+        },
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + DAY + 1);
+    assert_eq!(
+        client.try_purge_expired_claim(&user, &claim_id),
+        Err(Ok(ContractError::ActiveProofExists))
+    );
+
+    // Once the proof expires the claim and its proof are purged together
+    env.ledger().with_mut(|li| li.timestamp = 1_000 + 2 * DAY + 1);
+    client.purge_expired_claim(&user, &claim_id);
+    assert_eq!(client.get_confidential_claim(&claim_id), None);
+    assert_eq!(client.get_zk_proof(&proof_id), None);
+}
+
+#[test]
+fn test_get_entity_compliance_returns_records_in_order() {
+    let env = Env::default();
+    let (client, admin, user) = setup_with_admin(&env);
+    client.add_compliance_auditor(&admin, &user);
+    let claim = Symbol::new(&env, "claim");
+    let checks = [
+        (Symbol::new(&env, "kyc"), true),
+        (Symbol::new(&env, "aml"), false),
+        (Symbol::new(&env, "sanctions"), true),
+    ];
+
+    let mut record_ids = Vec::new(&env);
+    for (check_type, is_compliant) in checks.iter() {
+        record_ids.push_back(
+            client.record_compliance_check(&user, &claim, &7, check_type, is_compliant, &None),
+        );
+    }
+    // Checks on other entities stay out of the history
+    client.record_compliance_check(&user, &claim, &8, &checks[0].0, &true, &None);
+    client.record_compliance_check(
+        &user,
+        &Symbol::new(&env, "policy"),
+        &7,
+        &checks[0].0,
+        &true,
+        &None,
+    );
+
+    let history = client.get_entity_compliance(&claim, &7);
+    assert_eq!(history.len(), 3);
+    for (i, (check_type, is_compliant)) in checks.iter().enumerate() {
+        let record = history.get(i as u32).unwrap();
+        assert_eq!(record.record_id, record_ids.get(i as u32).unwrap());
+        assert_eq!(&record.check_type, check_type);
+        assert_eq!(record.is_compliant, *is_compliant);
+    }
+    assert_eq!(client.get_entity_compliance(&claim, &99).len(), 0);
+}
+
+#[test]
+fn test_record_compliance_check_requires_authorized_auditor() {
+    let env = Env::default();
+    let (client, admin, _) = setup_with_admin(&env);
+    let claim = Symbol::new(&env, "claim");
+    let kyc = Symbol::new(&env, "kyc");
+    let auditor = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.record_compliance_check(&admin, &claim, &1, &kyc, &true, &None);
+
+    assert_eq!(
+        client.try_add_compliance_auditor(&stranger, &auditor),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    client.add_compliance_auditor(&admin, &auditor);
+    assert!(client.is_compliance_auditor(&auditor));
+    client.record_compliance_check(&auditor, &claim, &1, &kyc, &true, &None);
+
+    assert_eq!(
+        client.try_record_compliance_check(&stranger, &claim, &1, &kyc, &true, &None),
+        Err(Ok(ContractError::Unauthorized))
+    );
+
+    client.remove_compliance_auditor(&admin, &auditor);
+    assert_eq!(
+        client.try_record_compliance_check(&auditor, &claim, &1, &kyc, &true, &None),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    assert_eq!(client.get_entity_compliance(&claim, &1).len(), 2);
+}
+
+fn test_proof(env: &Env, id: u8, circuit_id: &Symbol, vk_hash: &BytesN<32>) -> ZkProof {
+    ZkProof {
+        proof_id: BytesN::from_array(env, &[id; 32]),
+        circuit_id: circuit_id.clone(),
+        public_inputs: vec![env, 42],
+        vk_hash: vk_hash.clone(),
+        created_at: 0,
+        expires_at: None,
+    }
+}
+
+#[test]
+fn test_circuit_registry_controls_verification() {
+    let env = Env::default();
+    let (client, admin, user) = setup_with_admin(&env);
+    let blob = BytesN::from_array(&env, &[7; 32]);
+    let claim_id = client.submit_confidential_claim(&user, &1, &blob, &blob, &blob, &2);
+
+    let circuit_id = Symbol::new(&env, "solvency");
+    let vk_hash = BytesN::from_array(&env, &[4; 32]);
+    let proof = test_proof(&env, 1, &circuit_id, &vk_hash);
+
+    // Unregistered circuits are rejected
+    assert_eq!(
+        client.try_attach_claim_proof(&user, &claim_id, &proof),
+        Err(Ok(ContractError::ProofInvalid))
+    );
+
+    assert_eq!(
+        client.try_register_circuit(&user, &circuit_id, &vk_hash),
+        Err(Ok(ContractError::Unauthorized))
+    );
+    client.register_circuit(&admin, &circuit_id, &vk_hash);
+    assert_eq!(client.get_circuit(&circuit_id), Some(vk_hash.clone()));
+
+    // A proof made for a different verification key does not verify
+    let wrong_key = test_proof(&env, 2, &circuit_id, &BytesN::from_array(&env, &[5; 32]));
+    assert_eq!(
+        client.try_attach_claim_proof(&user, &claim_id, &wrong_key),
+        Err(Ok(ContractError::ProofInvalid))
+    );
+
+    client.attach_claim_proof(&user, &claim_id, &proof);
+    assert_eq!(client.verify_proof(&proof.proof_id), ZkVerificationResult::Valid);
+
+    client.deregister_circuit(&admin, &circuit_id);
+    assert_eq!(client.get_circuit(&circuit_id), None);
+    assert_eq!(client.verify_proof(&proof.proof_id), ZkVerificationResult::UnknownCircuit);
+    assert_eq!(
+        client.try_deregister_circuit(&admin, &circuit_id),
+        Err(Ok(ContractError::CircuitNotRecognized))
+    );
+}
+
+#[test]
+fn test_default_circuits_are_seeded() {
+    let env = Env::default();
+    let (client, admin, user) = setup_with_admin(&env);
+    let blob = BytesN::from_array(&env, &[7; 32]);
+    let claim_id = client.submit_confidential_claim(&user, &1, &blob, &blob, &blob, &2);
+
+    // Default circuits verify without a pinned key
+    let circuit_id = Symbol::new(&env, "amount_range");
+    assert!(client.get_circuit(&circuit_id).is_some());
+    let proof = test_proof(&env, 1, &circuit_id, &BytesN::from_array(&env, &[9; 32]));
+    client.attach_claim_proof(&user, &claim_id, &proof);
+    assert_eq!(client.verify_proof(&proof.proof_id), ZkVerificationResult::Valid);
+
+    // Pinning a key makes the circuit check it
+    let vk_hash = BytesN::from_array(&env, &[4; 32]);
+    client.register_circuit(&admin, &circuit_id, &vk_hash);
+    assert_eq!(client.verify_proof(&proof.proof_id), ZkVerificationResult::Invalid);
+
+    // The migration restores a missing default circuit but keeps pinned keys
+    client.deregister_circuit(&admin, &Symbol::new(&env, "claim_validity"));
+    client.migrate_default_circuits(&admin);
+    assert!(client.get_circuit(&Symbol::new(&env, "claim_validity")).is_some());
+    assert_eq!(client.get_circuit(&circuit_id), Some(vk_hash));
+}
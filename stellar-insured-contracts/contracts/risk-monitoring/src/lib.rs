@@ -1,10 +1,25 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec,
-    String, Map,
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, Address,
+    Env, IntoVal, Symbol, Val, Vec, String, Map,
 };
 
+/// Consecutive failed executions after which a trigger disables itself
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Upper bound on the number of sensors returned by `get_noisiest_sensors`
+pub const MAX_NOISIEST_SENSORS: u32 = 50;
+
+/// Alerts retained in `AlertHistory` when `initialize` is used
+pub const DEFAULT_MAX_ALERT_HISTORY: u32 = 500;
+
+/// Upper bound on the page size of `get_alert_history_paginated`
+pub const MAX_ALERT_PAGE_SIZE: u32 = 100;
+
+/// Oldest oracle consensus, in seconds, `check_risk_from_oracle` accepts by default
+pub const DEFAULT_ORACLE_MAX_AGE: u64 = 3600;
+
 // ============================================================================
 // Error Handling
 // ============================================================================
@@ -20,6 +35,12 @@ pub enum RiskError {
     NotInitialized = 6,
     ThresholdExceeded = 7,
     SensorInactive = 8,
+    /// The sensor has no oracle, or the oracle has no readable value for it
+    OracleUnavailable = 9,
+    /// The oracle value was invalidated after finalization
+    OracleDataInvalid = 10,
+    /// The oracle value was finalized longer ago than the allowed age
+    OracleDataStale = 11,
 }
 
 // ============================================================================
@@ -45,6 +66,8 @@ pub struct RiskSensor {
     pub threshold_low: i128,
     pub threshold_high: i128,
     pub is_active: bool,
+    /// Oracle contract the sensor's value is read from, as the consensus
+    /// value for `data_id` (see `OracleInterface`)
     pub source_contract: Option<Address>,
     pub data_id: u64, // Used for oracle data_id or internal metric ID
 }
@@ -65,6 +88,10 @@ pub struct MitigationTrigger {
     pub action: MitigationAction,
     pub target_contract: Address,
     pub is_enabled: bool,
+    pub max_retries: u32,
+    pub failure_count: u32,
+    /// Overrides the method called on the target; custom methods take no arguments
+    pub method: Option<Symbol>,
 }
 
 #[contracttype]
@@ -77,6 +104,31 @@ pub struct RiskAlert {
     pub timestamp: u64,
 }
 
+/// Finalized value as returned by the oracle contract's `get_oracle_data`.
+/// Mirrors the oracle's `OracleData` field for field.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleData {
+    pub data_id: u64,
+    pub consensus_value: i128,
+    pub submission_count: u32,
+    pub consensus_percentage: u32,
+    pub finalized_at: u64,
+    pub included_submissions: u32,
+    pub rejected_submissions: u32,
+    pub mean_abs_deviation: i128,
+    pub confidence: u32,
+    pub is_valid: bool,
+    pub admin_override: bool,
+    pub override_justification: Option<Symbol>,
+}
+
+/// The part of the oracle contract's interface sensors read from
+#[contractclient(name = "OracleClient")]
+pub trait OracleInterface {
+    fn get_oracle_data(env: Env, data_id: u64) -> OracleData;
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub enum DataKey {
@@ -86,6 +138,10 @@ pub enum DataKey {
     Triggers,
     NextSensorId,
     AlertHistory,
+    MitigationCooldown(u64),
+    LastTriggeredAt(u64),
+    OracleMaxAge,
+    MaxAlertHistory,
 }
 
 // ============================================================================
@@ -99,13 +155,27 @@ pub struct RiskMonitoringContract;
 impl RiskMonitoringContract {
     /// Initialize the risk monitoring contract
     pub fn initialize(env: Env, admin: Address) -> Result<(), RiskError> {
+        Self::initialize_with_history_limit(env, admin, DEFAULT_MAX_ALERT_HISTORY)
+    }
+
+    /// Initialize, keeping only the most recent `max_alert_history` alerts
+    pub fn initialize_with_history_limit(
+        env: Env,
+        admin: Address,
+        max_alert_history: u32,
+    ) -> Result<(), RiskError> {
         if env.storage().persistent().has(&DataKey::Admin) {
             return Err(RiskError::AlreadyInitialized);
         }
 
+        if max_alert_history == 0 {
+            return Err(RiskError::InvalidInput);
+        }
+
         admin.require_auth();
 
         env.storage().persistent().set(&DataKey::Admin, &admin);
+        env.storage().persistent().set(&DataKey::MaxAlertHistory, &max_alert_history);
         env.storage().persistent().set(&DataKey::Paused, &false);
         env.storage().persistent().set(&DataKey::NextSensorId, &1u64);
 
@@ -185,6 +255,54 @@ impl RiskMonitoringContract {
         Ok(())
     }
 
+    /// Enable or disable a sensor; inactive sensors reject `check_risk`
+    pub fn set_sensor_active(env: Env, sensor_id: u64, active: bool) -> Result<(), RiskError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        let mut sensors: Map<u64, RiskSensor> = env.storage().persistent().get(&DataKey::Sensors).unwrap();
+        let mut sensor = sensors.get(sensor_id).ok_or(RiskError::NotFound)?;
+
+        sensor.is_active = active;
+
+        sensors.set(sensor_id, sensor);
+        env.storage().persistent().set(&DataKey::Sensors, &sensors);
+
+        env.events().publish(
+            (symbol_short!("sensor"), symbol_short!("active")),
+            (sensor_id, active)
+        );
+
+        Ok(())
+    }
+
+    /// Delete a sensor along with its mitigation triggers and cooldown state
+    pub fn remove_sensor(env: Env, sensor_id: u64) -> Result<(), RiskError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        let mut sensors: Map<u64, RiskSensor> = env.storage().persistent().get(&DataKey::Sensors).unwrap();
+        if !sensors.contains_key(sensor_id) {
+            return Err(RiskError::NotFound);
+        }
+        sensors.remove(sensor_id);
+        env.storage().persistent().set(&DataKey::Sensors, &sensors);
+
+        let mut triggers: Map<u64, Vec<MitigationTrigger>> = env.storage().persistent().get(&DataKey::Triggers).unwrap();
+        triggers.remove(sensor_id);
+        env.storage().persistent().set(&DataKey::Triggers, &triggers);
+
+        env.storage().persistent().remove(&DataKey::MitigationCooldown(sensor_id));
+        env.storage().persistent().remove(&DataKey::LastTriggeredAt(sensor_id));
+
+        env.events().publish(
+            (symbol_short!("sensor"), symbol_short!("removed")),
+            sensor_id
+        );
+
+        Ok(())
+    }
+
     /// Configure an automated mitigation trigger
     pub fn add_mitigation_trigger(
         env: Env,
@@ -208,6 +326,9 @@ impl RiskMonitoringContract {
             action,
             target_contract,
             is_enabled: true,
+            max_retries: DEFAULT_MAX_RETRIES,
+            failure_count: 0,
+            method: None,
         });
 
         triggers.set(sensor_id, sensor_triggers);
@@ -216,10 +337,100 @@ impl RiskMonitoringContract {
         Ok(())
     }
 
+    /// Set how many consecutive failures a trigger tolerates before disabling itself
+    pub fn set_trigger_max_retries(
+        env: Env,
+        sensor_id: u64,
+        trigger_index: u32,
+        max_retries: u32,
+    ) -> Result<(), RiskError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        if max_retries == 0 {
+            return Err(RiskError::InvalidInput);
+        }
+
+        let mut triggers: Map<u64, Vec<MitigationTrigger>> = env.storage().persistent().get(&DataKey::Triggers).unwrap();
+        let mut sensor_triggers = triggers.get(sensor_id).ok_or(RiskError::NotFound)?;
+        let mut trigger = sensor_triggers.get(trigger_index).ok_or(RiskError::NotFound)?;
+
+        trigger.max_retries = max_retries;
+        trigger.failure_count = 0;
+        trigger.is_enabled = true;
+
+        sensor_triggers.set(trigger_index, trigger);
+        triggers.set(sensor_id, sensor_triggers);
+        env.storage().persistent().set(&DataKey::Triggers, &triggers);
+
+        Ok(())
+    }
+
+    /// Set the target method a trigger calls, e.g. `pause` instead of the
+    /// default `set_paused(true)`. `None` restores the action's default.
+    pub fn set_trigger_method(
+        env: Env,
+        sensor_id: u64,
+        trigger_index: u32,
+        method: Option<Symbol>,
+    ) -> Result<(), RiskError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        let mut triggers: Map<u64, Vec<MitigationTrigger>> = env.storage().persistent().get(&DataKey::Triggers).unwrap();
+        let mut sensor_triggers = triggers.get(sensor_id).ok_or(RiskError::NotFound)?;
+        let mut trigger = sensor_triggers.get(trigger_index).ok_or(RiskError::NotFound)?;
+
+        trigger.method = method;
+
+        sensor_triggers.set(trigger_index, trigger);
+        triggers.set(sensor_id, sensor_triggers);
+        env.storage().persistent().set(&DataKey::Triggers, &triggers);
+
+        Ok(())
+    }
+
+    /// Set how long after a mitigation fires further violations of the sensor
+    /// only record alerts. 0 disables the cooldown.
+    pub fn set_mitigation_cooldown(
+        env: Env,
+        sensor_id: u64,
+        cooldown_seconds: u64,
+    ) -> Result<(), RiskError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        let sensors: Map<u64, RiskSensor> = env.storage().persistent().get(&DataKey::Sensors).unwrap();
+        if !sensors.contains_key(sensor_id) {
+            return Err(RiskError::NotFound);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::MitigationCooldown(sensor_id), &cooldown_seconds);
+
+        Ok(())
+    }
+
+    /// Timestamp of the last mitigation fired for a sensor
+    pub fn get_last_triggered_at(env: Env, sensor_id: u64) -> Option<u64> {
+        env.storage().persistent().get(&DataKey::LastTriggeredAt(sensor_id))
+    }
+
     /// Perform a real-time risk check for a specific sensor
-    /// Integration point for external risk data
+    /// Integration point for external risk data. The value is taken on trust
+    /// and can fire mitigations, so only the admin may report it.
     pub fn check_risk(env: Env, sensor_id: u64, current_value: i128) -> Result<bool, RiskError> {
-        if Self::is_paused(&env) {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        Self::evaluate_risk(&env, sensor_id, current_value)
+    }
+
+    /// Compare a sensor's current value with its thresholds, recording an
+    /// alert and firing mitigations on a violation
+    fn evaluate_risk(env: &Env, sensor_id: u64, current_value: i128) -> Result<bool, RiskError> {
+        if Self::is_paused(env) {
             return Err(RiskError::Paused);
         }
 
@@ -251,8 +462,13 @@ impl RiskMonitoringContract {
                 timestamp: env.ledger().timestamp(),
             };
 
+            // Evict the oldest alerts beyond the configured cap
+            let max_alerts = Self::get_max_alert_history(env.clone());
             let mut history: Vec<RiskAlert> = env.storage().persistent().get(&DataKey::AlertHistory).unwrap();
             history.push_back(alert.clone());
+            while history.len() > max_alerts {
+                history.pop_front();
+            }
             env.storage().persistent().set(&DataKey::AlertHistory, &history);
 
             // Emit Alert Event (Notification System)
@@ -261,64 +477,214 @@ impl RiskMonitoringContract {
                 alert
             );
 
-            // Trigger Automated Mitigation
-            Self::trigger_mitigation(&env, sensor_id)?;
+            // Trigger Automated Mitigation, unless still cooling down from the last one
+            let now = env.ledger().timestamp();
+            let cooldown: u64 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::MitigationCooldown(sensor_id))
+                .unwrap_or(0);
+            let cooling_down = match Self::get_last_triggered_at(env.clone(), sensor_id) {
+                Some(last) => now < last.saturating_add(cooldown),
+                None => false,
+            };
+
+            // Only a mitigation that actually fired starts the cooldown, so a
+            // round of failed calls is retried on the next violation
+            if !cooling_down && Self::trigger_mitigation(env, sensor_id)? {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::LastTriggeredAt(sensor_id), &now);
+            }
         }
 
         Ok(violated)
     }
 
+    /// Set the oldest oracle consensus, in seconds, `check_risk_from_oracle` accepts
+    pub fn set_oracle_max_age(env: Env, max_age_seconds: u64) -> Result<(), RiskError> {
+        let admin = Self::require_admin(&env)?;
+        admin.require_auth();
+
+        if max_age_seconds == 0 {
+            return Err(RiskError::InvalidInput);
+        }
+
+        env.storage().persistent().set(&DataKey::OracleMaxAge, &max_age_seconds);
+        Ok(())
+    }
+
+    /// Get the oldest oracle consensus, in seconds, `check_risk_from_oracle` accepts
+    pub fn get_oracle_max_age(env: Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OracleMaxAge)
+            .unwrap_or(DEFAULT_ORACLE_MAX_AGE)
+    }
+
+    /// Run the `check_risk` evaluation on the sensor's current value, read from
+    /// the oracle contract in `source_contract` as the consensus value for
+    /// `data_id`. Open to anyone, since the value comes from the oracle.
+    pub fn check_risk_from_oracle(env: Env, sensor_id: u64) -> Result<bool, RiskError> {
+        if Self::is_paused(&env) {
+            return Err(RiskError::Paused);
+        }
+
+        let sensors: Map<u64, RiskSensor> = env.storage().persistent().get(&DataKey::Sensors).unwrap();
+        let sensor = sensors.get(sensor_id).ok_or(RiskError::NotFound)?;
+        let value = Self::read_oracle_value(&env, &sensor)?;
+
+        Self::evaluate_risk(&env, sensor_id, value)
+    }
+
+    /// Read a sensor's current value from its oracle, rejecting invalidated
+    /// and stale consensus values
+    fn read_oracle_value(env: &Env, sensor: &RiskSensor) -> Result<i128, RiskError> {
+        let oracle = sensor.source_contract.clone().ok_or(RiskError::OracleUnavailable)?;
+
+        let data = match OracleClient::new(env, &oracle).try_get_oracle_data(&sensor.data_id) {
+            Ok(Ok(data)) => data,
+            _ => return Err(RiskError::OracleUnavailable),
+        };
+
+        if !data.is_valid {
+            return Err(RiskError::OracleDataInvalid);
+        }
+
+        let age = env.ledger().timestamp().saturating_sub(data.finalized_at);
+        if age > Self::get_oracle_max_age(env.clone()) {
+            return Err(RiskError::OracleDataStale);
+        }
+
+        Ok(data.consensus_value)
+    }
+
+    /// Read the current value for every active sensor bound to an oracle, the
+    /// same way `check_risk_from_oracle` does, and evaluate it like `check_risk`,
+    /// firing mitigations as configured. Sensors without an oracle are
+    /// skipped, as are oracles with no usable value. Returns
+    /// `(sensor_id, violated)` for each evaluated sensor.
+    pub fn evaluate_all_sensors(env: Env) -> Result<Vec<(u64, bool)>, RiskError> {
+        if Self::is_paused(&env) {
+            return Err(RiskError::Paused);
+        }
+
+        let sensors: Map<u64, RiskSensor> = env.storage().persistent().get(&DataKey::Sensors).unwrap();
+        let mut results = Vec::new(&env);
+
+        for (sensor_id, sensor) in sensors.iter() {
+            if !sensor.is_active {
+                continue;
+            }
+            let source = match sensor.source_contract.clone() {
+                Some(source) => source,
+                None => continue,
+            };
+
+            let value = match Self::read_oracle_value(&env, &sensor) {
+                Ok(value) => value,
+                Err(_) => {
+                    env.events().publish(
+                        (Symbol::new(&env, "source_failed"), sensor_id),
+                        source
+                    );
+                    continue;
+                }
+            };
+
+            let violated = Self::evaluate_risk(&env, sensor_id, value)?;
+            results.push_back((sensor_id, violated));
+        }
+
+        Ok(results)
+    }
+
     /// Internal function to execute automated mitigation actions
-    fn trigger_mitigation(env: &Env, sensor_id: u64) -> Result<(), RiskError> {
-        let triggers_map: Map<u64, Vec<MitigationTrigger>> = env.storage().persistent().get(&DataKey::Triggers).unwrap();
-        let triggers = match triggers_map.get(sensor_id) {
+    ///
+    /// Cross-contract calls are made with `try_invoke_contract` so a failing
+    /// target is logged and counted instead of aborting the whole risk check.
+    /// Returns whether at least one trigger fired successfully.
+    fn trigger_mitigation(env: &Env, sensor_id: u64) -> Result<bool, RiskError> {
+        let mut triggers_map: Map<u64, Vec<MitigationTrigger>> = env.storage().persistent().get(&DataKey::Triggers).unwrap();
+        let mut triggers = match triggers_map.get(sensor_id) {
             Some(t) => t,
-            None => return Ok(()), // No triggers for this sensor
+            None => return Ok(false), // No triggers for this sensor
         };
+        let mut any_succeeded = false;
 
         for i in 0..triggers.len() {
-            let trigger = triggers.get(i).unwrap();
+            let mut trigger = triggers.get(i).unwrap();
             if !trigger.is_enabled {
                 continue;
             }
 
             // Execute action based on type
-            // Note: In real implementation, this would use cross-contract calls
-            match trigger.action {
+            let succeeded = match trigger.action {
                 MitigationAction::Pause => {
-                    // Example: Call set_paused on target contract
-                    // env.invoke_contract::<()>(
-                    //     &trigger.target_contract,
-                    //     &Symbol::new(env, "set_paused"),
-                    //     (true,).into_val(env),
-                    // );
                     env.events().publish(
                         (symbol_short!("mitigate"), symbol_short!("pause")),
                         trigger.target_contract.clone()
                     );
+                    Self::invoke_target(env, &trigger, "set_paused", (true,).into_val(env))
                 },
                 MitigationAction::EmergencyWithdraw => {
                     env.events().publish(
                         (symbol_short!("mitigate"), symbol_short!("withdraw")),
                         trigger.target_contract.clone()
                     );
+                    Self::invoke_target(env, &trigger, "emergency_withdraw", Vec::new(env))
                 },
                 MitigationAction::LimitCoverage => {
                     env.events().publish(
                         (symbol_short!("mitigate"), symbol_short!("limit")),
                         trigger.target_contract.clone()
                     );
+                    true
                 },
                 MitigationAction::FlashAlert => {
                     env.events().publish(
                         (symbol_short!("mitigate"), symbol_short!("flash")),
                         trigger.target_contract.clone()
                     );
+                    true
+                }
+            };
+
+            if succeeded {
+                any_succeeded = true;
+                if trigger.failure_count == 0 {
+                    continue;
+                }
+                trigger.failure_count = 0;
+            } else {
+                trigger.failure_count += 1;
+                env.events().publish(
+                    (Symbol::new(env, "mitigation_failed"), sensor_id),
+                    (trigger.target_contract.clone(), trigger.failure_count)
+                );
+
+                if trigger.failure_count >= trigger.max_retries {
+                    trigger.is_enabled = false;
+                    env.events().publish(
+                        (symbol_short!("trigger"), symbol_short!("disabled")),
+                        (sensor_id, trigger.target_contract.clone())
+                    );
                 }
             }
+
+            triggers.set(i, trigger);
         }
 
-        Ok(())
+        triggers_map.set(sensor_id, triggers);
+        env.storage().persistent().set(&DataKey::Triggers, &triggers_map);
+
+        Ok(any_succeeded)
+    }
+
+    /// Get mitigation triggers configured for a sensor
+    pub fn get_triggers(env: Env, sensor_id: u64) -> Vec<MitigationTrigger> {
+        let triggers_map: Map<u64, Vec<MitigationTrigger>> = env.storage().persistent().get(&DataKey::Triggers).unwrap_or_else(|| Map::new(&env));
+        triggers_map.get(sensor_id).unwrap_or_else(|| Vec::new(&env))
     }
 
     /// Get alert history
@@ -326,6 +692,82 @@ impl RiskMonitoringContract {
         env.storage().persistent().get(&DataKey::AlertHistory).unwrap_or_else(|| Vec::new(&env))
     }
 
+    /// Get a page of the retained alerts, oldest first. `limit` is capped at
+    /// `MAX_ALERT_PAGE_SIZE`.
+    pub fn get_alert_history_paginated(env: Env, start: u32, limit: u32) -> Vec<RiskAlert> {
+        let history = Self::get_alert_history(env.clone());
+        let end = start
+            .saturating_add(limit.min(MAX_ALERT_PAGE_SIZE))
+            .min(history.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        history.slice(start..end)
+    }
+
+    /// Number of most recent alerts kept in the history
+    pub fn get_max_alert_history(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MaxAlertHistory)
+            .unwrap_or(DEFAULT_MAX_ALERT_HISTORY)
+    }
+
+    /// Count alerts raised by a sensor within the last `window_seconds`
+    pub fn get_alert_frequency(env: Env, sensor_id: u64, window_seconds: u64) -> u32 {
+        let since = env.ledger().timestamp().saturating_sub(window_seconds);
+        let history = Self::get_alert_history(env);
+
+        let mut count = 0u32;
+        for alert in history.iter() {
+            if alert.sensor_id == sensor_id && alert.timestamp >= since {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Rank sensors by alert count within the last `window_seconds`
+    ///
+    /// Returns `(sensor_id, alert_count)` pairs, highest count first, ties
+    /// broken by lower sensor id. `limit` is capped at `MAX_NOISIEST_SENSORS`.
+    pub fn get_noisiest_sensors(env: Env, window_seconds: u64, limit: u32) -> Vec<(u64, u32)> {
+        let limit = limit.min(MAX_NOISIEST_SENSORS);
+        let mut ranked: Vec<(u64, u32)> = Vec::new(&env);
+        if limit == 0 {
+            return ranked;
+        }
+
+        let since = env.ledger().timestamp().saturating_sub(window_seconds);
+        let history = Self::get_alert_history(env.clone());
+
+        let mut counts: Map<u64, u32> = Map::new(&env);
+        for alert in history.iter() {
+            if alert.timestamp >= since {
+                let current = counts.get(alert.sensor_id).unwrap_or(0);
+                counts.set(alert.sensor_id, current + 1);
+            }
+        }
+
+        // Map iterates in ascending sensor id, so a strict comparison keeps
+        // lower ids ahead on ties
+        for (sensor_id, count) in counts.iter() {
+            let mut pos = ranked.len();
+            while pos > 0 && ranked.get(pos - 1).unwrap().1 < count {
+                pos -= 1;
+            }
+            if pos >= limit {
+                continue;
+            }
+            ranked.insert(pos, (sensor_id, count));
+            if ranked.len() > limit {
+                ranked.pop_back();
+            }
+        }
+
+        ranked
+    }
+
     /// Get all sensors
     pub fn get_sensors(env: Env) -> Vec<RiskSensor> {
         let sensors_map: Map<u64, RiskSensor> = env.storage().persistent().get(&DataKey::Sensors).unwrap_or_else(|| Map::new(&env));
@@ -344,6 +786,26 @@ impl RiskMonitoringContract {
         env.storage().persistent().get(&DataKey::Admin).ok_or(RiskError::NotInitialized)
     }
 
+    /// Call the trigger's target, using its custom method (without arguments)
+    /// if set and `default_method(default_args)` otherwise
+    fn invoke_target(
+        env: &Env,
+        trigger: &MitigationTrigger,
+        default_method: &str,
+        default_args: Vec<Val>,
+    ) -> bool {
+        let (method, args) = match &trigger.method {
+            Some(method) => (method.clone(), Vec::new(env)),
+            None => (Symbol::new(env, default_method), default_args),
+        };
+        let result = env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &trigger.target_contract,
+            &method,
+            args,
+        );
+        matches!(result, Ok(Ok(())))
+    }
+
     fn is_paused(env: &Env) -> bool {
         env.storage().persistent().get(&DataKey::Paused).unwrap_or(false)
     }
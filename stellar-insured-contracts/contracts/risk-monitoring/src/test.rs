@@ -7,6 +7,7 @@ use soroban_sdk::{vec, IntoVal};
 #[test]
 fn test_risk_monitoring_lifecycle() {
     let env = Env::default();
+    env.mock_all_auths();
     let admin = Address::generate(&env);
 
     let contract_id = env.register_contract(None, RiskMonitoringContract);
@@ -39,6 +40,7 @@ fn test_risk_monitoring_lifecycle() {
     // 5. Check Risk - High Violation (Alert Trigger)
     let result_violation = client.check_risk(&sensor_id, &120_000);
     assert_eq!(result_violation, true);
+    let violation_events = env.events().all();
 
     // 6. Verify Alert History
     let history = client.get_alert_history();
@@ -48,14 +50,16 @@ fn test_risk_monitoring_lifecycle() {
     assert_eq!(alert.value, 120_000);
 
     // 7. Verify Events (Notification System)
-    let last_event = env.events().all().last().unwrap();
+    let last_event = violation_events.events().last().unwrap();
     // (risk, alert) event
-    assert_eq!(last_event.0, contract_id);
+    let own_events = violation_events.filter_by_contract(&contract_id);
+    assert_eq!(own_events.events().last(), Some(last_event));
 }
 
 #[test]
 fn test_threshold_update() {
     let env = Env::default();
+    env.mock_all_auths();
     let admin = Address::generate(&env);
 
     let contract_id = env.register_contract(None, RiskMonitoringContract);
@@ -82,3 +86,610 @@ fn test_threshold_update() {
     // Check again - 800 is now normal
     assert_eq!(client.check_risk(&sensor_id, &800), false);
 }
+
+#[test]
+fn test_sensor_deactivation_and_removal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, RiskMonitoringContract);
+    let client = RiskMonitoringContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let sensor_id = client.add_sensor(
+        &RiskFactorType::Volatility,
+        &String::from_str(&env, "Misconfigured Sensor"),
+        &0,
+        &10,
+        &None,
+        &6,
+    );
+    client.add_mitigation_trigger(&sensor_id, &MitigationAction::FlashAlert, &Address::generate(&env));
+
+    client.set_sensor_active(&sensor_id, &false);
+    assert!(!client.get_sensors().get(0).unwrap().is_active);
+    assert_eq!(client.try_check_risk(&sensor_id, &50), Err(Ok(RiskError::SensorInactive)));
+
+    client.set_sensor_active(&sensor_id, &true);
+    assert_eq!(client.check_risk(&sensor_id, &50), true);
+
+    client.remove_sensor(&sensor_id);
+    assert_eq!(client.get_sensors().len(), 0);
+    assert_eq!(client.get_triggers(&sensor_id).len(), 0);
+    assert_eq!(client.try_check_risk(&sensor_id, &50), Err(Ok(RiskError::NotFound)));
+    assert_eq!(client.try_remove_sensor(&sensor_id), Err(Ok(RiskError::NotFound)));
+    assert_eq!(
+        client.try_set_sensor_active(&sensor_id, &true),
+        Err(Ok(RiskError::NotFound))
+    );
+}
+
+// ============================================================================
+// Mitigation Failure Handling
+// ============================================================================
+
+mod pausable_target {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+
+    #[contract]
+    pub struct PausableTarget;
+
+    #[contractimpl]
+    impl PausableTarget {
+        pub fn set_paused(env: Env, paused: bool) {
+            env.storage().instance().set(&symbol_short!("paused"), &paused);
+        }
+
+        pub fn is_paused(env: Env) -> bool {
+            env.storage().instance().get(&symbol_short!("paused")).unwrap_or(false)
+        }
+    }
+}
+
+mod failing_target {
+    use soroban_sdk::{contract, contractimpl, Env};
+
+    #[contract]
+    pub struct FailingTarget;
+
+    #[contractimpl]
+    impl FailingTarget {
+        pub fn set_paused(_env: Env, _paused: bool) {
+            panic!("target rejected pause");
+        }
+    }
+}
+
+fn setup_failing_mitigation(env: &Env) -> (RiskMonitoringContractClient<'_>, u64, Address) {
+    env.mock_all_auths();
+    let admin = Address::generate(env);
+
+    let contract_id = env.register_contract(None, RiskMonitoringContract);
+    let client = RiskMonitoringContractClient::new(env, &contract_id);
+    client.initialize(&admin);
+
+    let sensor_id = client.add_sensor(
+        &RiskFactorType::Volatility,
+        &String::from_str(env, "Volatility Sensor"),
+        &0,
+        &100,
+        &None,
+        &3,
+    );
+
+    let working = env.register_contract(None, pausable_target::PausableTarget);
+    let failing = env.register_contract(None, failing_target::FailingTarget);
+
+    // Failing trigger first so it must not prevent the working one from running
+    client.add_mitigation_trigger(&sensor_id, &MitigationAction::Pause, &failing);
+    client.add_mitigation_trigger(&sensor_id, &MitigationAction::Pause, &working);
+
+    (client, sensor_id, working)
+}
+
+#[test]
+fn test_failing_mitigation_does_not_abort_check() {
+    let env = Env::default();
+    let (client, sensor_id, working) = setup_failing_mitigation(&env);
+
+    assert_eq!(client.check_risk(&sensor_id, &500), true);
+    assert_eq!(client.get_alert_history().len(), 1);
+
+    let target = pausable_target::PausableTargetClient::new(&env, &working);
+    assert!(target.is_paused());
+
+    let triggers = client.get_triggers(&sensor_id);
+    let failed = triggers.get(0).unwrap();
+    assert_eq!(failed.failure_count, 1);
+    assert!(failed.is_enabled);
+    assert_eq!(triggers.get(1).unwrap().failure_count, 0);
+}
+
+#[test]
+fn test_failing_trigger_disables_after_max_retries() {
+    let env = Env::default();
+    let (client, sensor_id, _working) = setup_failing_mitigation(&env);
+
+    client.set_trigger_max_retries(&sensor_id, &0, &2);
+
+    client.check_risk(&sensor_id, &500);
+    assert!(client.get_triggers(&sensor_id).get(0).unwrap().is_enabled);
+
+    client.check_risk(&sensor_id, &500);
+    let failed = client.get_triggers(&sensor_id).get(0).unwrap();
+    assert_eq!(failed.failure_count, 2);
+    assert!(!failed.is_enabled);
+
+    // Disabled trigger is skipped on later checks
+    client.check_risk(&sensor_id, &500);
+    assert_eq!(client.get_triggers(&sensor_id).get(0).unwrap().failure_count, 2);
+    assert_eq!(client.get_alert_history().len(), 3);
+
+    assert_eq!(
+        client.try_set_trigger_max_retries(&sensor_id, &0, &0),
+        Err(Ok(RiskError::InvalidInput))
+    );
+}
+
+#[test]
+fn test_failed_mitigation_does_not_start_cooldown() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, RiskMonitoringContract);
+    let client = RiskMonitoringContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let sensor_id = client.add_sensor(
+        &RiskFactorType::Volatility,
+        &String::from_str(&env, "Volatility Sensor"),
+        &0,
+        &100,
+        &None,
+        &3,
+    );
+    let failing = env.register_contract(None, failing_target::FailingTarget);
+    client.add_mitigation_trigger(&sensor_id, &MitigationAction::Pause, &failing);
+    client.set_mitigation_cooldown(&sensor_id, &600);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(client.check_risk(&sensor_id, &500), true);
+    assert_eq!(client.get_last_triggered_at(&sensor_id), None);
+
+    // The next violation retries straight away instead of waiting out a cooldown
+    env.ledger().with_mut(|li| li.timestamp = 1_001);
+    client.check_risk(&sensor_id, &500);
+    assert_eq!(client.get_triggers(&sensor_id).get(0).unwrap().failure_count, 2);
+}
+
+mod custom_target {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+
+    #[contract]
+    pub struct CustomTarget;
+
+    #[contractimpl]
+    impl CustomTarget {
+        pub fn pause(env: Env) {
+            env.storage().instance().set(&symbol_short!("paused"), &true);
+        }
+
+        pub fn emergency_withdraw(env: Env) {
+            env.storage().instance().set(&symbol_short!("drained"), &true);
+        }
+
+        pub fn is_paused(env: Env) -> bool {
+            env.storage().instance().get(&symbol_short!("paused")).unwrap_or(false)
+        }
+
+        pub fn is_drained(env: Env) -> bool {
+            env.storage().instance().get(&symbol_short!("drained")).unwrap_or(false)
+        }
+    }
+}
+
+#[test]
+fn test_mitigation_invokes_configured_target_methods() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, RiskMonitoringContract);
+    let client = RiskMonitoringContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let sensor_id = client.add_sensor(
+        &RiskFactorType::Liquidity,
+        &String::from_str(&env, "Pool Liquidity"),
+        &1_000,
+        &i128::MAX,
+        &None,
+        &4,
+    );
+
+    let target_id = env.register_contract(None, custom_target::CustomTarget);
+    let target = custom_target::CustomTargetClient::new(&env, &target_id);
+    client.add_mitigation_trigger(&sensor_id, &MitigationAction::Pause, &target_id);
+    client.add_mitigation_trigger(&sensor_id, &MitigationAction::EmergencyWithdraw, &target_id);
+
+    // The target has no `set_paused`, so the default pause call fails
+    client.check_risk(&sensor_id, &10);
+    assert!(!target.is_paused());
+    assert!(target.is_drained());
+    assert_eq!(client.get_triggers(&sensor_id).get(0).unwrap().failure_count, 1);
+
+    client.set_trigger_method(&sensor_id, &0, &Some(Symbol::new(&env, "pause")));
+    client.check_risk(&sensor_id, &10);
+    assert!(target.is_paused());
+
+    let pause_trigger = client.get_triggers(&sensor_id).get(0).unwrap();
+    assert_eq!(pause_trigger.method, Some(Symbol::new(&env, "pause")));
+    assert_eq!(pause_trigger.failure_count, 0);
+
+    assert_eq!(
+        client.try_set_trigger_method(&sensor_id, &5, &None),
+        Err(Ok(RiskError::NotFound))
+    );
+}
+
+#[test]
+fn test_mitigation_cooldown_suppresses_repeat_triggers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, RiskMonitoringContract);
+    let client = RiskMonitoringContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let sensor_id = client.add_sensor(
+        &RiskFactorType::Price,
+        &String::from_str(&env, "Collateral Price"),
+        &0,
+        &100,
+        &None,
+        &5,
+    );
+    let target_id = env.register_contract(None, pausable_target::PausableTarget);
+    let target = pausable_target::PausableTargetClient::new(&env, &target_id);
+    client.add_mitigation_trigger(&sensor_id, &MitigationAction::Pause, &target_id);
+    client.set_mitigation_cooldown(&sensor_id, &600);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.check_risk(&sensor_id, &500);
+    assert!(target.is_paused());
+    assert_eq!(client.get_last_triggered_at(&sensor_id), Some(1_000));
+
+    // Within the window the alert is recorded but the target stays unpaused
+    target.set_paused(&false);
+    env.ledger().with_mut(|li| li.timestamp = 1_599);
+    assert_eq!(client.check_risk(&sensor_id, &500), true);
+    assert!(!target.is_paused());
+    assert_eq!(client.get_alert_history().len(), 2);
+    assert_eq!(client.get_last_triggered_at(&sensor_id), Some(1_000));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_600);
+    client.check_risk(&sensor_id, &500);
+    assert!(target.is_paused());
+    assert_eq!(client.get_last_triggered_at(&sensor_id), Some(1_600));
+
+    assert_eq!(
+        client.try_set_mitigation_cooldown(&99, &600),
+        Err(Ok(RiskError::NotFound))
+    );
+}
+
+// ============================================================================
+// Aggregated Sensor Evaluation
+// ============================================================================
+
+#[test]
+fn test_evaluate_all_sensors_pulls_sources_and_fires_mitigations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, RiskMonitoringContract);
+    let client = RiskMonitoringContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let source_id = env.register_contract(None, mock_oracle::MockOracle);
+    let source = mock_oracle::MockOracleClient::new(&env, &source_id);
+    source.set_data(&1, &150, &0, &true); // breaching
+    source.set_data(&2, &50, &0, &true); // in range
+    source.set_data(&4, &500, &0, &false); // invalidated, so skipped
+
+    let breaching = client.add_sensor(
+        &RiskFactorType::Price,
+        &String::from_str(&env, "Oracle Price"),
+        &0,
+        &100,
+        &Some(source_id.clone()),
+        &1,
+    );
+    let healthy = client.add_sensor(
+        &RiskFactorType::Liquidity,
+        &String::from_str(&env, "Treasury Liquidity"),
+        &0,
+        &100,
+        &Some(source_id.clone()),
+        &2,
+    );
+    let unbound = client.add_sensor(
+        &RiskFactorType::Invariant,
+        &String::from_str(&env, "Manual Invariant"),
+        &0,
+        &100,
+        &None,
+        &3,
+    );
+    client.add_sensor(
+        &RiskFactorType::Volatility,
+        &String::from_str(&env, "Invalidated Feed"),
+        &0,
+        &100,
+        &Some(source_id.clone()),
+        &4,
+    );
+
+    let pausable = env.register_contract(None, pausable_target::PausableTarget);
+    client.add_mitigation_trigger(&breaching, &MitigationAction::Pause, &pausable);
+    let untouched = env.register_contract(None, pausable_target::PausableTarget);
+    client.add_mitigation_trigger(&healthy, &MitigationAction::Pause, &untouched);
+    client.add_mitigation_trigger(&unbound, &MitigationAction::Pause, &untouched);
+
+    let results = client.evaluate_all_sensors();
+    assert_eq!(results, vec![&env, (breaching, true), (healthy, false)]);
+
+    assert!(pausable_target::PausableTargetClient::new(&env, &pausable).is_paused());
+    assert!(!pausable_target::PausableTargetClient::new(&env, &untouched).is_paused());
+
+    let history = client.get_alert_history();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().sensor_id, breaching);
+    assert_eq!(history.get(0).unwrap().value, 150);
+}
+
+// ============================================================================
+// Oracle Integration
+// ============================================================================
+
+mod mock_oracle {
+    use crate::OracleData;
+    use soroban_sdk::{contract, contractimpl, Env};
+
+    #[contract]
+    pub struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn set_data(env: Env, data_id: u64, value: i128, finalized_at: u64, is_valid: bool) {
+            let data = OracleData {
+                data_id,
+                consensus_value: value,
+                submission_count: 3,
+                consensus_percentage: 100,
+                finalized_at,
+                included_submissions: 3,
+                rejected_submissions: 0,
+                mean_abs_deviation: 0,
+                confidence: 100,
+                is_valid,
+                admin_override: false,
+                override_justification: None,
+            };
+            env.storage().instance().set(&data_id, &data);
+        }
+
+        pub fn get_oracle_data(env: Env, data_id: u64) -> OracleData {
+            env.storage().instance().get(&data_id).expect("no oracle data")
+        }
+    }
+}
+
+#[test]
+fn test_check_risk_from_oracle() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, RiskMonitoringContract);
+    let client = RiskMonitoringContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let oracle_id = env.register_contract(None, mock_oracle::MockOracle);
+    let oracle = mock_oracle::MockOracleClient::new(&env, &oracle_id);
+
+    let sensor_id = client.add_sensor(
+        &RiskFactorType::Price,
+        &String::from_str(&env, "ETH/USD"),
+        &1_000,
+        &5_000,
+        &Some(oracle_id.clone()),
+        &42,
+    );
+    let target_id = env.register_contract(None, pausable_target::PausableTarget);
+    client.add_mitigation_trigger(&sensor_id, &MitigationAction::Pause, &target_id);
+
+    // Nothing finalized for the data id yet
+    assert_eq!(
+        client.try_check_risk_from_oracle(&sensor_id),
+        Err(Ok(RiskError::OracleUnavailable))
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    oracle.set_data(&42, &3_000, &10_000, &true);
+    assert_eq!(client.check_risk_from_oracle(&sensor_id), false);
+
+    oracle.set_data(&42, &800, &10_000, &true);
+    assert_eq!(client.check_risk_from_oracle(&sensor_id), true);
+    assert_eq!(client.get_alert_history().get(0).unwrap().value, 800);
+    assert!(pausable_target::PausableTargetClient::new(&env, &target_id).is_paused());
+
+    oracle.set_data(&42, &800, &10_000, &false);
+    assert_eq!(
+        client.try_check_risk_from_oracle(&sensor_id),
+        Err(Ok(RiskError::OracleDataInvalid))
+    );
+
+    oracle.set_data(&42, &800, &10_000, &true);
+    env.ledger().with_mut(|li| li.timestamp = 10_000 + DEFAULT_ORACLE_MAX_AGE + 1);
+    assert_eq!(
+        client.try_check_risk_from_oracle(&sensor_id),
+        Err(Ok(RiskError::OracleDataStale))
+    );
+    client.set_oracle_max_age(&(2 * DEFAULT_ORACLE_MAX_AGE));
+    assert_eq!(client.check_risk_from_oracle(&sensor_id), true);
+
+    let unbound = client.add_sensor(
+        &RiskFactorType::Price,
+        &String::from_str(&env, "Manual"),
+        &0,
+        &100,
+        &None,
+        &1,
+    );
+    assert_eq!(
+        client.try_check_risk_from_oracle(&unbound),
+        Err(Ok(RiskError::OracleUnavailable))
+    );
+}
+
+#[test]
+fn test_only_admin_or_oracle_values_fire_mitigations() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, RiskMonitoringContract);
+    let client = RiskMonitoringContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let oracle_id = env.register_contract(None, mock_oracle::MockOracle);
+    let sensor_id = client.add_sensor(
+        &RiskFactorType::Price,
+        &String::from_str(&env, "ETH/USD"),
+        &1_000,
+        &5_000,
+        &Some(oracle_id.clone()),
+        &42,
+    );
+    let target_id = env.register_contract(None, pausable_target::PausableTarget);
+    client.add_mitigation_trigger(&sensor_id, &MitigationAction::Pause, &target_id);
+    let target = pausable_target::PausableTargetClient::new(&env, &target_id);
+
+    // Without the admin's signature a reported value is rejected
+    env.set_auths(&[]);
+    assert!(client.try_check_risk(&sensor_id, &800).is_err());
+    assert!(!target.is_paused());
+    assert_eq!(client.get_alert_history().len(), 0);
+
+    // An oracle-sourced value needs no signature
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    mock_oracle::MockOracleClient::new(&env, &oracle_id).set_data(&42, &800, &10_000, &true);
+    assert_eq!(client.check_risk_from_oracle(&sensor_id), true);
+    assert!(target.is_paused());
+}
+
+// ============================================================================
+// Bounded Alert History
+// ============================================================================
+
+#[test]
+fn test_alert_history_keeps_most_recent_alerts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, RiskMonitoringContract);
+    let client = RiskMonitoringContractClient::new(&env, &contract_id);
+    assert_eq!(
+        client.try_initialize_with_history_limit(&admin, &0),
+        Err(Ok(RiskError::InvalidInput))
+    );
+    client.initialize_with_history_limit(&admin, &3);
+    assert_eq!(client.get_max_alert_history(), 3);
+
+    let sensor_id = client.add_sensor(
+        &RiskFactorType::Price,
+        &String::from_str(&env, "Price Sensor"),
+        &0,
+        &100,
+        &None,
+        &7,
+    );
+    for value in 101..=105i128 {
+        client.check_risk(&sensor_id, &value);
+    }
+
+    let history = client.get_alert_history();
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap().value, 103);
+    assert_eq!(history.get(2).unwrap().value, 105);
+
+    let page = client.get_alert_history_paginated(&1, &10);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().value, 104);
+    assert_eq!(client.get_alert_history_paginated(&0, &1).get(0).unwrap().value, 103);
+    assert_eq!(client.get_alert_history_paginated(&3, &10).len(), 0);
+}
+
+// ============================================================================
+// Alert Frequency Analytics
+// ============================================================================
+
+#[test]
+fn test_alert_frequency_and_noisiest_sensors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let contract_id = env.register_contract(None, RiskMonitoringContract);
+    let client = RiskMonitoringContractClient::new(&env, &contract_id);
+    client.initialize(&admin);
+
+    let mut sensors = Vec::new(&env);
+    for data_id in 1..=3u64 {
+        let sensor_id = client.add_sensor(
+            &RiskFactorType::Price,
+            &String::from_str(&env, "Price Sensor"),
+            &0,
+            &100,
+            &None,
+            &data_id,
+        );
+        sensors.push_back(sensor_id);
+    }
+    let (flaky, steady, quiet) = (sensors.get(0).unwrap(), sensors.get(1).unwrap(), sensors.get(2).unwrap());
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    // One old alert for the quiet sensor, outside the later window
+    client.check_risk(&quiet, &500);
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    for _ in 0..4 {
+        client.check_risk(&flaky, &500);
+    }
+    for _ in 0..2 {
+        client.check_risk(&steady, &500);
+    }
+    // In-range readings do not count as alerts
+    client.check_risk(&steady, &50);
+
+    assert_eq!(client.get_alert_frequency(&flaky, &600), 4);
+    assert_eq!(client.get_alert_frequency(&steady, &600), 2);
+    assert_eq!(client.get_alert_frequency(&quiet, &600), 0);
+    assert_eq!(client.get_alert_frequency(&quiet, &10_000), 1);
+
+    let ranked = client.get_noisiest_sensors(&600, &10);
+    assert_eq!(ranked, vec![&env, (flaky, 4u32), (steady, 2u32)]);
+
+    let ranked = client.get_noisiest_sensors(&10_000, &10);
+    assert_eq!(ranked, vec![&env, (flaky, 4u32), (steady, 2u32), (quiet, 1u32)]);
+
+    let top = client.get_noisiest_sensors(&10_000, &1);
+    assert_eq!(top, vec![&env, (flaky, 4u32)]);
+    assert_eq!(client.get_noisiest_sensors(&10_000, &0).len(), 0);
+}
@@ -318,7 +318,7 @@ impl RiskPoolContract {
         recipient: Address,
     ) -> Result<(), ContractError> {
         // Default to Native asset for backward compatibility
-        Self::payout_reserved_claim_multi_asset(
+        Self::payout_reserved_claim_asset(
             env,
             caller_contract,
             claim_id,
@@ -328,7 +328,7 @@ impl RiskPoolContract {
     }
 
     /// Multi-asset version of payout_reserved_claim
-    pub fn payout_reserved_claim_multi_asset(
+    pub fn payout_reserved_claim_asset(
         env: Env,
         caller_contract: Address,
         claim_id: u64,
@@ -45,8 +45,8 @@ pub struct EmergencyPauseEvent {
 }
 
 /// Storage keys for emergency pause functionality
-const EMERGENCY_PAUSE_CONFIG: Symbol = Symbol::short("EMG_PAUSED");
-const EMERGENCY_PAUSE_HISTORY: Symbol = Symbol::short("EMG_HISTORY");
+const EMERGENCY_PAUSE_CONFIG: Symbol = Symbol::short("EMG_PAUSE");
+const EMERGENCY_PAUSE_HISTORY: Symbol = Symbol::short("EMG_HIST");
 const SELECTIVE_PAUSE_MAP: Symbol = Symbol::short("SEL_PAUSE");
 
 /// Emergency pause management utilities
@@ -82,16 +82,12 @@ impl EmergencyPause {
 
     /// Check if a specific function is paused
     pub fn is_function_paused(env: &Env, function_name: &Symbol) -> bool {
-        let config: EmergencyPauseConfig = env.storage().persistent()
+        let config: EmergencyPauseConfig = match env.storage().persistent()
             .get(&EMERGENCY_PAUSE_CONFIG)
-            .unwrap_or_else(|| EmergencyPauseConfig {
-                is_emergency_paused: false,
-                pause_reason: Symbol::new(env, "not_paused"),
-                pause_timestamp: 0,
-                paused_by: Address::from_string(env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF"),
-                paused_functions: Vec::new(env),
-                max_duration_seconds: 0,
-            });
+        {
+            Some(config) => config,
+            None => return false,
+        };
 
         // If global emergency pause is active, all functions are paused
         if config.is_emergency_paused {
@@ -101,7 +97,7 @@ impl EmergencyPause {
         // Check selective function pause
         for i in 0..config.paused_functions.len() {
             if let Some(paused_func) = config.paused_functions.get(i) {
-                if &paused_func == function_name {
+                if paused_func == *function_name {
                     return true;
                 }
             }
@@ -142,7 +138,7 @@ impl EmergencyPause {
             env,
             Symbol::new(env, "emergency_pause"),
             admin.clone(),
-            reason,
+            reason.clone(),
             Vec::new(env),
         );
 
@@ -184,7 +180,7 @@ impl EmergencyPause {
             env,
             Symbol::new(env, "emergency_unpause"),
             admin.clone(),
-            reason,
+            reason.clone(),
             Vec::new(env),
         );
 
@@ -237,7 +233,7 @@ impl EmergencyPause {
             env,
             Symbol::new(env, "selective_pause"),
             admin.clone(),
-            reason,
+            reason.clone(),
             functions.clone(),
         );
 
@@ -270,7 +266,7 @@ impl EmergencyPause {
                 let mut should_remove = false;
                 for j in 0..functions.len() {
                     if let Some(target_func) = functions.get(j) {
-                        if &paused_func == target_func {
+                        if paused_func == target_func {
                             should_remove = true;
                             break;
                         }
@@ -290,7 +286,7 @@ impl EmergencyPause {
             env,
             Symbol::new(env, "selective_unpause"),
             admin.clone(),
-            reason,
+            reason.clone(),
             functions.clone(),
         );
 
@@ -347,7 +343,7 @@ impl EmergencyPause {
         
         if elapsed >= config.max_duration_seconds {
             // Auto-unpause expired emergency pause
-            let mut updated_config = config;
+            let mut updated_config = config.clone();
             updated_config.is_emergency_paused = false;
             updated_config.pause_reason = Symbol::new(env, "not_paused");
             updated_config.pause_timestamp = 0;
@@ -438,42 +434,58 @@ impl EmergencyPause {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Env, Address};
+    use soroban_sdk::{contract, testutils::{Address as _, Ledger}, Env, Address};
 
-    #[test]
-    fn test_emergency_pause_initialization() {
+    /// Contract whose storage the pause helpers run against
+    #[contract]
+    struct PauseHost;
+
+    fn setup() -> (Env, Address, Address) {
         let env = Env::default();
+        env.mock_all_auths();
+        let host = env.register(PauseHost, ());
         let admin = Address::generate(&env);
+        (env, host, admin)
+    }
+
+    /// Each call gets its own frame so repeated `require_auth` calls don't collide
+    fn in_host<T>(env: &Env, host: &Address, f: impl FnOnce() -> T) -> T {
+        env.as_contract(host, f)
+    }
+
+    #[test]
+    fn test_emergency_pause_initialization() {
+        let (env, host, admin) = setup();
 
         // Should succeed on first initialization
-        let result = EmergencyPause::initialize(&env, &admin);
+        let result = in_host(&env, &host, || EmergencyPause::initialize(&env, &admin));
         assert!(result.is_ok());
 
         // Should fail on second initialization
-        let result2 = EmergencyPause::initialize(&env, &admin);
+        let result2 = in_host(&env, &host, || EmergencyPause::initialize(&env, &admin));
         assert_eq!(result2, Err(ContractError::AlreadyInitialized));
 
         // Check initial state
-        let config = EmergencyPause::get_pause_config(&env).unwrap();
+        let config = in_host(&env, &host, || EmergencyPause::get_pause_config(&env)).unwrap();
         assert!(!config.is_emergency_paused);
         assert_eq!(config.pause_reason, Symbol::new(&env, "not_paused"));
     }
 
     #[test]
     fn test_emergency_pause_activation() {
-        let env = Env::default();
-        let admin = Address::generate(&env);
-        
-        EmergencyPause::initialize(&env, &admin).unwrap();
+        let (env, host, admin) = setup();
+        in_host(&env, &host, || EmergencyPause::initialize(&env, &admin)).unwrap();
 
         // Activate emergency pause
         let reason = Symbol::new(&env, "security_vulnerability");
-        let result = EmergencyPause::activate_emergency_pause(&env, &admin, reason.clone(), 3600);
+        let result = in_host(&env, &host, || {
+            EmergencyPause::activate_emergency_pause(&env, &admin, reason.clone(), 3600)
+        });
         assert!(result.is_ok());
 
         // Check state
-        assert!(EmergencyPause::is_emergency_paused(&env));
-        let config = EmergencyPause::get_pause_config(&env).unwrap();
+        assert!(in_host(&env, &host, || EmergencyPause::is_emergency_paused(&env)));
+        let config = in_host(&env, &host, || EmergencyPause::get_pause_config(&env)).unwrap();
         assert!(config.is_emergency_paused);
         assert_eq!(config.pause_reason, reason);
         assert_eq!(config.paused_by, admin);
@@ -482,134 +494,158 @@ mod tests {
 
     #[test]
     fn test_emergency_pause_deactivation() {
-        let env = Env::default();
-        let admin = Address::generate(&env);
-        
-        EmergencyPause::initialize(&env, &admin).unwrap();
-        EmergencyPause::activate_emergency_pause(&env, &admin, Symbol::new(&env, "test"), 3600).unwrap();
+        let (env, host, admin) = setup();
+        in_host(&env, &host, || EmergencyPause::initialize(&env, &admin)).unwrap();
+        in_host(&env, &host, || {
+            EmergencyPause::activate_emergency_pause(&env, &admin, Symbol::new(&env, "test"), 3600)
+        })
+        .unwrap();
 
         // Deactivate emergency pause
         let reason = Symbol::new(&env, "issue_resolved");
-        let result = EmergencyPause::deactivate_emergency_pause(&env, &admin, reason.clone());
+        let result = in_host(&env, &host, || {
+            EmergencyPause::deactivate_emergency_pause(&env, &admin, reason.clone())
+        });
         assert!(result.is_ok());
 
         // Check state
-        assert!(!EmergencyPause::is_emergency_paused(&env));
-        let config = EmergencyPause::get_pause_config(&env).unwrap();
+        assert!(!in_host(&env, &host, || EmergencyPause::is_emergency_paused(&env)));
+        let config = in_host(&env, &host, || EmergencyPause::get_pause_config(&env)).unwrap();
         assert!(!config.is_emergency_paused);
         assert_eq!(config.pause_reason, Symbol::new(&env, "not_paused"));
     }
 
     #[test]
     fn test_selective_function_pause() {
-        let env = Env::default();
-        let admin = Address::generate(&env);
-        
-        EmergencyPause::initialize(&env, &admin).unwrap();
+        let (env, host, admin) = setup();
+        in_host(&env, &host, || EmergencyPause::initialize(&env, &admin)).unwrap();
 
         let functions = Vec::from_array(&env, [
             Symbol::new(&env, "issue_policy"),
             Symbol::new(&env, "renew_policy"),
         ]);
+        let is_paused = |name: &str| {
+            in_host(&env, &host, || {
+                EmergencyPause::is_function_paused(&env, &Symbol::new(&env, name))
+            })
+        };
 
         // Pause specific functions
-        let result = EmergencyPause::pause_functions(&env, &admin, &functions, Symbol::new(&env, "maintenance"));
+        let result = in_host(&env, &host, || {
+            EmergencyPause::pause_functions(&env, &admin, &functions, Symbol::new(&env, "maintenance"))
+        });
         assert!(result.is_ok());
 
         // Check individual function states
-        assert!(EmergencyPause::is_function_paused(&env, &Symbol::new(&env, "issue_policy")));
-        assert!(EmergencyPause::is_function_paused(&env, &Symbol::new(&env, "renew_policy")));
-        assert!(!EmergencyPause::is_function_paused(&env, &Symbol::new(&env, "get_policy")));
+        assert!(is_paused("issue_policy"));
+        assert!(is_paused("renew_policy"));
+        assert!(!is_paused("get_policy"));
 
         // Unpause specific functions
-        let result2 = EmergencyPause::unpause_functions(&env, &admin, &Vec::from_array(&env, [Symbol::new(&env, "issue_policy")]), Symbol::new(&env, "maintenance_complete"));
+        let result2 = in_host(&env, &host, || {
+            EmergencyPause::unpause_functions(
+                &env,
+                &admin,
+                &Vec::from_array(&env, [Symbol::new(&env, "issue_policy")]),
+                Symbol::new(&env, "maintenance_complete"),
+            )
+        });
         assert!(result2.is_ok());
 
         // Check state after unpausing
-        assert!(!EmergencyPause::is_function_paused(&env, &Symbol::new(&env, "issue_policy")));
-        assert!(EmergencyPause::is_function_paused(&env, &Symbol::new(&env, "renew_policy")));
+        assert!(!is_paused("issue_policy"));
+        assert!(is_paused("renew_policy"));
     }
 
     #[test]
     fn test_pause_validation() {
-        let env = Env::default();
-        let admin = Address::generate(&env);
-        
-        EmergencyPause::initialize(&env, &admin).unwrap();
+        let (env, host, admin) = setup();
+        in_host(&env, &host, || EmergencyPause::initialize(&env, &admin)).unwrap();
+        let validate = |name: Option<&str>| {
+            in_host(&env, &host, || {
+                let name = name.map(|n| Symbol::new(&env, n));
+                EmergencyPause::validate_not_paused(&env, name.as_ref())
+            })
+        };
 
         // Should allow operations when not paused
-        assert!(EmergencyPause::validate_not_paused(&env, None).is_ok());
-        assert!(EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "test_function"))).is_ok());
+        assert!(validate(None).is_ok());
+        assert!(validate(Some("test_function")).is_ok());
 
         // Pause a specific function
-        EmergencyPause::pause_functions(
-            &env, 
-            &admin, 
-            &Vec::from_array(&env, [Symbol::new(&env, "restricted_function")]),
-            Symbol::new(&env, "test")
-        ).unwrap();
+        in_host(&env, &host, || {
+            EmergencyPause::pause_functions(
+                &env,
+                &admin,
+                &Vec::from_array(&env, [Symbol::new(&env, "restricted_function")]),
+                Symbol::new(&env, "test"),
+            )
+        })
+        .unwrap();
 
         // Should fail for paused function
-        assert_eq!(
-            EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "restricted_function"))),
-            Err(ContractError::FunctionPaused)
-        );
+        assert_eq!(validate(Some("restricted_function")), Err(ContractError::FunctionPaused));
 
         // Should still allow other functions
-        assert!(EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "allowed_function"))).is_ok());
+        assert!(validate(Some("allowed_function")).is_ok());
 
         // Activate global emergency pause
-        EmergencyPause::activate_emergency_pause(&env, &admin, Symbol::new(&env, "global"), 3600).unwrap();
+        in_host(&env, &host, || {
+            EmergencyPause::activate_emergency_pause(&env, &admin, Symbol::new(&env, "global"), 3600)
+        })
+        .unwrap();
 
         // Should fail for all functions during global pause
-        assert_eq!(
-            EmergencyPause::validate_not_paused(&env, Some(&Symbol::new(&env, "any_function"))),
-            Err(ContractError::Paused)
-        );
-        assert_eq!(
-            EmergencyPause::validate_not_paused(&env, None),
-            Err(ContractError::Paused)
-        );
+        assert_eq!(validate(Some("any_function")), Err(ContractError::Paused));
+        assert_eq!(validate(None), Err(ContractError::Paused));
     }
 
     #[test]
     fn test_pause_history() {
-        let env = Env::default();
-        let admin = Address::generate(&env);
-        
-        EmergencyPause::initialize(&env, &admin).unwrap();
+        let (env, host, admin) = setup();
+        in_host(&env, &host, || EmergencyPause::initialize(&env, &admin)).unwrap();
 
         // Perform some pause operations
-        EmergencyPause::activate_emergency_pause(&env, &admin, Symbol::new(&env, "reason1"), 3600).unwrap();
-        EmergencyPause::deactivate_emergency_pause(&env, &admin, Symbol::new(&env, "resolved")).unwrap();
-        
+        in_host(&env, &host, || {
+            EmergencyPause::activate_emergency_pause(&env, &admin, Symbol::new(&env, "reason1"), 3600)
+        })
+        .unwrap();
+        in_host(&env, &host, || {
+            EmergencyPause::deactivate_emergency_pause(&env, &admin, Symbol::new(&env, "resolved"))
+        })
+        .unwrap();
+
         let functions = Vec::from_array(&env, [Symbol::new(&env, "test_func")]);
-        EmergencyPause::pause_functions(&env, &admin, &functions, Symbol::new(&env, "maintenance")).unwrap();
+        in_host(&env, &host, || {
+            EmergencyPause::pause_functions(&env, &admin, &functions, Symbol::new(&env, "maintenance"))
+        })
+        .unwrap();
 
         // Check history
-        let history = EmergencyPause::get_pause_history(&env, 10);
+        let history = in_host(&env, &host, || EmergencyPause::get_pause_history(&env, 10));
         assert_eq!(history.len(), 3);
-        
+
         let first_event = history.get(0).unwrap();
         assert_eq!(first_event.action, Symbol::new(&env, "emergency_pause"));
-        
+
         let last_event = history.get(2).unwrap();
         assert_eq!(last_event.action, Symbol::new(&env, "selective_pause"));
     }
 
     #[test]
     fn test_pause_expiry() {
-        let env = Env::default();
+        let (env, host, admin) = setup();
         env.ledger().with_mut(|ledger| {
             ledger.timestamp = 1000;
         });
-        
-        let admin = Address::generate(&env);
-        EmergencyPause::initialize(&env, &admin).unwrap();
+        in_host(&env, &host, || EmergencyPause::initialize(&env, &admin)).unwrap();
 
         // Activate pause with 100 second duration
-        EmergencyPause::activate_emergency_pause(&env, &admin, Symbol::new(&env, "test"), 100).unwrap();
-        assert!(EmergencyPause::is_emergency_paused(&env));
+        in_host(&env, &host, || {
+            EmergencyPause::activate_emergency_pause(&env, &admin, Symbol::new(&env, "test"), 100)
+        })
+        .unwrap();
+        assert!(in_host(&env, &host, || EmergencyPause::is_emergency_paused(&env)));
 
         // Advance time by 50 seconds
         env.ledger().with_mut(|ledger| {
@@ -617,16 +653,20 @@ mod tests {
         });
 
         // Should still be paused
-        assert!(EmergencyPause::is_emergency_paused(&env));
-        assert!(EmergencyPause::validate_not_paused(&env, None).is_err());
+        assert!(in_host(&env, &host, || EmergencyPause::is_emergency_paused(&env)));
+        assert!(in_host(&env, &host, || EmergencyPause::validate_not_paused(&env, None)).is_err());
 
         // Advance time by another 60 seconds (total 110 seconds)
         env.ledger().with_mut(|ledger| {
             ledger.timestamp = 1110;
         });
 
+        // Still paused until the expiry is checked
+        assert!(in_host(&env, &host, || EmergencyPause::is_emergency_paused(&env)));
+        assert!(in_host(&env, &host, || EmergencyPause::check_pause_expiry(&env)).unwrap());
+
         // Should be auto-unpaused due to expiry
-        assert!(!EmergencyPause::is_emergency_paused(&env));
-        assert!(EmergencyPause::validate_not_paused(&env, None).is_ok());
+        assert!(!in_host(&env, &host, || EmergencyPause::is_emergency_paused(&env)));
+        assert!(in_host(&env, &host, || EmergencyPause::validate_not_paused(&env, None)).is_ok());
     }
-}
\ No newline at end of file
+}
@@ -10,6 +10,7 @@
 //! - `types`      – Shared data types and enums (PolicyStatus, ClaimStatus, etc.)
 //! - `constants`  – Configuration constants for validation and limits
 //! - `validation` – Centralized, domain-specific validation helper functions
+//! - `reentrancy_guard` – Lock for functions that transfer funds or call other contracts
 //!
 //! ## Usage
 //!
@@ -37,6 +38,7 @@ pub mod versioning;
 pub mod upgradeable;
 pub mod gas_optimization;
 pub mod emergency_pause;
+pub mod reentrancy_guard;
 
 // Re-export commonly used types
 pub use errors::ContractError;
@@ -125,6 +127,8 @@ pub use validation::{
     validate_all,
     calculate_percentage,
     calculate_basis_points,
+    checked_percentage,
+    checked_basis_points,
     calculate_reserve_ratio,
 
     // Domain-level composite validators
@@ -154,3 +158,4 @@ pub use versioning::{
     MigrationState, migration_state_to_u32, u32_to_migration_state,
 };
 pub use upgradeable::UpgradeableContract;
+pub use reentrancy_guard::{ReentrancyError, ReentrancyGuard};
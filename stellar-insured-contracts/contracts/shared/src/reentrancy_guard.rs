@@ -0,0 +1,169 @@
+//! # Reentrancy Guard
+//!
+//! A per-contract lock for functions that move funds or call out to other contracts
+//! after touching state (payouts, claim settlement, parametric triggers).
+//!
+//! The lock is a flag in the calling contract's instance storage. A guarded function
+//! that is re-entered while the flag is set fails with [`ReentrancyError::Reentrancy`];
+//! because a failed invocation rolls back its storage writes, a panic inside the
+//! guarded section can never leave the lock stuck.
+//!
+//! ## Usage Example
+//!
+//! ```rust,ignore
+//! use shared::reentrancy_guard::{ReentrancyError, ReentrancyGuard};
+//!
+//! impl From<ReentrancyError> for ContractError {
+//!     fn from(_: ReentrancyError) -> Self {
+//!         ContractError::Reentrancy
+//!     }
+//! }
+//!
+//! pub fn process_payout(env: Env, claim_id: u64) -> Result<(), ContractError> {
+//!     ReentrancyGuard::run(&env, || {
+//!         // state updates, then token transfer / cross-contract call
+//!         Ok(())
+//!     })
+//! }
+//! ```
+
+use soroban_sdk::{contracterror, symbol_short, Env, Symbol};
+
+/// Instance storage key holding the lock flag
+const REENTRANCY_LOCK: Symbol = symbol_short!("RE_LOCK");
+
+/// Reentrancy guard errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ReentrancyError {
+    /// A guarded function was entered while another guarded call was in progress
+    Reentrancy = 1,
+}
+
+/// Lock helpers scoped to the current contract's instance storage
+pub struct ReentrancyGuard;
+
+impl ReentrancyGuard {
+    /// Take the lock, failing if it is already held
+    pub fn enter(env: &Env) -> Result<(), ReentrancyError> {
+        if Self::is_entered(env) {
+            return Err(ReentrancyError::Reentrancy);
+        }
+        env.storage().instance().set(&REENTRANCY_LOCK, &true);
+        Ok(())
+    }
+
+    /// Release the lock
+    pub fn exit(env: &Env) {
+        env.storage().instance().remove(&REENTRANCY_LOCK);
+    }
+
+    /// Whether a guarded call is currently in progress
+    pub fn is_entered(env: &Env) -> bool {
+        env.storage().instance().get(&REENTRANCY_LOCK).unwrap_or(false)
+    }
+
+    /// Run `f` holding the lock. The lock is released whether `f` succeeds or
+    /// returns an error.
+    pub fn run<T, E, F>(env: &Env, f: F) -> Result<T, E>
+    where
+        E: From<ReentrancyError>,
+        F: FnOnce() -> Result<T, E>,
+    {
+        Self::enter(env)?;
+        let result = f();
+        Self::exit(env);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    /// Vault whose `withdraw` hands control to an untrusted callback mid-call
+    #[contract]
+    pub struct GuardedVault;
+
+    #[contractimpl]
+    impl GuardedVault {
+        pub fn withdraw(env: Env, callback: Address) -> Result<u32, ReentrancyError> {
+            ReentrancyGuard::run(&env, || {
+                let count: u32 =
+                    env.storage().instance().get(&symbol_short!("count")).unwrap_or(0);
+                env.storage().instance().set(&symbol_short!("count"), &(count + 1));
+
+                CallbackClient::new(&env, &callback).on_withdraw(&env.current_contract_address());
+                Ok(count + 1)
+            })
+        }
+
+        pub fn withdrawals(env: Env) -> u32 {
+            env.storage().instance().get(&symbol_short!("count")).unwrap_or(0)
+        }
+    }
+
+    /// Callback that tries to withdraw again before the first withdrawal returns
+    #[contract]
+    pub struct Callback;
+
+    #[contractimpl]
+    impl Callback {
+        pub fn on_withdraw(env: Env, vault: Address) {
+            let reentry =
+                GuardedVaultClient::new(&env, &vault).try_withdraw(&env.current_contract_address());
+            env.storage().instance().set(&symbol_short!("blocked"), &reentry.is_err());
+        }
+
+        pub fn reentry_blocked(env: Env) -> bool {
+            env.storage().instance().get(&symbol_short!("blocked")).unwrap_or(false)
+        }
+    }
+
+    #[test]
+    fn test_reentrant_callback_is_blocked() {
+        let env = Env::default();
+        let vault_id = env.register(GuardedVault, ());
+        let callback_id = env.register(Callback, ());
+        let vault = GuardedVaultClient::new(&env, &vault_id);
+
+        assert_eq!(vault.withdraw(&callback_id), 1);
+        assert!(CallbackClient::new(&env, &callback_id).reentry_blocked());
+        assert_eq!(vault.withdrawals(), 1);
+
+        // The lock is released once the outer call returns
+        env.as_contract(&vault_id, || assert!(!ReentrancyGuard::is_entered(&env)));
+        assert_eq!(vault.withdraw(&callback_id), 2);
+    }
+
+    #[test]
+    fn test_enter_while_held_fails() {
+        let env = Env::default();
+        let vault_id = env.register(GuardedVault, ());
+
+        env.as_contract(&vault_id, || {
+            ReentrancyGuard::enter(&env).unwrap();
+            assert_eq!(ReentrancyGuard::enter(&env), Err(ReentrancyError::Reentrancy));
+
+            let nested: Result<(), ReentrancyError> = ReentrancyGuard::run(&env, || Ok(()));
+            assert_eq!(nested, Err(ReentrancyError::Reentrancy));
+
+            ReentrancyGuard::exit(&env);
+            assert!(ReentrancyGuard::enter(&env).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_run_releases_lock_on_error() {
+        let env = Env::default();
+        let vault_id = env.register(GuardedVault, ());
+
+        env.as_contract(&vault_id, || {
+            let result: Result<(), ReentrancyError> =
+                ReentrancyGuard::run(&env, || Err(ReentrancyError::Reentrancy));
+            assert!(result.is_err());
+            assert!(!ReentrancyGuard::is_entered(&env));
+        });
+    }
+}
@@ -567,12 +567,7 @@ pub fn validate_all(conditions: &[(bool, ContractError)]) -> Result<(), Contract
 /// Validates `percent` is 0–100; uses checked arithmetic.
 pub fn calculate_percentage(amount: i128, percent: u32) -> Result<i128, ContractError> {
     validate_percentage(percent)?;
-    if percent == 0 {
-        return Ok(0);
-    }
-    safe_mul(amount, percent as i128)?
-        .checked_div(100)
-        .ok_or(ContractError::Overflow)
+    checked_percentage(amount, percent)
 }
 
 /// Calculate `bps` basis points of `amount`.
@@ -580,11 +575,28 @@ pub fn calculate_percentage(amount: i128, percent: u32) -> Result<i128, Contract
 /// Validates `bps` is 0–10 000; uses checked arithmetic.
 pub fn calculate_basis_points(amount: i128, bps: u32) -> Result<i128, ContractError> {
     validate_basis_points(bps)?;
-    if bps == 0 {
-        return Ok(0);
-    }
-    safe_mul(amount, bps as i128)?
-        .checked_div(10_000)
+    checked_basis_points(amount, bps)
+}
+
+/// Compute `amount * pct / 100` without wrapping.
+///
+/// Unlike [`calculate_percentage`], `pct` is not capped at 100, so it also fits
+/// multipliers. Returns `ContractError::Overflow` if the product exceeds `i128`.
+pub fn checked_percentage(amount: i128, pct: u32) -> Result<i128, ContractError> {
+    amount
+        .checked_mul(pct as i128)
+        .and_then(|product| product.checked_div(100))
+        .ok_or(ContractError::Overflow)
+}
+
+/// Compute `amount * bps / 10 000` without wrapping.
+///
+/// Unlike [`calculate_basis_points`], `bps` is not capped at 10 000. Returns
+/// `ContractError::Overflow` if the product exceeds `i128`.
+pub fn checked_basis_points(amount: i128, bps: u32) -> Result<i128, ContractError> {
+    amount
+        .checked_mul(bps as i128)
+        .and_then(|product| product.checked_div(10_000))
         .ok_or(ContractError::Overflow)
 }
 
@@ -660,3 +672,36 @@ pub fn validate_risk_pool_init_params(
     validate_positive_amount(min_provider_stake)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_percentage() {
+        assert_eq!(checked_percentage(1_000, 25), Ok(250));
+        assert_eq!(checked_percentage(1_000, 250), Ok(2_500));
+        assert_eq!(checked_percentage(-1_000, 10), Ok(-100));
+        assert_eq!(checked_percentage(i128::MAX / 100, 100), Ok(i128::MAX / 100));
+    }
+
+    #[test]
+    fn test_checked_percentage_overflow() {
+        assert_eq!(checked_percentage(i128::MAX, 2), Err(ContractError::Overflow));
+        assert_eq!(checked_percentage(i128::MAX / 50, 100), Err(ContractError::Overflow));
+        assert_eq!(calculate_percentage(i128::MAX, 50), Err(ContractError::Overflow));
+    }
+
+    #[test]
+    fn test_checked_basis_points() {
+        assert_eq!(checked_basis_points(1_000_000, 500), Ok(50_000));
+        assert_eq!(checked_basis_points(i128::MAX / 10_000, 10_000), Ok(i128::MAX / 10_000));
+    }
+
+    #[test]
+    fn test_checked_basis_points_overflow() {
+        assert_eq!(checked_basis_points(i128::MAX, 2), Err(ContractError::Overflow));
+        assert_eq!(checked_basis_points(i128::MAX - 1, 10_000), Err(ContractError::Overflow));
+        assert_eq!(calculate_basis_points(i128::MAX / 2, 500), Err(ContractError::Overflow));
+    }
+}
@@ -388,6 +388,23 @@ impl VersionManager {
         Ok(())
     }
 
+    /// Ensures that the current contract version is at least `min_version`
+    ///
+    /// Exposed by contracts so integrators can assert compatibility before
+    /// relying on a deployed contract's interface.
+    ///
+    /// # Errors
+    ///
+    /// - `VersioningError::NotInitialized` if version not set
+    /// - `VersioningError::VersionMismatch` if the current version is older
+    pub fn require_min_version(env: &Env, min_version: u32) -> Result<(), VersioningError> {
+        let current = Self::current_version(env)?;
+        if current < min_version {
+            return Err(VersioningError::VersionMismatch);
+        }
+        Ok(())
+    }
+
     /// Checks if a version upgrade is valid (new version must be higher)
     fn validate_version_upgrade(
         current: u32,
@@ -1,5 +1,7 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracterror, Address, Env, Symbol, Vec};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, token, Address, Env, Symbol, Vec,
+};
 
 #[contract]
 pub struct SlashingContract;
@@ -13,6 +15,19 @@ const PENALTY_PARAMS: Symbol = Symbol::short("PENALTY");
 const SLASH_COUNTER: Symbol = Symbol::short("SLASH_CNT");
 const GOVERNANCE_CONTRACT: Symbol = Symbol::short("GOV_CON");
 const RISK_POOL_CONTRACT: Symbol = Symbol::short("RISK_PO");
+const OFFENDERS: Symbol = Symbol::short("OFFENDERS");
+const OFFENDER_STATS: Symbol = Symbol::short("OFF_STATS");
+const TREASURY_CONTRACT: Symbol = Symbol::short("TREASURY");
+const COMPENSATION_FUND: Symbol = Symbol::short("COMP_FUND");
+const PENALTY_TOKEN: Symbol = Symbol::short("PEN_TOKEN");
+const TOTAL_BURNED: Symbol = Symbol::short("BURNED");
+const SLASHERS: Symbol = Symbol::short("SLASHERS");
+const TOTAL_SLASHED: Symbol = Symbol::short("TOT_SLASH");
+const UNIQUE_SLASHED: Symbol = Symbol::short("UNQ_SLASH");
+const PENDING_SLASH: Symbol = Symbol::short("PEND_SLSH");
+
+/// Maximum number of entries returned by `get_top_offenders`
+const MAX_LEADERBOARD_SIZE: u32 = 50;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum SlashingRole {
@@ -43,6 +58,21 @@ pub enum PenaltyDestination {
     CompensationFund = 3,
 }
 
+/// A recorded slash, stored per `(target, role)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashingRecord {
+    pub id: u64,
+    pub target: Address,
+    pub role: u32,
+    pub reason: u32,
+    pub timestamp: u64,
+    pub amount: i128,
+    pub destination: u32,
+    /// 1-based violation number of this slash for the target and role
+    pub violation_index: u32,
+}
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 pub enum ContractError {
@@ -61,6 +91,8 @@ pub enum ContractError {
     MaxPenaltyExceeded = 13,
     DuplicateSlashing = 14,
     GovernanceRequired = 15,
+    AppealWindowActive = 16,
+    Overflow = 17,
 }
 
 fn validate_address(_env: &Env, _address: &Address) -> Result<(), ContractError> {
@@ -80,22 +112,27 @@ fn set_paused(env: &Env, paused: bool) {
         .set(&PAUSED, &paused);
 }
 
-fn require_admin(env: &Env) -> Result<Address, ContractError> {
+/// Authenticate `caller` and require it to be the stored admin
+fn require_admin(env: &Env, caller: &Address) -> Result<Address, ContractError> {
+    caller.require_auth();
+
     let admin: Address = env
         .storage()
         .persistent()
         .get(&ADMIN)
         .ok_or(ContractError::NotInitialized)?;
     
-    let caller = env.current_contract_address();
-    if caller != admin {
+    if *caller != admin {
         return Err(ContractError::Unauthorized);
     }
     
     Ok(admin)
 }
 
-fn require_governance_or_admin(env: &Env) -> Result<(), ContractError> {
+/// Authenticate `caller` and require it to be the admin or the governance contract
+fn require_governance_or_admin(env: &Env, caller: &Address) -> Result<(), ContractError> {
+    caller.require_auth();
+
     let admin: Address = env
         .storage()
         .persistent()
@@ -108,14 +145,32 @@ fn require_governance_or_admin(env: &Env) -> Result<(), ContractError> {
         .get(&GOVERNANCE_CONTRACT)
         .ok_or(ContractError::NotInitialized)?;
     
-    let caller = env.current_contract_address();
-    if caller != admin && caller != governance_contract {
+    if *caller != admin && *caller != governance_contract {
         return Err(ContractError::GovernanceRequired);
     }
     
     Ok(())
 }
 
+/// Authenticate `caller` and require it to be the admin, the governance
+/// contract, or a registered slasher
+fn require_slasher(env: &Env, caller: &Address) -> Result<(), ContractError> {
+    let is_slasher: bool = env
+        .storage()
+        .persistent()
+        .get(&(SLASHERS, caller.clone()))
+        .unwrap_or(false);
+    if is_slasher {
+        caller.require_auth();
+        return Ok(());
+    }
+
+    require_governance_or_admin(env, caller).map_err(|e| match e {
+        ContractError::GovernanceRequired => ContractError::Unauthorized,
+        e => e,
+    })
+}
+
 fn is_role_slashable(env: &Env, role: u32) -> bool {
     let slashable_roles: Vec<u32> = env
         .storage()
@@ -136,14 +191,58 @@ fn calculate_penalty_amount(
         return Err(ContractError::InvalidInput);
     }
     
-    let base_penalty = (base_amount * penalty_percentage as i128) / 100;
-    let repeat_offender_multiplier = 1 + (violation_count.saturating_sub(1) * multiplier);
-    
-    Ok(base_penalty * repeat_offender_multiplier as i128)
+    let base_penalty = base_amount
+        .checked_mul(penalty_percentage as i128)
+        .and_then(|product| product.checked_div(100))
+        .ok_or(ContractError::Overflow)?;
+    let repeat_offender_multiplier = violation_count
+        .saturating_sub(1)
+        .checked_mul(multiplier)
+        .and_then(|extra| extra.checked_add(1))
+        .ok_or(ContractError::Overflow)?;
+
+    base_penalty
+        .checked_mul(repeat_offender_multiplier as i128)
+        .ok_or(ContractError::Overflow)
+}
+
+/// (role, reason, penalty_percentage, destination, repeat_offender_multiplier, cooldown_period,
+/// appeal_window)
+type PenaltyParams = (u32, u32, u32, u32, u32, u64, u64);
+
+/// (target, role, amount, destination, appeal_deadline)
+type PendingSlash = (Address, u32, i128, u32, u64);
+
+/// Resolves the penalty parameters and amount a slash of `target` would incur,
+/// based on the configured parameters and the target's current violation count.
+fn resolve_penalty(
+    env: &Env,
+    target: &Address,
+    role: u32,
+    reason: u32,
+    base_amount: i128,
+) -> Result<(PenaltyParams, u32, i128), ContractError> {
+    let penalty_params: PenaltyParams = env
+        .storage()
+        .persistent()
+        .get(&(PENALTY_PARAMS, role, reason))
+        .ok_or(ContractError::NotFound)?;
+
+    let violation_count =
+        SlashingContract::get_violation_count(env.clone(), target.clone(), role)?;
+    // The penalty is scaled by this offense's number, counting the one being slashed
+    let penalty_amount = calculate_penalty_amount(
+        base_amount,
+        penalty_params.2,
+        violation_count.checked_add(1).ok_or(ContractError::Overflow)?,
+        penalty_params.4,
+    )?;
+
+    Ok((penalty_params, violation_count, penalty_amount))
 }
 
 fn has_recent_slashing(env: &Env, target: &Address, role: u32, current_time: u64) -> bool {
-    let slashing_records: Vec<(u64, Address, u32, u32, u64, i128, u32, u32)> = env
+    let slashing_records: Vec<SlashingRecord> = env
         .storage()
         .persistent()
         .get(&(SLASHING_RECORD, target, role))
@@ -152,7 +251,7 @@ fn has_recent_slashing(env: &Env, target: &Address, role: u32, current_time: u64
     let cooldown_period = 86400u64; // 24 hours in seconds
     
     for record in slashing_records.iter() {
-        if current_time - record.4 < cooldown_period {
+        if current_time - record.timestamp < cooldown_period {
             return true;
         }
     }
@@ -160,13 +259,50 @@ fn has_recent_slashing(env: &Env, target: &Address, role: u32, current_time: u64
     false
 }
 
+/// Add a slash to the per-address and contract-wide totals, indexing and
+/// counting first-time offenders
+fn record_offender(env: &Env, target: &Address, penalty_amount: i128) {
+    let stats_key = (OFFENDER_STATS, target.clone());
+    let (count, total): (u32, i128) = match env.storage().persistent().get(&stats_key) {
+        Some(stats) => stats,
+        None => {
+            let mut offenders: Vec<Address> =
+                env.storage().persistent().get(&OFFENDERS).unwrap_or_else(|| Vec::new(env));
+            offenders.push_back(target.clone());
+            env.storage().persistent().set(&OFFENDERS, &offenders);
+
+            let unique: u64 = env.storage().persistent().get(&UNIQUE_SLASHED).unwrap_or(0);
+            env.storage().persistent().set(&UNIQUE_SLASHED, &(unique + 1));
+            (0, 0)
+        }
+    };
+
+    env.storage()
+        .persistent()
+        .set(&stats_key, &(count + 1, total.saturating_add(penalty_amount)));
+
+    let total_slashed: i128 = env.storage().persistent().get(&TOTAL_SLASHED).unwrap_or(0);
+    env.storage()
+        .persistent()
+        .set(&TOTAL_SLASHED, &total_slashed.saturating_add(penalty_amount));
+}
+
+/// Whether offender `a` ranks above `b`: more violations first, then larger total slashed
+fn ranks_above(a: &(Address, u32, i128), b: &(Address, u32, i128)) -> bool {
+    a.1 > b.1 || (a.1 == b.1 && a.2 > b.2)
+}
+
 #[contractimpl]
 impl SlashingContract {
+    /// Penalties are collected in `penalty_token` from the slashed party's
+    /// allowance to this contract and sent to the configured destination.
     pub fn initialize(
         env: Env,
         admin: Address,
         governance_contract: Address,
         risk_pool_contract: Address,
+        treasury_contract: Address,
+        penalty_token: Address,
     ) -> Result<(), ContractError> {
         if env.storage().persistent().has(&ADMIN) {
             return Err(ContractError::AlreadyInitialized);
@@ -175,10 +311,14 @@ impl SlashingContract {
         validate_address(&env, &admin)?;
         validate_address(&env, &governance_contract)?;
         validate_address(&env, &risk_pool_contract)?;
+        validate_address(&env, &treasury_contract)?;
+        validate_address(&env, &penalty_token)?;
 
         env.storage().persistent().set(&ADMIN, &admin);
         env.storage().persistent().set(&GOVERNANCE_CONTRACT, &governance_contract);
         env.storage().persistent().set(&RISK_POOL_CONTRACT, &risk_pool_contract);
+        env.storage().persistent().set(&TREASURY_CONTRACT, &treasury_contract);
+        env.storage().persistent().set(&PENALTY_TOKEN, &penalty_token);
         env.storage().persistent().set(&SLASH_COUNTER, &0u64);
 
         let mut default_slashable_roles = Vec::new(&env);
@@ -195,22 +335,27 @@ impl SlashingContract {
             PenaltyDestination::RiskPool as u32,
             2u32,  // 2x multiplier for repeat offenders
             86400u64, // 24 hour cooldown
+            0u64,  // no appeal window
         );
         env.storage().persistent().set(&PENALTY_PARAMS, &default_penalty_params);
 
         Ok(())
     }
 
+    /// With a non-zero `appeal_window` (seconds), slashes are held pending
+    /// until `finalize_slash` and can be cancelled with `reverse_slash`.
     pub fn configure_penalty_parameters(
         env: Env,
+        caller: Address,
         role: u32,
         reason: u32,
         penalty_percentage: u32,
         destination: u32,
         repeat_offender_multiplier: u32,
         cooldown_period: u64,
+        appeal_window: u64,
     ) -> Result<(), ContractError> {
-        require_governance_or_admin(&env)?;
+        require_governance_or_admin(&env, &caller)?;
 
         if penalty_percentage > 100 {
             return Err(ContractError::InvalidInput);
@@ -227,6 +372,7 @@ impl SlashingContract {
             destination,
             repeat_offender_multiplier,
             cooldown_period,
+            appeal_window,
         );
 
         env.storage()
@@ -241,8 +387,8 @@ impl SlashingContract {
         Ok(())
     }
 
-    pub fn add_slashable_role(env: Env, role: u32) -> Result<(), ContractError> {
-        require_admin(&env)?;
+    pub fn add_slashable_role(env: Env, caller: Address, role: u32) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
 
         let mut slashable_roles: Vec<u32> = env
             .storage()
@@ -263,8 +409,12 @@ impl SlashingContract {
         Ok(())
     }
 
-    pub fn remove_slashable_role(env: Env, role: u32) -> Result<(), ContractError> {
-        require_admin(&env)?;
+    pub fn remove_slashable_role(
+        env: Env,
+        caller: Address,
+        role: u32,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
 
         let mut slashable_roles: Vec<u32> = env
             .storage()
@@ -286,8 +436,43 @@ impl SlashingContract {
         Ok(())
     }
 
+    /// Set the address that receives penalties sent to the compensation fund
+    pub fn set_compensation_fund(
+        env: Env,
+        caller: Address,
+        fund: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+        validate_address(&env, &fund)?;
+
+        env.storage().persistent().set(&COMPENSATION_FUND, &fund);
+        Ok(())
+    }
+
+    /// Allow or revoke a contract (e.g. the oracle) to call `slash_funds`
+    pub fn set_slasher(
+        env: Env,
+        caller: Address,
+        slasher: Address,
+        allowed: bool,
+    ) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
+
+        if allowed {
+            env.storage().persistent().set(&(SLASHERS, slasher), &true);
+        } else {
+            env.storage().persistent().remove(&(SLASHERS, slasher));
+        }
+        Ok(())
+    }
+
+    /// Slash `target`, collecting the penalty from its allowance to this
+    /// contract. Callable by the admin, governance, or a registered slasher.
+    /// If the penalty parameters set an appeal window, the slash is only
+    /// recorded as pending and funds move on `finalize_slash`.
     pub fn slash_funds(
         env: Env,
+        caller: Address,
         target: Address,
         role: u32,
         reason: u32,
@@ -297,6 +482,8 @@ impl SlashingContract {
             return Err(ContractError::Paused);
         }
 
+        require_slasher(&env, &caller)?;
+
         if base_amount <= 0 {
             return Err(ContractError::InvalidInput);
         }
@@ -310,19 +497,8 @@ impl SlashingContract {
             return Err(ContractError::SlashingPeriodNotElapsed);
         }
 
-        let penalty_params: (u32, u32, u32, u32, u32, u64) = env
-            .storage()
-            .persistent()
-            .get(&(PENALTY_PARAMS, role, reason))
-            .ok_or(ContractError::NotFound)?;
-
-        let violation_count = Self::get_violation_count(env.clone(), target.clone(), role)?;
-        let penalty_amount = calculate_penalty_amount(
-            base_amount,
-            penalty_params.2,
-            violation_count,
-            penalty_params.4,
-        )?;
+        let (penalty_params, violation_count, penalty_amount) =
+            resolve_penalty(&env, &target, role, reason, base_amount)?;
 
         let slash_id: u64 = env
             .storage()
@@ -330,18 +506,18 @@ impl SlashingContract {
             .get(&SLASH_COUNTER)
             .unwrap_or(0) + 1;
 
-        let slashing_record = (
-            slash_id,
-            target.clone(),
+        let slashing_record = SlashingRecord {
+            id: slash_id,
+            target: target.clone(),
             role,
             reason,
-            current_time,
-            penalty_amount,
-            penalty_params.3, // destination
-            violation_count + 1,
-        );
+            timestamp: current_time,
+            amount: penalty_amount,
+            destination: penalty_params.3,
+            violation_index: violation_count + 1,
+        };
 
-        let mut user_records: Vec<(u64, Address, u32, u32, u64, i128, u32, u32)> = env
+        let mut user_records: Vec<SlashingRecord> = env
             .storage()
             .persistent()
             .get(&(SLASHING_RECORD, target.clone(), role))
@@ -353,7 +529,22 @@ impl SlashingContract {
 
         env.storage().persistent().set(&SLASH_COUNTER, &slash_id);
 
-        Self::redirect_funds(env.clone(), penalty_amount, penalty_params.3)?;
+        let appeal_window = penalty_params.6;
+        if appeal_window > 0 {
+            let deadline = current_time.saturating_add(appeal_window);
+            let pending: PendingSlash =
+                (target.clone(), role, penalty_amount, penalty_params.3, deadline);
+            env.storage().persistent().set(&(PENDING_SLASH, slash_id), &pending);
+
+            env.events().publish(
+                (Symbol::new(&env, "slash_pending"), slash_id),
+                (target, role, reason, penalty_amount, deadline),
+            );
+            return Ok(slash_id);
+        }
+
+        record_offender(&env, &target, penalty_amount);
+        Self::redirect_funds(&env, &target, penalty_amount, penalty_params.3)?;
 
         env.events().publish(
             (Symbol::new(&env, "funds_slashed"), slash_id),
@@ -363,52 +554,158 @@ impl SlashingContract {
         Ok(slash_id)
     }
 
-    fn redirect_funds(env: Env, amount: i128, destination: u32) -> Result<(), ContractError> {
-        match destination {
-            0 => {
-                // Risk Pool
-                let risk_pool_contract: Address = env
-                    .storage()
-                    .persistent()
-                    .get(&RISK_POOL_CONTRACT)
-                    .ok_or(ContractError::NotInitialized)?;
-                
-                // In a real implementation, this would call the risk pool contract
-                // to deposit the slashed funds
-                env.events().publish(
-                    (Symbol::new(&env, "funds_redirected"), 0u32),
-                    (risk_pool_contract, amount),
-                );
-            }
-            1 => {
-                // Treasury - funds are burned or sent to treasury address
-                env.events().publish(
-                    (Symbol::new(&env, "funds_burned"), 1u32),
-                    (amount,),
-                );
-            }
+    /// Redirect the funds of a pending slash once its appeal window has elapsed
+    pub fn finalize_slash(env: Env, caller: Address, slash_id: u64) -> Result<(), ContractError> {
+        if is_paused(&env) {
+            return Err(ContractError::Paused);
+        }
+
+        require_slasher(&env, &caller)?;
+
+        let (target, role, amount, destination, deadline): PendingSlash = env
+            .storage()
+            .persistent()
+            .get(&(PENDING_SLASH, slash_id))
+            .ok_or(ContractError::NotFound)?;
+
+        if env.ledger().timestamp() < deadline {
+            return Err(ContractError::AppealWindowActive);
+        }
+
+        env.storage().persistent().remove(&(PENDING_SLASH, slash_id));
+        record_offender(&env, &target, amount);
+        Self::redirect_funds(&env, &target, amount, destination)?;
+
+        env.events().publish(
+            (Symbol::new(&env, "slash_finalized"), slash_id),
+            (target, role, amount, destination),
+        );
+
+        Ok(())
+    }
+
+    /// Cancel a pending slash, dropping it from the target's violation history.
+    /// Only the admin or governance can reverse.
+    pub fn reverse_slash(env: Env, caller: Address, slash_id: u64) -> Result<(), ContractError> {
+        require_governance_or_admin(&env, &caller)?;
+
+        let (target, role, amount, _, _): PendingSlash = env
+            .storage()
+            .persistent()
+            .get(&(PENDING_SLASH, slash_id))
+            .ok_or(ContractError::NotFound)?;
+
+        let records_key = (SLASHING_RECORD, target.clone(), role);
+        let mut records: Vec<SlashingRecord> = env
+            .storage()
+            .persistent()
+            .get(&records_key)
+            .unwrap_or_else(|| Vec::new(&env));
+        if let Some(index) = records.iter().position(|record| record.id == slash_id) {
+            records.remove(index as u32);
+        }
+        env.storage().persistent().set(&records_key, &records);
+        env.storage().persistent().remove(&(PENDING_SLASH, slash_id));
+
+        env.events().publish(
+            (Symbol::new(&env, "slash_reversed"), slash_id),
+            (target, role, amount),
+        );
+
+        Ok(())
+    }
+
+    /// `(target, role, amount, destination, appeal_deadline)` of a pending slash
+    pub fn get_pending_slash(env: Env, slash_id: u64) -> Result<PendingSlash, ContractError> {
+        env.storage()
+            .persistent()
+            .get(&(PENDING_SLASH, slash_id))
+            .ok_or(ContractError::NotFound)
+    }
+
+    /// Dry-run of `slash_funds`: returns the penalty that would be recorded for
+    /// `target` right now, including the repeat-offender multiplier, without
+    /// mutating any state.
+    pub fn preview_penalty(
+        env: Env,
+        target: Address,
+        role: u32,
+        reason: u32,
+        base_amount: i128,
+    ) -> Result<i128, ContractError> {
+        if base_amount <= 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        if !is_role_slashable(&env, role) {
+            return Err(ContractError::RoleNotSlashable);
+        }
+
+        let (_, _, penalty_amount) = resolve_penalty(&env, &target, role, reason, base_amount)?;
+        Ok(penalty_amount)
+    }
+
+    /// Move `amount` of the penalty token from `target` to the destination.
+    /// The target must have approved this contract for at least `amount`.
+    fn redirect_funds(
+        env: &Env,
+        target: &Address,
+        amount: i128,
+        destination: u32,
+    ) -> Result<(), ContractError> {
+        let token_address: Address = env
+            .storage()
+            .persistent()
+            .get(&PENALTY_TOKEN)
+            .ok_or(ContractError::NotInitialized)?;
+        let token = token::Client::new(env, &token_address);
+        let spender = env.current_contract_address();
+
+        if token.allowance(target, &spender) < amount || token.balance(target) < amount {
+            return Err(ContractError::InsufficientBalance);
+        }
+
+        let recipient_key = match destination {
+            0 => RISK_POOL_CONTRACT,
+            1 => TREASURY_CONTRACT,
             2 => {
-                // Burn directly
-                env.events().publish(
-                    (Symbol::new(&env, "funds_burned"), 2u32),
-                    (amount,),
-                );
-            }
-            3 => {
-                // Compensation Fund
+                // Burn directly and track the reduction in supply
+                token.burn_from(&spender, target, &amount);
+                let burned: i128 = env.storage().persistent().get(&TOTAL_BURNED).unwrap_or(0);
+                env.storage().persistent().set(&TOTAL_BURNED, &(burned + amount));
+
                 env.events().publish(
-                    (Symbol::new(&env, "funds_redirected"), 3u32),
+                    (Symbol::new(env, "funds_burned"), 2u32),
                     (amount,),
                 );
+                return Ok(());
             }
+            3 => COMPENSATION_FUND,
             _ => return Err(ContractError::InvalidInput),
-        }
+        };
+
+        let recipient: Address = env
+            .storage()
+            .persistent()
+            .get(&recipient_key)
+            .ok_or(ContractError::NotInitialized)?;
+        token.transfer_from(&spender, target, &recipient, &amount);
+
+        env.events().publish(
+            (Symbol::new(env, "funds_redirected"), destination),
+            (recipient, amount),
+        );
 
         Ok(())
     }
 
+    /// Total penalty tokens burned
+    pub fn get_total_burned(env: Env) -> i128 {
+        env.storage().persistent().get(&TOTAL_BURNED).unwrap_or(0)
+    }
+
     pub fn get_violation_count(env: Env, target: Address, role: u32) -> Result<u32, ContractError> {
-        let records: Vec<(u64, Address, u32, u32, u64, i128, u32, u32)> = env
+        let records: Vec<SlashingRecord> = env
             .storage()
             .persistent()
             .get(&(SLASHING_RECORD, target, role))
@@ -421,8 +718,8 @@ impl SlashingContract {
         env: Env,
         target: Address,
         role: u32,
-    ) -> Result<Vec<(u64, Address, u32, u32, u64, i128, u32, u32)>, ContractError> {
-        let records: Vec<(u64, Address, u32, u32, u64, i128, u32, u32)> = env
+    ) -> Result<Vec<SlashingRecord>, ContractError> {
+        let records: Vec<SlashingRecord> = env
             .storage()
             .persistent()
             .get(&(SLASHING_RECORD, target, role))
@@ -431,12 +728,51 @@ impl SlashingContract {
         Ok(records)
     }
 
+    /// Most-slashed addresses across all roles as `(address, violations, total_slashed)`,
+    /// ordered by violation count and then total slashed. `limit` is capped at
+    /// `MAX_LEADERBOARD_SIZE`.
+    pub fn get_top_offenders(env: Env, limit: u32) -> Vec<(Address, u32, i128)> {
+        let limit = limit.min(MAX_LEADERBOARD_SIZE);
+        let mut top: Vec<(Address, u32, i128)> = Vec::new(&env);
+        if limit == 0 {
+            return top;
+        }
+
+        let offenders: Vec<Address> =
+            env.storage().persistent().get(&OFFENDERS).unwrap_or_else(|| Vec::new(&env));
+
+        for offender in offenders.iter() {
+            let (count, total): (u32, i128) = env
+                .storage()
+                .persistent()
+                .get(&(OFFENDER_STATS, offender.clone()))
+                .unwrap_or((0, 0));
+            let entry = (offender, count, total);
+
+            // Bounded insertion: find the slot, skip if it falls past the limit
+            let mut pos = top.len();
+            while pos > 0 && ranks_above(&entry, &top.get(pos - 1).unwrap()) {
+                pos -= 1;
+            }
+            if pos >= limit {
+                continue;
+            }
+
+            top.insert(pos, entry);
+            if top.len() > limit {
+                top.pop_back();
+            }
+        }
+
+        top
+    }
+
     pub fn get_penalty_parameters(
         env: Env,
         role: u32,
         reason: u32,
-    ) -> Result<(u32, u32, u32, u32, u32, u64), ContractError> {
-        let params: (u32, u32, u32, u32, u32, u64) = env
+    ) -> Result<PenaltyParams, ContractError> {
+        let params: PenaltyParams = env
             .storage()
             .persistent()
             .get(&(PENALTY_PARAMS, role, reason))
@@ -468,14 +804,14 @@ impl SlashingContract {
         Ok(!has_recent_slashing(&env, &target, role, current_time))
     }
 
-    pub fn pause(env: Env) -> Result<(), ContractError> {
-        require_admin(&env)?;
+    pub fn pause(env: Env, caller: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
         set_paused(&env, true);
         Ok(())
     }
 
-    pub fn unpause(env: Env) -> Result<(), ContractError> {
-        require_admin(&env)?;
+    pub fn unpause(env: Env, caller: Address) -> Result<(), ContractError> {
+        require_admin(&env, &caller)?;
         set_paused(&env, false);
         Ok(())
     }
@@ -494,17 +830,16 @@ impl SlashingContract {
         is_paused(&env)
     }
 
+    /// `(slash_count, unique_addresses, total_slashed)` across all roles
     pub fn get_slashing_stats(env: Env) -> Result<(u64, u64, i128), ContractError> {
         let slash_count: u64 = env
             .storage()
             .persistent()
             .get(&SLASH_COUNTER)
             .unwrap_or(0);
-
-        // In a real implementation, we'd calculate total slashed amount
-        // by iterating through all records. For now, return placeholder.
-        let total_slashed = 0i128;
-        let unique_addresses = 0u64;
+        let unique_addresses: u64 =
+            env.storage().persistent().get(&UNIQUE_SLASHED).unwrap_or(0);
+        let total_slashed: i128 = env.storage().persistent().get(&TOTAL_SLASHED).unwrap_or(0);
 
         Ok((slash_count, unique_addresses, total_slashed))
     }
@@ -513,6 +848,7 @@ impl SlashingContract {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
     use soroban_sdk::{Address, Env};
 
     #[test]
@@ -552,6 +888,394 @@ mod tests {
         assert_eq!(ContractError::NotFound as u32, 4);
     }
 
+    struct Destinations {
+        risk_pool: Address,
+        treasury: Address,
+    }
+
+    fn setup_slashing_with(
+        env: &Env,
+    ) -> (SlashingContractClient<'_>, Address, Address, Destinations) {
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, SlashingContract);
+        let client = SlashingContractClient::new(env, &contract_id);
+        let admin = Address::generate(env);
+        let token = env.register_stellar_asset_contract_v2(Address::generate(env)).address();
+        let destinations =
+            Destinations { risk_pool: Address::generate(env), treasury: Address::generate(env) };
+        client.initialize(
+            &admin,
+            &Address::generate(env),
+            &destinations.risk_pool,
+            &destinations.treasury,
+            &token,
+        );
+        client.configure_penalty_parameters(
+            &admin,
+            &(SlashingRole::OracleProvider as u32),
+            &(SlashingReason::OracleManipulation as u32),
+            &50,
+            &(PenaltyDestination::Burn as u32),
+            &2,
+            &86400,
+            &0,
+        );
+        (client, admin, token, destinations)
+    }
+
+    fn setup_slashing(env: &Env) -> (SlashingContractClient<'_>, Address, Address) {
+        let (client, admin, token, _) = setup_slashing_with(env);
+        (client, admin, token)
+    }
+
+    /// A target holding `balance` penalty tokens, all approved to the contract
+    fn funded_target(
+        env: &Env,
+        client: &SlashingContractClient,
+        token: &Address,
+        balance: i128,
+    ) -> Address {
+        let target = Address::generate(env);
+        token::StellarAssetClient::new(env, token).mint(&target, &balance);
+        token::Client::new(env, token).approve(&target, &client.address, &balance, &10_000);
+        target
+    }
+
+    #[test]
+    fn test_admin_functions_require_admin_caller() {
+        let env = Env::default();
+        let (client, admin, _) = setup_slashing(&env);
+        let outsider = Address::generate(&env);
+        let role = SlashingRole::PolicyHolder as u32;
+
+        assert_eq!(client.try_pause(&outsider), Err(Ok(ContractError::Unauthorized)));
+        assert_eq!(client.try_unpause(&outsider), Err(Ok(ContractError::Unauthorized)));
+        assert_eq!(
+            client.try_add_slashable_role(&outsider, &role),
+            Err(Ok(ContractError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_remove_slashable_role(&outsider, &0),
+            Err(Ok(ContractError::Unauthorized))
+        );
+        assert_eq!(
+            client.try_configure_penalty_parameters(&outsider, &0, &0, &10, &0, &1, &0, &0),
+            Err(Ok(ContractError::GovernanceRequired))
+        );
+        assert!(!client.is_contract_paused());
+
+        client.pause(&admin);
+        assert!(client.is_contract_paused());
+        client.unpause(&admin);
+        assert!(!client.is_contract_paused());
+
+        client.add_slashable_role(&admin, &role);
+        assert!(client.get_slashable_roles().contains(role));
+        client.remove_slashable_role(&admin, &role);
+        assert!(!client.get_slashable_roles().contains(role));
+    }
+
+    #[test]
+    fn test_preview_penalty_matches_slash() {
+        let env = Env::default();
+        let (client, admin, token) = setup_slashing(&env);
+        let target = funded_target(&env, &client, &token, 10_000);
+        let role = SlashingRole::OracleProvider as u32;
+        let reason = SlashingReason::OracleManipulation as u32;
+
+        for offense in 0..3u64 {
+            env.ledger().with_mut(|l| l.timestamp = 100_000 * (offense + 1));
+
+            let preview = client.preview_penalty(&target, &role, &reason, &1000);
+            // Previewing must not count as a violation
+            assert_eq!(client.get_violation_count(&target, &role), offense as u32);
+
+            client.slash_funds(&admin, &target, &role, &reason, &1000);
+            let history = client.get_slashing_history(&target, &role);
+            assert_eq!(history.get(offense as u32).unwrap().amount, preview);
+        }
+
+        // Repeat offenses pick up the multiplier: 500 * (1 + 1 * 2), then 500 * (1 + 2 * 2)
+        let history = client.get_slashing_history(&target, &role);
+        assert_eq!(history.get(1).unwrap().amount, 1500);
+        assert_eq!(history.get(2).unwrap().amount, 2500);
+    }
+
+    #[test]
+    fn test_preview_penalty_errors() {
+        let env = Env::default();
+        let (client, _, _) = setup_slashing(&env);
+        let target = Address::generate(&env);
+
+        assert_eq!(
+            client.try_preview_penalty(&target, &0, &0, &0),
+            Err(Ok(ContractError::InvalidInput))
+        );
+        assert_eq!(
+            client.try_preview_penalty(&target, &(SlashingRole::PolicyHolder as u32), &0, &1000),
+            Err(Ok(ContractError::RoleNotSlashable))
+        );
+        assert_eq!(
+            client.try_preview_penalty(&target, &0, &(SlashingReason::Collusion as u32), &1000),
+            Err(Ok(ContractError::NotFound))
+        );
+    }
+
+    #[test]
+    fn test_get_top_offenders_ranking() {
+        let env = Env::default();
+        let (client, admin, token) = setup_slashing(&env);
+        let role = SlashingRole::OracleProvider as u32;
+        let reason = SlashingReason::OracleManipulation as u32;
+
+        let repeat = funded_target(&env, &client, &token, 10_000);
+        let twice = funded_target(&env, &client, &token, 10_000);
+        let once_small = funded_target(&env, &client, &token, 10_000);
+        let once_large = funded_target(&env, &client, &token, 10_000);
+
+        // (target, base_amount) per round; rounds are spaced past the cooldown
+        let rounds = [
+            [(&once_small, 1000), (&repeat, 1000), (&twice, 1000)],
+            [(&once_large, 2000), (&repeat, 1000), (&twice, 1000)],
+        ];
+        for (round, slashes) in rounds.iter().enumerate() {
+            env.ledger().with_mut(|l| l.timestamp = 100_000 * (round as u64 + 1));
+            for (target, base_amount) in slashes.iter() {
+                client.slash_funds(&admin, target, &role, &reason, base_amount);
+            }
+        }
+        env.ledger().with_mut(|l| l.timestamp = 300_000);
+        client.slash_funds(&admin, &repeat, &role, &reason, &1000);
+
+        let top = client.get_top_offenders(&10);
+        assert_eq!(top.len(), 4);
+        // 500 + 1500 + 2500
+        assert_eq!(top.get(0).unwrap(), (repeat.clone(), 3, 4500));
+        assert_eq!(top.get(1).unwrap(), (twice.clone(), 2, 2000));
+        // Equal counts fall back to total slashed
+        assert_eq!(top.get(2).unwrap(), (once_large.clone(), 1, 1000));
+        assert_eq!(top.get(3).unwrap(), (once_small.clone(), 1, 500));
+
+        let top_two = client.get_top_offenders(&2);
+        assert_eq!(top_two.len(), 2);
+        assert_eq!(top_two.get(0).unwrap().0, repeat);
+        assert_eq!(top_two.get(1).unwrap().0, twice);
+
+        assert_eq!(client.get_top_offenders(&0).len(), 0);
+    }
+
+    #[test]
+    fn test_slash_moves_funds_to_each_destination() {
+        let env = Env::default();
+        let (client, admin, token, destinations) = setup_slashing_with(&env);
+        let balances = token::Client::new(&env, &token);
+        let role = SlashingRole::OracleProvider as u32;
+        let compensation = Address::generate(&env);
+        client.set_compensation_fund(&admin, &compensation);
+
+        for (reason, destination) in [
+            (SlashingReason::FraudulentClaim, PenaltyDestination::RiskPool),
+            (SlashingReason::Negligence, PenaltyDestination::Treasury),
+            (SlashingReason::Collusion, PenaltyDestination::CompensationFund),
+        ] {
+            client.configure_penalty_parameters(
+                &admin,
+                &role,
+                &(reason as u32),
+                &10,
+                &(destination as u32),
+                &1,
+                &86400,
+                &0,
+            );
+        }
+
+        let cases = [
+            (SlashingReason::FraudulentClaim, destinations.risk_pool.clone()),
+            (SlashingReason::Negligence, destinations.treasury.clone()),
+            (SlashingReason::Collusion, compensation.clone()),
+        ];
+        for (reason, recipient) in cases.iter() {
+            let target = funded_target(&env, &client, &token, 1_000);
+            client.slash_funds(&admin, &target, &role, &(*reason as u32), &1_000);
+            assert_eq!(balances.balance(&target), 900);
+            assert_eq!(balances.balance(recipient), 100);
+        }
+
+        // Burn (50% in setup) leaves the tokens nowhere and is tracked
+        let target = funded_target(&env, &client, &token, 1_000);
+        client.slash_funds(
+            &admin,
+            &target,
+            &role,
+            &(SlashingReason::OracleManipulation as u32),
+            &1_000,
+        );
+        assert_eq!(balances.balance(&target), 500);
+        assert_eq!(balances.balance(&client.address), 0);
+        assert_eq!(client.get_total_burned(), 500);
+    }
+
+    #[test]
+    fn test_slash_requires_slasher_and_allowance() {
+        let env = Env::default();
+        let (client, admin, token) = setup_slashing(&env);
+        let role = SlashingRole::OracleProvider as u32;
+        let reason = SlashingReason::OracleManipulation as u32;
+        let target = funded_target(&env, &client, &token, 1_000);
+        let slasher = Address::generate(&env);
+
+        assert_eq!(
+            client.try_slash_funds(&slasher, &target, &role, &reason, &1_000),
+            Err(Ok(ContractError::Unauthorized))
+        );
+
+        client.set_slasher(&admin, &slasher, &true);
+        // Penalty of 5_000 exceeds the 1_000 the target approved
+        assert_eq!(
+            client.try_slash_funds(&slasher, &target, &role, &reason, &10_000),
+            Err(Ok(ContractError::InsufficientBalance))
+        );
+        client.slash_funds(&slasher, &target, &role, &reason, &1_000);
+        assert_eq!(client.get_violation_count(&target, &role), 1);
+
+        client.set_slasher(&admin, &slasher, &false);
+        let other = funded_target(&env, &client, &token, 1_000);
+        assert_eq!(
+            client.try_slash_funds(&slasher, &other, &role, &reason, &1_000),
+            Err(Ok(ContractError::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_slashing_stats_track_unique_targets_and_total() {
+        let env = Env::default();
+        let (client, admin, token) = setup_slashing(&env);
+        let role = SlashingRole::OracleProvider as u32;
+        let reason = SlashingReason::OracleManipulation as u32;
+        assert_eq!(client.get_slashing_stats(), (0, 0, 0));
+
+        let repeat = funded_target(&env, &client, &token, 10_000);
+        let other = funded_target(&env, &client, &token, 10_000);
+
+        env.ledger().with_mut(|l| l.timestamp = 100_000);
+        client.slash_funds(&admin, &repeat, &role, &reason, &1_000);
+        client.slash_funds(&admin, &other, &role, &reason, &400);
+
+        // Past the cooldown the repeat offender pays 500 * 3
+        env.ledger().with_mut(|l| l.timestamp = 200_000);
+        client.slash_funds(&admin, &repeat, &role, &reason, &1_000);
+
+        assert_eq!(client.get_slashing_stats(), (3, 2, 500 + 200 + 1_500));
+    }
+
+    #[test]
+    fn test_appeal_window_delays_finalization() {
+        let env = Env::default();
+        let (client, admin, token, destinations) = setup_slashing_with(&env);
+        let balances = token::Client::new(&env, &token);
+        let role = SlashingRole::OracleProvider as u32;
+        let reason = SlashingReason::FraudulentClaim as u32;
+        client.configure_penalty_parameters(
+            &admin,
+            &role,
+            &reason,
+            &10,
+            &(PenaltyDestination::RiskPool as u32),
+            &1,
+            &86400,
+            &3_600,
+        );
+
+        env.ledger().with_mut(|l| l.timestamp = 1_000);
+        let target = funded_target(&env, &client, &token, 1_000);
+        let slash_id = client.slash_funds(&admin, &target, &role, &reason, &1_000);
+        assert_eq!(
+            client.get_pending_slash(&slash_id),
+            (target.clone(), role, 100, PenaltyDestination::RiskPool as u32, 4_600)
+        );
+        assert_eq!(balances.balance(&target), 1_000);
+
+        env.ledger().with_mut(|l| l.timestamp = 4_599);
+        assert_eq!(
+            client.try_finalize_slash(&admin, &slash_id),
+            Err(Ok(ContractError::AppealWindowActive))
+        );
+
+        env.ledger().with_mut(|l| l.timestamp = 4_600);
+        client.finalize_slash(&admin, &slash_id);
+        assert_eq!(balances.balance(&target), 900);
+        assert_eq!(balances.balance(&destinations.risk_pool), 100);
+        assert_eq!(client.get_slashing_stats(), (1, 1, 100));
+        assert_eq!(
+            client.try_finalize_slash(&admin, &slash_id),
+            Err(Ok(ContractError::NotFound))
+        );
+    }
+
+    #[test]
+    fn test_reverse_pending_slash() {
+        let env = Env::default();
+        let (client, admin, token) = setup_slashing(&env);
+        let role = SlashingRole::OracleProvider as u32;
+        let reason = SlashingReason::FraudulentClaim as u32;
+        client.configure_penalty_parameters(&admin, &role, &reason, &10, &0, &1, &86400, &3_600);
+
+        let target = funded_target(&env, &client, &token, 1_000);
+        let slash_id = client.slash_funds(&admin, &target, &role, &reason, &1_000);
+        assert_eq!(client.get_violation_count(&target, &role), 1);
+
+        let outsider = Address::generate(&env);
+        assert_eq!(
+            client.try_reverse_slash(&outsider, &slash_id),
+            Err(Ok(ContractError::GovernanceRequired))
+        );
+
+        client.reverse_slash(&admin, &slash_id);
+        assert_eq!(client.get_violation_count(&target, &role), 0);
+        assert_eq!(client.try_get_pending_slash(&slash_id), Err(Ok(ContractError::NotFound)));
+        assert_eq!(token::Client::new(&env, &token).balance(&target), 1_000);
+
+        env.ledger().with_mut(|l| l.timestamp = 10_000);
+        assert_eq!(
+            client.try_finalize_slash(&admin, &slash_id),
+            Err(Ok(ContractError::NotFound))
+        );
+        assert_eq!(client.get_slashing_stats(), (1, 0, 0));
+    }
+
+    #[test]
+    fn test_slashing_record_storage_round_trip() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, SlashingContract);
+        let client = SlashingContractClient::new(&env, &contract_id);
+        let target = Address::generate(&env);
+        let role = SlashingRole::ClaimSubmitter as u32;
+        let record = SlashingRecord {
+            id: 7,
+            target: target.clone(),
+            role,
+            reason: SlashingReason::FraudulentClaim as u32,
+            timestamp: 12_345,
+            amount: 2_500,
+            destination: PenaltyDestination::Treasury as u32,
+            violation_index: 1,
+        };
+
+        env.as_contract(&contract_id, || {
+            let mut records = Vec::new(&env);
+            records.push_back(record.clone());
+            env.storage()
+                .persistent()
+                .set(&(SLASHING_RECORD, target.clone(), role), &records);
+        });
+
+        let history = client.get_slashing_history(&target, &role);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.get(0).unwrap(), record);
+        assert_eq!(client.get_violation_count(&target, &role), 1);
+    }
+
     #[test]
     fn test_penalty_calculation() {
         let base_amount = 1000i128;
@@ -570,5 +1294,21 @@ mod tests {
         let repeat_multiplier_2 = 1 + (2u32.saturating_sub(1) * multiplier);
         let repeat_penalty_2 = expected_penalty * repeat_multiplier_2 as i128;
         assert_eq!(repeat_penalty_2, 1500i128); // Second offense (1 + (2-1)*2 = 3, 500*3 = 1500)
+
+        assert_eq!(calculate_penalty_amount(base_amount, 50, 2, 2), Ok(1500));
+    }
+
+    #[test]
+    fn test_penalty_calculation_overflow() {
+        // The percentage product overflows before the division
+        assert_eq!(calculate_penalty_amount(i128::MAX, 50, 1, 1), Err(ContractError::Overflow));
+
+        // The base penalty fits, but the repeat-offender multiplier pushes it past i128::MAX
+        let base = i128::MAX / 100;
+        assert_eq!(calculate_penalty_amount(base, 100, 1, 0), Ok(base));
+        assert_eq!(calculate_penalty_amount(base, 100, 2, 100), Err(ContractError::Overflow));
+
+        // The multiplier itself overflows u32
+        assert_eq!(calculate_penalty_amount(1_000, 50, u32::MAX, 2), Err(ContractError::Overflow));
     }
 }
@@ -1,5 +1,6 @@
 // Integration Examples: How Other Contracts Use Treasury
 
+use shared::{checked_basis_points, checked_percentage};
 use soroban_sdk::{Address, Env, Symbol};
 
 // ============================================================================
@@ -15,7 +16,8 @@ pub fn policy_issue_with_fee(
     fee_percentage: u32,
 ) -> Result<(), String> {
     // Calculate protocol fee (e.g., 5% = 500 basis points)
-    let protocol_fee = (premium_amount * (fee_percentage as i128)) / 10000;
+    let protocol_fee = checked_basis_points(premium_amount, fee_percentage)
+        .map_err(|_| String::from("fee overflow"))?;
 
     // Deposit fee to treasury
     // In real implementation, this would be a cross-contract call:
@@ -42,7 +44,8 @@ pub fn claims_reject_with_penalty(
     penalty_percentage: u32,
 ) -> Result<(), String> {
     // Calculate penalty (e.g., 10% of claim amount)
-    let penalty = (claim_amount * (penalty_percentage as i128)) / 100;
+    let penalty = checked_percentage(claim_amount, penalty_percentage)
+        .map_err(|_| String::from("penalty overflow"))?;
 
     // Deposit penalty to treasury
     // let result: Result<(), _> = env.invoke_contract(
@@ -67,7 +70,8 @@ pub fn slashing_execute_with_fee(
     slashing_amount: i128,
 ) -> Result<(), String> {
     // Calculate protocol fee from slashing (e.g., 20% fee)
-    let protocol_fee = (slashing_amount * 20) / 100;
+    let protocol_fee = checked_percentage(slashing_amount, 20)
+        .map_err(|_| String::from("fee overflow"))?;
 
     // Deposit slashing fee to treasury
     // let result: Result<(), _> = env.invoke_contract(
@@ -101,6 +105,7 @@ pub fn governance_propose_audit_funding(
     //         proposer,
     //         audit_contractor,
     //         audit_budget,
+    //         Asset::Native,
     //         1u32, // AuditFunding
     //         Symbol::new(env, "Q1 2024 Security Audit"),
     //     ),
@@ -164,6 +169,7 @@ pub fn risk_pool_receive_allocation(
     //         risk_pool_address.clone(),
     //         risk_pool_address.clone(),
     //         allocation_amount,
+    //         Asset::Native,
     //         3u32, // InsuranceReserves
     //         Symbol::new(env, "Reserve allocation"),
     //     ),